@@ -0,0 +1,209 @@
+//! Implementations of the [approx] comparison traits for the floating point scalar, vector and
+//! matrix types, and for arrays of types that implement them.
+//!
+//! Vectors, matrices and arrays compare component-wise: two values are approximately equal if all
+//! of their corresponding components are approximately equal.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::*;
+
+macro_rules! impl_approx_scalar {
+    ($scalar:ident, $primitive:ty) => {
+        impl AbsDiffEq for $scalar {
+            type Epsilon = $primitive;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$primitive>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.0.abs_diff_eq(&other.0, epsilon)
+            }
+        }
+
+        impl RelativeEq for $scalar {
+            fn default_max_relative() -> Self::Epsilon {
+                <$primitive>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $scalar {
+            fn default_max_ulps() -> u32 {
+                <$primitive>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx_scalar!(float, f32);
+impl_approx_scalar!(double, f64);
+
+macro_rules! impl_approx_vector {
+    ($vector:ident, $primitive:ty, $($c:tt),*) => {
+        impl AbsDiffEq for $vector {
+            type Epsilon = $primitive;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$primitive>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(self.$c.abs_diff_eq(&other.$c, epsilon))&&*
+            }
+        }
+
+        impl RelativeEq for $vector {
+            fn default_max_relative() -> Self::Epsilon {
+                <$primitive>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $(self.$c.relative_eq(&other.$c, epsilon, max_relative))&&*
+            }
+        }
+
+        impl UlpsEq for $vector {
+            fn default_max_ulps() -> u32 {
+                <$primitive>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $(self.$c.ulps_eq(&other.$c, epsilon, max_ulps))&&*
+            }
+        }
+    };
+}
+
+impl_approx_vector!(vec2, f32, 0, 1);
+impl_approx_vector!(vec3, f32, 0, 1, 2);
+impl_approx_vector!(vec4, f32, 0, 1, 2, 3);
+impl_approx_vector!(dvec2, f64, 0, 1);
+impl_approx_vector!(dvec3, f64, 0, 1, 2);
+impl_approx_vector!(dvec4, f64, 0, 1, 2, 3);
+
+impl<T, const N: usize> AbsDiffEq for array<T, { N }>
+where
+    T: Std140ArrayElement + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.internal
+            .iter()
+            .zip(other.internal.iter())
+            .all(|(a, b)| a.element.abs_diff_eq(&b.element, epsilon))
+    }
+}
+
+impl<T, const N: usize> RelativeEq for array<T, { N }>
+where
+    T: Std140ArrayElement + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.internal
+            .iter()
+            .zip(other.internal.iter())
+            .all(|(a, b)| a.element.relative_eq(&b.element, epsilon, max_relative))
+    }
+}
+
+impl<T, const N: usize> UlpsEq for array<T, { N }>
+where
+    T: Std140ArrayElement + UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.internal
+            .iter()
+            .zip(other.internal.iter())
+            .all(|(a, b)| a.element.ulps_eq(&b.element, epsilon, max_ulps))
+    }
+}
+
+impl<V, const C: usize> AbsDiffEq for Matrix<V, { C }>
+where
+    V: MatrixColumn + AbsDiffEq,
+    V::Epsilon: Copy,
+{
+    type Epsilon = V::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        V::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.columns.abs_diff_eq(&other.columns, epsilon)
+    }
+}
+
+impl<V, const C: usize> RelativeEq for Matrix<V, { C }>
+where
+    V: MatrixColumn + RelativeEq,
+    V::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        V::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.columns
+            .relative_eq(&other.columns, epsilon, max_relative)
+    }
+}
+
+impl<V, const C: usize> UlpsEq for Matrix<V, { C }>
+where
+    V: MatrixColumn + UlpsEq,
+    V::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        V::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.columns.ulps_eq(&other.columns, epsilon, max_ulps)
+    }
+}
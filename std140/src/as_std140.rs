@@ -0,0 +1,270 @@
+use crate::*;
+
+/// Trait for plain Rust types that have a std140 counterpart.
+///
+/// This trait is implemented for the following types:
+///
+/// - `f32`: [float]
+/// - `f64`: [double]
+/// - `i32`: [int]
+/// - `u32`: [uint]
+/// - `bool`: [boolean]
+/// - `[f32; N]` where `N` is `2`, `3` or `4`: [vec2], [vec3] or [vec4] respectively
+/// - `[f64; N]` where `N` is `2`, `3` or `4`: [dvec2], [dvec3] or [dvec4] respectively
+/// - `[i32; N]` where `N` is `2`, `3` or `4`: [ivec2], [ivec3] or [ivec4] respectively
+/// - `[u32; N]` where `N` is `2`, `3` or `4`: [uvec2], [uvec3] or [uvec4] respectively
+/// - `[bool; N]` where `N` is `2`, `3` or `4`: [bvec2], [bvec3] or [bvec4] respectively
+/// - `[[f32; R]; C]` where `C` and `R` are `2`, `3` or `4`: the `matCxR` matrix with `C` columns
+///   and `R` rows (e.g. `[[f32; 3]; 4]` is represented by [mat4x3][type@mat4x3])
+/// - `[[f64; R]; C]` where `C` and `R` are `2`, `3` or `4`: the `dmatCxR` matrix with `C` columns
+///   and `R` rows (e.g. `[[f64; 3]; 4]` is represented by [dmat4x3][type@dmat4x3])
+///
+/// It is also implemented for structs marked with [`#[derive_std140]`][derive_std140].
+///
+/// [derive_std140]: attr.derive_std140.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not have a std140 counterpart",
+    label = "`{Self}` does not implement `AsStd140`"
+)]
+pub trait AsStd140 {
+    /// The std140 counterpart of this type.
+    type Output: ReprStd140;
+
+    /// Converts this value into its std140 counterpart.
+    #[must_use]
+    fn as_std140(&self) -> Self::Output;
+}
+
+/// Trait for plain Rust types that can be recovered from their std140 counterpart.
+///
+/// See [AsStd140] for the types that implement this trait.
+pub trait FromStd140: AsStd140 {
+    /// Converts the std140 counterpart back into the plain Rust type.
+    #[must_use]
+    fn from_std140(value: Self::Output) -> Self;
+}
+
+/// Trait for fieldless Rust enums that are stored as a [uint] in a std140 interface block, e.g. a
+/// material type that a shader switches on.
+///
+/// Should be implemented with `#[derive(Std140Enum)]`, which only accepts enums marked
+/// `#[repr(u32)]` of which all variants are without fields. The discriminant of a variant is its
+/// [uint] value. Converting back with [from_std140][Std140Enum::from_std140] returns `None` for a
+/// value that is not the discriminant of any variant, which prevents invalid discriminants from
+/// being turned into an enum value.
+///
+/// If the `std140` crate is re-exported under a different path, specify that path with
+/// `#[std140(crate = "path")]`.
+///
+/// # Example
+///
+/// ```
+/// use std140::Std140Enum;
+///
+/// #[derive(Std140Enum, Clone, Copy, PartialEq, Debug)]
+/// #[repr(u32)]
+/// enum MaterialKind {
+///     Opaque,
+///     Transparent,
+///     Emissive = 8,
+/// }
+///
+/// #[std140::repr_std140]
+/// struct Material {
+///     color: std140::vec4,
+///     kind: std140::uint,
+/// }
+///
+/// let material = Material {
+///     color: std140::vec4(1.0, 0.0, 0.0, 1.0),
+///     kind: MaterialKind::Emissive.to_std140(),
+/// };
+///
+/// assert_eq!(material.kind, std140::uint(8));
+/// assert_eq!(MaterialKind::from_std140(material.kind), Some(MaterialKind::Emissive));
+/// assert_eq!(MaterialKind::from_std140(std140::uint(2)), None);
+/// ```
+///
+/// Enums without `#[repr(u32)]` are rejected:
+///
+/// ```compile_fail
+/// #[derive(std140::Std140Enum)]
+/// enum MaterialKind {
+///     Opaque,
+///     Transparent,
+/// }
+/// ```
+pub trait Std140Enum: Sized {
+    /// Converts this enum value into its discriminant.
+    #[must_use]
+    fn to_std140(self) -> uint;
+
+    /// Converts a discriminant back into an enum value, or returns `None` if `value` is not the
+    /// discriminant of any of the variants.
+    #[must_use]
+    fn from_std140(value: uint) -> Option<Self>;
+}
+
+macro_rules! impl_as_std140_scalar {
+    ($plain:ty, $std140:ident) => {
+        impl AsStd140 for $plain {
+            type Output = $std140;
+
+            fn as_std140(&self) -> Self::Output {
+                $std140(*self)
+            }
+        }
+
+        impl FromStd140 for $plain {
+            fn from_std140(value: Self::Output) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+impl_as_std140_scalar!(f32, float);
+impl_as_std140_scalar!(f64, double);
+impl_as_std140_scalar!(i32, int);
+impl_as_std140_scalar!(u32, uint);
+
+impl AsStd140 for bool {
+    type Output = boolean;
+
+    fn as_std140(&self) -> Self::Output {
+        (*self).into()
+    }
+}
+
+impl FromStd140 for bool {
+    fn from_std140(value: Self::Output) -> Self {
+        value == boolean::True
+    }
+}
+
+macro_rules! impl_as_std140_vector {
+    ($scalar:ty, $len:literal, $std140:ident, $($component:tt),*) => {
+        impl AsStd140 for [$scalar; $len] {
+            type Output = $std140;
+
+            fn as_std140(&self) -> Self::Output {
+                $std140($(self[$component]),*)
+            }
+        }
+
+        impl FromStd140 for [$scalar; $len] {
+            fn from_std140(value: Self::Output) -> Self {
+                [$(value.$component),*]
+            }
+        }
+    };
+}
+
+macro_rules! impl_as_std140_bvec {
+    ($len:literal, $std140:ident, $($component:tt),*) => {
+        impl AsStd140 for [bool; $len] {
+            type Output = $std140;
+
+            fn as_std140(&self) -> Self::Output {
+                $std140($(self[$component].into()),*)
+            }
+        }
+
+        impl FromStd140 for [bool; $len] {
+            fn from_std140(value: Self::Output) -> Self {
+                [$(value.$component == boolean::True),*]
+            }
+        }
+    };
+}
+
+impl_as_std140_vector!(f32, 2, vec2, 0, 1);
+impl_as_std140_vector!(f32, 3, vec3, 0, 1, 2);
+impl_as_std140_vector!(f32, 4, vec4, 0, 1, 2, 3);
+impl_as_std140_vector!(f64, 2, dvec2, 0, 1);
+impl_as_std140_vector!(f64, 3, dvec3, 0, 1, 2);
+impl_as_std140_vector!(f64, 4, dvec4, 0, 1, 2, 3);
+impl_as_std140_vector!(i32, 2, ivec2, 0, 1);
+impl_as_std140_vector!(i32, 3, ivec3, 0, 1, 2);
+impl_as_std140_vector!(i32, 4, ivec4, 0, 1, 2, 3);
+impl_as_std140_vector!(u32, 2, uvec2, 0, 1);
+impl_as_std140_vector!(u32, 3, uvec3, 0, 1, 2);
+impl_as_std140_vector!(u32, 4, uvec4, 0, 1, 2, 3);
+impl_as_std140_bvec!(2, bvec2, 0, 1);
+impl_as_std140_bvec!(3, bvec3, 0, 1, 2);
+impl_as_std140_bvec!(4, bvec4, 0, 1, 2, 3);
+
+macro_rules! impl_as_std140_matrix {
+    ($scalar:ty, $columns:literal, $rows:literal, $std140:ident, $($column:tt),*) => {
+        impl AsStd140 for [[$scalar; $rows]; $columns] {
+            type Output = $std140;
+
+            fn as_std140(&self) -> Self::Output {
+                $std140($(self[$column].as_std140()),*)
+            }
+        }
+
+        impl FromStd140 for [[$scalar; $rows]; $columns] {
+            fn from_std140(value: Self::Output) -> Self {
+                [$(FromStd140::from_std140(value.columns.internal[$column].element)),*]
+            }
+        }
+
+        impl $std140 {
+            #[doc = concat!("Creates a new [", stringify!($std140), "] from its elements in row-major order.")]
+            ///
+            /// `rows[r][c]` is the element in row `r` and column `c`. Use the [From] conversion
+            /// instead if the elements are in column-major order.
+            #[must_use]
+            pub fn from_row_major(rows: [[$scalar; $columns]; $rows]) -> Self {
+                $std140($(core::array::from_fn::<$scalar, $rows, _>(|r| rows[r][$column]).as_std140()),*)
+            }
+
+            /// Returns the elements of this matrix in row-major order.
+            ///
+            /// `self.to_row_major()[r][c]` is the element in row `r` and column `c`. Use the [From]
+            /// conversion instead to obtain the elements in column-major order.
+            #[must_use]
+            pub fn to_row_major(&self) -> [[$scalar; $columns]; $rows] {
+                let columns = <[[$scalar; $rows]; $columns]>::from(*self);
+
+                core::array::from_fn(|r| core::array::from_fn(|c| columns[c][r]))
+            }
+        }
+
+        /// Creates a matrix from its elements in column-major order: `value[c][r]` is the element
+        /// in column `c` and row `r`.
+        impl From<[[$scalar; $rows]; $columns]> for $std140 {
+            fn from(value: [[$scalar; $rows]; $columns]) -> Self {
+                value.as_std140()
+            }
+        }
+
+        /// Returns the elements of a matrix in column-major order: `value[c][r]` is the element in
+        /// column `c` and row `r`.
+        impl From<$std140> for [[$scalar; $rows]; $columns] {
+            fn from(value: $std140) -> Self {
+                FromStd140::from_std140(value)
+            }
+        }
+    };
+}
+
+impl_as_std140_matrix!(f32, 2, 2, mat2x2, 0, 1);
+impl_as_std140_matrix!(f32, 2, 3, mat2x3, 0, 1);
+impl_as_std140_matrix!(f32, 2, 4, mat2x4, 0, 1);
+impl_as_std140_matrix!(f32, 3, 2, mat3x2, 0, 1, 2);
+impl_as_std140_matrix!(f32, 3, 3, mat3x3, 0, 1, 2);
+impl_as_std140_matrix!(f32, 3, 4, mat3x4, 0, 1, 2);
+impl_as_std140_matrix!(f32, 4, 2, mat4x2, 0, 1, 2, 3);
+impl_as_std140_matrix!(f32, 4, 3, mat4x3, 0, 1, 2, 3);
+impl_as_std140_matrix!(f32, 4, 4, mat4x4, 0, 1, 2, 3);
+impl_as_std140_matrix!(f64, 2, 2, dmat2x2, 0, 1);
+impl_as_std140_matrix!(f64, 2, 3, dmat2x3, 0, 1);
+impl_as_std140_matrix!(f64, 2, 4, dmat2x4, 0, 1);
+impl_as_std140_matrix!(f64, 3, 2, dmat3x2, 0, 1, 2);
+impl_as_std140_matrix!(f64, 3, 3, dmat3x3, 0, 1, 2);
+impl_as_std140_matrix!(f64, 3, 4, dmat3x4, 0, 1, 2);
+impl_as_std140_matrix!(f64, 4, 2, dmat4x2, 0, 1, 2, 3);
+impl_as_std140_matrix!(f64, 4, 3, dmat4x3, 0, 1, 2, 3);
+impl_as_std140_matrix!(f64, 4, 4, dmat4x4, 0, 1, 2, 3);
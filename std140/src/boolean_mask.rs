@@ -0,0 +1,129 @@
+use core::fmt;
+
+use crate::std430::{ReprStd430, Std430ArrayElement};
+use crate::{uint, ReprStd140, Std140ArrayElement, Std140PaddingFree};
+
+/// Up to 32 flags packed into the bits of a single [uint].
+///
+/// Each [boolean][crate::boolean] in an [array][struct@crate::array] occupies 16 bytes, which
+/// matches GLSL's `bool flags[N]` but wastes space when a shader needs many flags. A
+/// [BooleanMask] is stored identically to a [uint] and is read in GLSL as such, e.g. with
+/// `(flags & (1u << index)) != 0u`.
+///
+/// # Example
+///
+/// ```
+/// let mut flags = std140::BooleanMask::new();
+///
+/// flags.set_bit(0, true);
+/// flags.set_bit(5, true);
+///
+/// assert!(flags.get_bit(5));
+/// assert!(!flags.get_bit(1));
+/// assert_eq!(flags.to_uint(), std140::uint(0b100001));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BooleanMask(uint);
+
+impl BooleanMask {
+    /// The number of flags a [BooleanMask] can hold.
+    pub const BITS: usize = 32;
+
+    /// Creates a new [BooleanMask] with all bits cleared.
+    #[must_use]
+    pub const fn new() -> Self {
+        BooleanMask(uint(0))
+    }
+
+    /// Creates a [BooleanMask] from the bits of a [uint].
+    #[must_use]
+    pub const fn from_uint(value: uint) -> Self {
+        BooleanMask(value)
+    }
+
+    /// Returns the bits of this mask as a [uint].
+    #[must_use]
+    pub const fn to_uint(self) -> uint {
+        self.0
+    }
+
+    /// Returns `true` if the bit at `index` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [BITS][BooleanMask::BITS].
+    #[must_use]
+    pub const fn get_bit(&self, index: usize) -> bool {
+        assert!(index < Self::BITS, "Index out of bounds");
+
+        self.0 .0 & (1 << index) != 0
+    }
+
+    /// Sets the bit at `index` if `value` is `true`, or clears it if `value` is `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [BITS][BooleanMask::BITS].
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < Self::BITS, "Index out of bounds");
+
+        if value {
+            self.0 .0 |= 1 << index;
+        } else {
+            self.0 .0 &= !(1 << index);
+        }
+    }
+
+    /// Returns an iterator over all [BITS][BooleanMask::BITS] bits of this mask, starting with the
+    /// least significant bit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let flags = std140::BooleanMask::from_uint(std140::uint(0b101));
+    ///
+    /// let set: Vec<usize> = flags
+    ///     .iter_bits()
+    ///     .enumerate()
+    ///     .filter(|(_, bit)| *bit)
+    ///     .map(|(index, _)| index)
+    ///     .collect();
+    ///
+    /// assert_eq!(set, vec![0, 2]);
+    /// ```
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> {
+        let bits = self.0 .0;
+
+        (0..Self::BITS).map(move |index| bits & (1 << index) != 0)
+    }
+}
+
+unsafe impl ReprStd140 for BooleanMask {
+    const GLSL_TYPE_NAME: &'static str = "uint";
+}
+unsafe impl Std140ArrayElement for BooleanMask {}
+unsafe impl Std140PaddingFree for BooleanMask {}
+
+unsafe impl ReprStd430 for BooleanMask {}
+unsafe impl Std430ArrayElement for BooleanMask {}
+
+impl From<uint> for BooleanMask {
+    fn from(value: uint) -> Self {
+        BooleanMask(value)
+    }
+}
+
+impl From<BooleanMask> for uint {
+    fn from(value: BooleanMask) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Debug for BooleanMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BooleanMask({:#034b})", self.0 .0)
+    }
+}
@@ -0,0 +1,58 @@
+//! Implementations of the [bytemuck] traits.
+//!
+//! An all-zero bit pattern is valid for every std140 type, so all types implement [Zeroable]. Only
+//! the types that contain no padding bytes (e.g. `vec4`, but not `vec3` or `array`) and no
+//! [boolean]s (for which most bit patterns are invalid) implement [Pod].
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::std430;
+use crate::*;
+
+macro_rules! impl_zeroable {
+    ($($ty:ty),*) => {
+        $(unsafe impl Zeroable for $ty {})*
+    };
+}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl Zeroable for $ty {}
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(float, int, uint, double, float16, BooleanMask);
+impl_pod!(vec2, vec4, ivec2, ivec4, uvec2, uvec4, dvec2, dvec4, f16vec2, f16vec4);
+impl_zeroable!(vec3, ivec3, uvec3, dvec3, f16vec3);
+impl_zeroable!(boolean, bvec2, bvec3, bvec4);
+
+unsafe impl<V, const C: usize> Zeroable for Matrix<V, { C }> where V: MatrixColumn {}
+
+// Columns of 4 components fill the 16 (or 32) byte array stride exactly.
+unsafe impl Pod for mat2x4 {}
+unsafe impl Pod for mat3x4 {}
+unsafe impl Pod for mat4x4 {}
+unsafe impl Pod for dmat2x4 {}
+unsafe impl Pod for dmat3x4 {}
+unsafe impl Pod for dmat4x4 {}
+
+unsafe impl<T, const LEN: usize> Zeroable for array<T, { LEN }> where T: Std140ArrayElement {}
+
+unsafe impl<M> Zeroable for RowMajor<M> where M: Zeroable {}
+unsafe impl<M> Pod for RowMajor<M> where M: Pod {}
+
+unsafe impl<T, const LEN: usize> Zeroable for std430::array<T, { LEN }> where
+    T: std430::Std430ArrayElement
+{
+}
+unsafe impl<T, const LEN: usize> Pod for std430::array<T, { LEN }> where
+    T: std430::Std430ArrayElement + Pod
+{
+}
+
+impl_pod!(std430::mat2x2, std430::mat3x2, std430::mat4x2);
+
+unsafe impl<T> Zeroable for std430::runtime_array<T> where T: std430::Std430ArrayElement {}
@@ -0,0 +1,65 @@
+//! Conversions between scalar and vector types of the same shape, mirroring the GLSL constructor
+//! conversions (e.g. `ivec4(u)` or `uvec2(b)`).
+//!
+//! Conversions between the signed and unsigned integer types preserve the bit pattern (like an
+//! `as` cast between `i32` and `u32`), rather than clamping the value: `uint(u32::MAX)` converts
+//! to `int(-1)` and `int(-1)` converts back to `uint(u32::MAX)`. [boolean::True] converts to `1`
+//! and [boolean::False] converts to `0`.
+
+use crate::*;
+
+impl From<uint> for int {
+    fn from(value: uint) -> Self {
+        int(value.0 as i32)
+    }
+}
+
+impl From<int> for uint {
+    fn from(value: int) -> Self {
+        uint(value.0 as u32)
+    }
+}
+
+impl From<boolean> for uint {
+    fn from(value: boolean) -> Self {
+        uint(value as u32)
+    }
+}
+
+impl From<boolean> for int {
+    fn from(value: boolean) -> Self {
+        int(value as i32)
+    }
+}
+
+macro_rules! impl_vector_conversions {
+    ($ivec:ident, $uvec:ident, $bvec:ident, $($c:tt),*) => {
+        impl From<$uvec> for $ivec {
+            fn from(value: $uvec) -> Self {
+                $ivec($(value.$c as i32),*)
+            }
+        }
+
+        impl From<$ivec> for $uvec {
+            fn from(value: $ivec) -> Self {
+                $uvec($(value.$c as u32),*)
+            }
+        }
+
+        impl From<$bvec> for $uvec {
+            fn from(value: $bvec) -> Self {
+                $uvec($(value.$c as u32),*)
+            }
+        }
+
+        impl From<$bvec> for $ivec {
+            fn from(value: $bvec) -> Self {
+                $ivec($(value.$c as i32),*)
+            }
+        }
+    };
+}
+
+impl_vector_conversions!(ivec2, uvec2, bvec2, 0, 1);
+impl_vector_conversions!(ivec3, uvec3, bvec3, 0, 1, 2);
+impl_vector_conversions!(ivec4, uvec4, bvec4, 0, 1, 2, 3);
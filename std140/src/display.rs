@@ -0,0 +1,146 @@
+//! User-facing `Display` formatting for the vector and matrix types.
+//!
+//! Vectors are formatted as a parenthesized list of their components, e.g. `(1, 0.5, 0)`.
+//! Matrices are formatted as one bracketed row per line (even though they are stored in
+//! column-major order), with the elements of each column right-aligned. A precision (e.g. `{:.2}`)
+//! applies to each component or element.
+
+use core::fmt::{self, Display, Write};
+
+use crate::*;
+
+macro_rules! impl_vector_display {
+    ($vector:ident, $($c:tt),*) => {
+        impl fmt::Display for $vector {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let components = [$(&self.$c as &dyn Display),*];
+
+                f.write_char('(')?;
+
+                for (i, component) in components.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write_element(f, *component, f.precision())?;
+                }
+
+                f.write_char(')')
+            }
+        }
+    };
+}
+
+impl_vector_display!(vec2, 0, 1);
+impl_vector_display!(vec3, 0, 1, 2);
+impl_vector_display!(vec4, 0, 1, 2, 3);
+impl_vector_display!(dvec2, 0, 1);
+impl_vector_display!(dvec3, 0, 1, 2);
+impl_vector_display!(dvec4, 0, 1, 2, 3);
+impl_vector_display!(ivec2, 0, 1);
+impl_vector_display!(ivec3, 0, 1, 2);
+impl_vector_display!(ivec4, 0, 1, 2, 3);
+impl_vector_display!(uvec2, 0, 1);
+impl_vector_display!(uvec3, 0, 1, 2);
+impl_vector_display!(uvec4, 0, 1, 2, 3);
+impl_vector_display!(bvec2, 0, 1);
+impl_vector_display!(bvec3, 0, 1, 2);
+impl_vector_display!(bvec4, 0, 1, 2, 3);
+
+impl fmt::Display for boolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_bool(), f)
+    }
+}
+
+macro_rules! impl_matrix_display {
+    ($matrix:ident, $columns:literal, $rows:literal) => {
+        impl fmt::Display for $matrix {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let precision = f.precision();
+                let mut widths = [0; $columns];
+
+                for (c, width) in widths.iter_mut().enumerate() {
+                    for r in 0..$rows {
+                        *width = (*width).max(element_width(&self.col(c)[r], precision));
+                    }
+                }
+
+                for r in 0..$rows {
+                    if r > 0 {
+                        f.write_char('\n')?;
+                    }
+
+                    f.write_char('[')?;
+
+                    for (c, width) in widths.iter().enumerate() {
+                        if c > 0 {
+                            f.write_str(", ")?;
+                        }
+
+                        let element = self.col(c)[r];
+
+                        for _ in element_width(&element, precision)..*width {
+                            f.write_char(' ')?;
+                        }
+
+                        write_element(f, &element, precision)?;
+                    }
+
+                    f.write_char(']')?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_matrix_display!(mat2x2, 2, 2);
+impl_matrix_display!(mat2x3, 2, 3);
+impl_matrix_display!(mat2x4, 2, 4);
+impl_matrix_display!(mat3x2, 3, 2);
+impl_matrix_display!(mat3x3, 3, 3);
+impl_matrix_display!(mat3x4, 3, 4);
+impl_matrix_display!(mat4x2, 4, 2);
+impl_matrix_display!(mat4x3, 4, 3);
+impl_matrix_display!(mat4x4, 4, 4);
+impl_matrix_display!(dmat2x2, 2, 2);
+impl_matrix_display!(dmat2x3, 2, 3);
+impl_matrix_display!(dmat2x4, 2, 4);
+impl_matrix_display!(dmat3x2, 3, 2);
+impl_matrix_display!(dmat3x3, 3, 3);
+impl_matrix_display!(dmat3x4, 3, 4);
+impl_matrix_display!(dmat4x2, 4, 2);
+impl_matrix_display!(dmat4x3, 4, 3);
+impl_matrix_display!(dmat4x4, 4, 4);
+
+fn write_element<W>(w: &mut W, element: &dyn Display, precision: Option<usize>) -> fmt::Result
+where
+    W: Write,
+{
+    match precision {
+        Some(precision) => write!(w, "{:.*}", precision, element),
+        None => write!(w, "{}", element),
+    }
+}
+
+/// Returns the number of characters `element` occupies when written with [write_element].
+fn element_width(element: &dyn Display, precision: Option<usize>) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+
+    // Writing to a `Counter` cannot fail.
+    let _ = write_element(&mut counter, element, precision);
+
+    counter.0
+}
@@ -0,0 +1,82 @@
+//! Conversions from the std140 matrix types to [glam] matrix types.
+
+use glam::{Mat4, Vec4};
+
+use crate::{mat3x4, mat4x3};
+
+impl mat4x3 {
+    /// Converts this matrix into a [glam::Mat4] by adding a 4th row.
+    ///
+    /// The 4th row is `[0, 0, 0, 1]`: the 3 leading columns are padded with a `0` and the 4th column
+    /// is padded with a `1`. If this matrix represents an affine transformation (with the
+    /// translation in the 4th column), then the resulting [glam::Mat4] represents the same
+    /// transformation in homogeneous coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = std140::mat4x3(
+    ///     std140::vec3(1.0, 0.0, 0.0),
+    ///     std140::vec3(0.0, 1.0, 0.0),
+    ///     std140::vec3(0.0, 0.0, 1.0),
+    ///     std140::vec3(2.0, 3.0, 4.0),
+    /// );
+    ///
+    /// assert_eq!(matrix.to_mat4(), glam::Mat4::from_translation(glam::Vec3::new(2.0, 3.0, 4.0)));
+    /// ```
+    #[must_use]
+    pub fn to_mat4(&self) -> Mat4 {
+        let c = &self.columns.internal;
+
+        Mat4::from_cols(
+            Vec4::new(c[0].element.0, c[0].element.1, c[0].element.2, 0.0),
+            Vec4::new(c[1].element.0, c[1].element.1, c[1].element.2, 0.0),
+            Vec4::new(c[2].element.0, c[2].element.1, c[2].element.2, 0.0),
+            Vec4::new(c[3].element.0, c[3].element.1, c[3].element.2, 1.0),
+        )
+    }
+}
+
+impl mat3x4 {
+    /// Converts this matrix into a [glam::Mat4] by adding a 4th column.
+    ///
+    /// The 4th column is `[0, 0, 0, 1]`; the 3 existing columns are copied unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = std140::mat3x4(
+    ///     std140::vec4(1.0, 0.0, 0.0, 0.0),
+    ///     std140::vec4(0.0, 1.0, 0.0, 0.0),
+    ///     std140::vec4(0.0, 0.0, 1.0, 0.0),
+    /// );
+    ///
+    /// assert_eq!(matrix.to_mat4(), glam::Mat4::IDENTITY);
+    /// ```
+    #[must_use]
+    pub fn to_mat4(&self) -> Mat4 {
+        let c = &self.columns.internal;
+
+        Mat4::from_cols(
+            Vec4::new(
+                c[0].element.0,
+                c[0].element.1,
+                c[0].element.2,
+                c[0].element.3,
+            ),
+            Vec4::new(
+                c[1].element.0,
+                c[1].element.1,
+                c[1].element.2,
+                c[1].element.3,
+            ),
+            Vec4::new(
+                c[2].element.0,
+                c[2].element.1,
+                c[2].element.2,
+                c[2].element.3,
+            ),
+            Vec4::W,
+        )
+    }
+}
@@ -0,0 +1,266 @@
+use core::fmt;
+
+use crate::le_bytes::{f32_from_bits, f32_to_bits};
+use crate::std430::{ReprStd430, Std430ArrayElement};
+use crate::{
+    initialized_bytes, vec2, vec3, vec4, ReprStd140, Std140ArrayElement, Std140PaddingFree,
+};
+
+/// A 16-bit (half precision) floating point value, as used by `float16_t` in GLSL.
+///
+/// Using 16-bit values in uniform blocks requires the `GL_EXT_shader_16bit_storage` GLSL
+/// extension (and the corresponding device feature in Vulkan). The value is stored as its IEEE 754
+/// binary16 bit pattern; use [from_f32][float16::from_f32] and [to_f32][float16::to_f32] (or the
+/// [From] conversions) to convert from and to an `f32`.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::float16::from_f32(0.5);
+///
+/// assert_eq!(value.to_bits(), 0x3800);
+/// assert_eq!(f32::from(value), 0.5);
+/// ```
+#[repr(C, align(2))]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "f32", into = "f32"))]
+pub struct float16(u16);
+
+impl float16 {
+    /// Creates a [float16] from its IEEE 754 binary16 bit pattern.
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        float16(bits)
+    }
+
+    /// Returns the IEEE 754 binary16 bit pattern of this value.
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts an `f32` to the nearest [float16], rounding ties to even.
+    ///
+    /// Values that are too large to be represented become an infinity with the same sign; values
+    /// that are too small become a (signed) zero.
+    #[must_use]
+    pub const fn from_f32(value: f32) -> Self {
+        let bits = f32_to_bits(value);
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x007f_ffff;
+
+        if exponent == 0xff {
+            // Infinity or NaN; keep NaNs quiet.
+            let nan = if mantissa != 0 {
+                0x0200 | (mantissa >> 13) as u16
+            } else {
+                0
+            };
+
+            return float16(sign | 0x7c00 | nan);
+        }
+
+        let exponent = exponent - 127 + 15;
+
+        if exponent >= 0x1f {
+            return float16(sign | 0x7c00);
+        }
+
+        if exponent <= 0 {
+            if exponent < -10 {
+                return float16(sign);
+            }
+
+            let mantissa = mantissa | 0x0080_0000;
+            let shift = (14 - exponent) as u32;
+            let round_bit = 1 << (shift - 1);
+            let mut result = (mantissa >> shift) as u16;
+
+            if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+                result += 1;
+            }
+
+            return float16(sign | result);
+        }
+
+        let round_bit = 0x1000;
+        let mut result = ((exponent as u16) << 10) | (mantissa >> 13) as u16;
+
+        // A carry out of the mantissa correctly increments the exponent (up to infinity).
+        if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+            result += 1;
+        }
+
+        float16(sign | result)
+    }
+
+    /// Converts this value to an `f32`; every [float16] value can be represented exactly.
+    #[must_use]
+    pub const fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exponent = ((self.0 >> 10) & 0x1f) as u32;
+        let mantissa = (self.0 & 0x03ff) as u32;
+
+        let bits = match exponent {
+            0 if mantissa == 0 => sign,
+            0 => {
+                // Subnormal: normalize the mantissa.
+                let shift = mantissa.leading_zeros() - 21;
+                let mantissa = (mantissa << shift) & 0x03ff;
+
+                sign | ((127 - 15 + 1 - shift) << 23) | (mantissa << 13)
+            }
+            0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+            _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+        };
+
+        f32_from_bits(bits)
+    }
+}
+
+unsafe impl ReprStd140 for float16 {
+    const GLSL_TYPE_NAME: &'static str = "float16_t";
+}
+unsafe impl Std140ArrayElement for float16 {}
+unsafe impl Std140PaddingFree for float16 {}
+unsafe impl ReprStd430 for float16 {}
+unsafe impl Std430ArrayElement for float16 {}
+
+impl PartialEq for float16 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl From<f32> for float16 {
+    fn from(value: f32) -> Self {
+        float16::from_f32(value)
+    }
+}
+
+impl From<float16> for f32 {
+    fn from(value: float16) -> Self {
+        value.to_f32()
+    }
+}
+
+impl fmt::Debug for float16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("float16").field(&self.to_f32()).finish()
+    }
+}
+
+macro_rules! impl_f16vec {
+    ($vector:ident, $f32_vector:ident, $($c:tt),*) => {
+        unsafe impl Std140ArrayElement for $vector {}
+        unsafe impl ReprStd430 for $vector {}
+        unsafe impl Std430ArrayElement for $vector {}
+
+        impl From<$f32_vector> for $vector {
+            fn from(value: $f32_vector) -> Self {
+                $vector($(float16::from_f32(value.$c)),*)
+            }
+        }
+
+        impl From<$vector> for $f32_vector {
+            fn from(value: $vector) -> Self {
+                $f32_vector($(value.$c.to_f32()),*)
+            }
+        }
+    };
+}
+
+/// A column vector of 2 [float16] values.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::f16vec2::from(std140::vec2(0.0, 1.0));
+/// ```
+#[repr(C, align(4))]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct f16vec2(pub float16, pub float16);
+
+impl f16vec2 {
+    /// Creates a new [f16vec2] with zeros in all positions.
+    #[must_use]
+    pub const fn zero() -> Self {
+        f16vec2(float16(0), float16(0))
+    }
+}
+
+unsafe impl ReprStd140 for f16vec2 {
+    const GLSL_TYPE_NAME: &'static str = "f16vec2";
+}
+
+unsafe impl Std140PaddingFree for f16vec2 {}
+
+impl_f16vec!(f16vec2, vec2, 0, 1);
+
+/// A column vector of 3 [float16] values.
+///
+/// Like a [vec3][struct@vec3], this type is padded in Rust to the size of its 4-component
+/// counterpart, but occupies only 6 bytes in a std140 block.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::f16vec3::from(std140::vec3(0.0, 0.0, 1.0));
+/// ```
+#[repr(C, align(8))]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct f16vec3(pub float16, pub float16, pub float16);
+
+impl f16vec3 {
+    /// Creates a new [f16vec3] with zeros in all positions.
+    #[must_use]
+    pub const fn zero() -> Self {
+        f16vec3(float16(0), float16(0), float16(0))
+    }
+}
+
+unsafe impl ReprStd140 for f16vec3 {
+    const STD140_SIZE: usize = 6;
+
+    const GLSL_TYPE_NAME: &'static str = "f16vec3";
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
+
+impl_f16vec!(f16vec3, vec3, 0, 1, 2);
+
+/// A column vector of 4 [float16] values.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::f16vec4::from(std140::vec4(0.0, 0.0, 0.0, 1.0));
+/// ```
+#[repr(C, align(8))]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct f16vec4(pub float16, pub float16, pub float16, pub float16);
+
+impl f16vec4 {
+    /// Creates a new [f16vec4] with zeros in all positions.
+    #[must_use]
+    pub const fn zero() -> Self {
+        f16vec4(float16(0), float16(0), float16(0), float16(0))
+    }
+}
+
+unsafe impl ReprStd140 for f16vec4 {
+    const GLSL_TYPE_NAME: &'static str = "f16vec4";
+}
+
+unsafe impl Std140PaddingFree for f16vec4 {}
+
+impl_f16vec!(f16vec4, vec4, 0, 1, 2, 3);
@@ -0,0 +1,105 @@
+//! Compile-time construction of [Std140Struct::LAYOUT_DESCRIPTION][crate::Std140Struct].
+//!
+//! Formatting is not available in a const context, so the description is written into a
+//! fixed-capacity byte buffer that is sized by `#[repr_std140]`. Unused capacity is filled with
+//! zero bytes, which are trimmed off when the buffer is converted into a string.
+
+/// A fixed-capacity buffer that a layout description is written into.
+pub struct LayoutDescription<const CAP: usize> {
+    bytes: [u8; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> LayoutDescription<CAP> {
+    pub const fn new() -> Self {
+        LayoutDescription {
+            bytes: [0; CAP],
+            len: 0,
+        }
+    }
+
+    pub const fn push_str(mut self, s: &str) -> Self {
+        let s = s.as_bytes();
+        let mut i = 0;
+
+        while i < s.len() {
+            self.bytes[self.len] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+
+        self
+    }
+
+    pub const fn push_usize(mut self, mut value: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut count = 0;
+
+        loop {
+            digits[count] = b'0' + (value % 10) as u8;
+            value /= 10;
+            count += 1;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        while count > 0 {
+            count -= 1;
+            self.bytes[self.len] = digits[count];
+            self.len += 1;
+        }
+
+        self
+    }
+
+    /// Appends a line that describes a single field.
+    pub const fn push_field(self, name: &str, ty: &str, offset: usize, size: usize) -> Self {
+        self.push_str(name)
+            .push_str(": ")
+            .push_str(ty)
+            .push_str(" (offset ")
+            .push_usize(offset)
+            .push_str(", size ")
+            .push_usize(size)
+            .push_str(")\n")
+    }
+
+    pub const fn into_bytes(self) -> [u8; CAP] {
+        self.bytes
+    }
+
+    /// Returns the text that has been written so far.
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => panic!("layout description is not valid UTF-8"),
+        }
+    }
+}
+
+impl<const CAP: usize> Default for LayoutDescription<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Converts the bytes of a layout description into a string, dropping the unused capacity and the
+/// final line break.
+pub const fn layout_description_str(bytes: &'static [u8]) -> &'static str {
+    let mut len = 0;
+
+    while len < bytes.len() && bytes[len] != 0 {
+        len += 1;
+    }
+
+    if len > 0 && bytes[len - 1] == b'\n' {
+        len -= 1;
+    }
+
+    match core::str::from_utf8(bytes.split_at(len).0) {
+        Ok(description) => description,
+        Err(_) => panic!("layout description is not valid UTF-8"),
+    }
+}
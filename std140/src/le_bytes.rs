@@ -0,0 +1,204 @@
+//! Conversions between the scalar and vector types and their little-endian byte representation.
+//!
+//! [as_bytes][crate::AsBytes::as_bytes] returns the bytes in the byte order of the host. GPUs are
+//! little-endian, so a buffer that is written on a big-endian host (e.g. a dump that is created
+//! in CI and loaded elsewhere) has to be converted explicitly; these methods produce (or read)
+//! little-endian bytes regardless of the host.
+//!
+//! A vector is represented by the bytes of its components only, so a `vec3` occupies 12 bytes
+//! (its [STD140_SIZE][crate::ReprStd140::STD140_SIZE]) rather than the 16 bytes of the Rust type.
+//! A [boolean] is represented like a [uint]; when converting back, any non-zero value becomes
+//! [boolean::True].
+
+use crate::*;
+
+const fn read_chunk<const N: usize>(bytes: &[u8], offset: usize) -> [u8; N] {
+    let mut chunk = [0; N];
+    let mut i = 0;
+
+    while i < N {
+        chunk[i] = bytes[offset + i];
+        i += 1;
+    }
+
+    chunk
+}
+
+const fn write_chunk<const LEN: usize, const N: usize>(
+    mut bytes: [u8; LEN],
+    offset: usize,
+    chunk: [u8; N],
+) -> [u8; LEN] {
+    let mut i = 0;
+
+    while i < N {
+        bytes[offset + i] = chunk[i];
+        i += 1;
+    }
+
+    bytes
+}
+
+// The bit and byte conversions of `f32` and `f64` are only `const` since Rust 1.83, so the
+// floating point types are converted by transmuting them to and from their bit patterns instead
+// (which newer compilers consider unnecessary).
+
+#[allow(unknown_lints, unnecessary_transmutes)]
+pub(crate) const fn f32_to_bits(value: f32) -> u32 {
+    unsafe { core::mem::transmute(value) }
+}
+
+#[allow(unknown_lints, unnecessary_transmutes)]
+pub(crate) const fn f32_from_bits(bits: u32) -> f32 {
+    unsafe { core::mem::transmute(bits) }
+}
+
+#[allow(unknown_lints, unnecessary_transmutes)]
+const fn f64_to_bits(value: f64) -> u64 {
+    unsafe { core::mem::transmute(value) }
+}
+
+#[allow(unknown_lints, unnecessary_transmutes)]
+const fn f64_from_bits(bits: u64) -> f64 {
+    unsafe { core::mem::transmute(bits) }
+}
+
+const fn f32_to_le_bytes(value: f32) -> [u8; 4] {
+    f32_to_bits(value).to_le_bytes()
+}
+
+const fn f32_from_le_bytes(bytes: [u8; 4]) -> f32 {
+    f32_from_bits(u32::from_le_bytes(bytes))
+}
+
+const fn f64_to_le_bytes(value: f64) -> [u8; 8] {
+    f64_to_bits(value).to_le_bytes()
+}
+
+const fn f64_from_le_bytes(bytes: [u8; 8]) -> f64 {
+    f64_from_bits(u64::from_le_bytes(bytes))
+}
+
+macro_rules! impl_scalar_le_bytes {
+    ($scalar:ident, $to_le_bytes:path, $from_le_bytes:path, $size:literal) => {
+        impl $scalar {
+            #[doc = concat!("Returns the little-endian byte representation of this [", stringify!($scalar), "].")]
+            #[must_use]
+            pub const fn to_le_bytes(self) -> [u8; $size] {
+                $to_le_bytes(self.0)
+            }
+
+            #[doc = concat!("Creates a [", stringify!($scalar), "] from its little-endian byte representation.")]
+            #[must_use]
+            pub const fn from_le_bytes(bytes: [u8; $size]) -> Self {
+                $scalar($from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_scalar_le_bytes!(float, f32_to_le_bytes, f32_from_le_bytes, 4);
+impl_scalar_le_bytes!(double, f64_to_le_bytes, f64_from_le_bytes, 8);
+impl_scalar_le_bytes!(int, i32::to_le_bytes, i32::from_le_bytes, 4);
+impl_scalar_le_bytes!(uint, u32::to_le_bytes, u32::from_le_bytes, 4);
+
+impl boolean {
+    /// Returns the little-endian byte representation of this [boolean], which is that of
+    /// [uint_value][boolean::uint_value].
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.uint_value().to_le_bytes()
+    }
+
+    /// Creates a [boolean] from the little-endian byte representation of a `u32`; any non-zero
+    /// value becomes [boolean::True] (see [from_raw][boolean::from_raw]).
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        boolean::from_raw(u32::from_le_bytes(bytes))
+    }
+}
+
+macro_rules! impl_vector_le_bytes {
+    ($vector:ident, $to_le_bytes:path, $from_le_bytes:path, $size:literal, $len:literal, $($c:tt),*) => {
+        impl $vector {
+            #[doc = concat!("Returns the little-endian byte representation of the components of this [", stringify!($vector), "], in order.")]
+            #[must_use]
+            pub const fn to_le_bytes(self) -> [u8; $len] {
+                let mut bytes = [0; $len];
+
+                $(bytes = write_chunk(bytes, $c * $size, $to_le_bytes(self.$c));)*
+
+                bytes
+            }
+
+            #[doc = concat!("Creates a [", stringify!($vector), "] from the little-endian byte representation of its components.")]
+            #[must_use]
+            pub const fn from_le_bytes(bytes: [u8; $len]) -> Self {
+                $vector($($from_le_bytes(read_chunk(&bytes, $c * $size))),*)
+            }
+        }
+    };
+}
+
+impl_vector_le_bytes!(vec2, f32_to_le_bytes, f32_from_le_bytes, 4, 8, 0, 1);
+impl_vector_le_bytes!(vec3, f32_to_le_bytes, f32_from_le_bytes, 4, 12, 0, 1, 2);
+impl_vector_le_bytes!(vec4, f32_to_le_bytes, f32_from_le_bytes, 4, 16, 0, 1, 2, 3);
+impl_vector_le_bytes!(dvec2, f64_to_le_bytes, f64_from_le_bytes, 8, 16, 0, 1);
+impl_vector_le_bytes!(dvec3, f64_to_le_bytes, f64_from_le_bytes, 8, 24, 0, 1, 2);
+impl_vector_le_bytes!(dvec4, f64_to_le_bytes, f64_from_le_bytes, 8, 32, 0, 1, 2, 3);
+impl_vector_le_bytes!(ivec2, i32::to_le_bytes, i32::from_le_bytes, 4, 8, 0, 1);
+impl_vector_le_bytes!(ivec3, i32::to_le_bytes, i32::from_le_bytes, 4, 12, 0, 1, 2);
+impl_vector_le_bytes!(
+    ivec4,
+    i32::to_le_bytes,
+    i32::from_le_bytes,
+    4,
+    16,
+    0,
+    1,
+    2,
+    3
+);
+impl_vector_le_bytes!(uvec2, u32::to_le_bytes, u32::from_le_bytes, 4, 8, 0, 1);
+impl_vector_le_bytes!(uvec3, u32::to_le_bytes, u32::from_le_bytes, 4, 12, 0, 1, 2);
+impl_vector_le_bytes!(
+    uvec4,
+    u32::to_le_bytes,
+    u32::from_le_bytes,
+    4,
+    16,
+    0,
+    1,
+    2,
+    3
+);
+impl_vector_le_bytes!(
+    bvec2,
+    boolean::to_le_bytes,
+    boolean::from_le_bytes,
+    4,
+    8,
+    0,
+    1
+);
+impl_vector_le_bytes!(
+    bvec3,
+    boolean::to_le_bytes,
+    boolean::from_le_bytes,
+    4,
+    12,
+    0,
+    1,
+    2
+);
+impl_vector_le_bytes!(
+    bvec4,
+    boolean::to_le_bytes,
+    boolean::from_le_bytes,
+    4,
+    16,
+    0,
+    1,
+    2,
+    3
+);
@@ -1,3 +1,4 @@
+#![no_std]
 #![allow(non_camel_case_types)]
 
 //! This module contains types that may be used to define Rust struct types that match the GLSL
@@ -24,18 +25,18 @@
 //! - `vec2`: [vec2]
 //! - `vec3`: [vec3]
 //! - `vec4`: [vec4]
-//! - `mat2`: [mat2x2][struct@mat2x2]
-//! - `mat3`: [mat3x3][struct@mat3x3]
-//! - `mat4`: [mat4x4][struct@mat4x4]
-//! - `mat2x2`: [mat2x2][struct@mat2x2]
-//! - `mat2x3`: [mat2x3][struct@mat2x3]
-//! - `mat2x4`: [mat2x4][struct@mat2x4]
-//! - `mat3x2`: [mat3x2][struct@mat3x2]
-//! - `mat3x3`: [mat3x3][struct@mat3x3]
-//! - `mat3x4`: [mat3x4][struct@mat3x4]
-//! - `mat4x2`: [mat4x2][struct@mat4x2]
-//! - `mat4x3`: [mat4x3][struct@mat4x3]
-//! - `mat4x4`: [mat4x4][struct@mat4x4]
+//! - `mat2`: [mat2x2][type@mat2x2]
+//! - `mat3`: [mat3x3][type@mat3x3]
+//! - `mat4`: [mat4x4][type@mat4x4]
+//! - `mat2x2`: [mat2x2][type@mat2x2]
+//! - `mat2x3`: [mat2x3][type@mat2x3]
+//! - `mat2x4`: [mat2x4][type@mat2x4]
+//! - `mat3x2`: [mat3x2][type@mat3x2]
+//! - `mat3x3`: [mat3x3][type@mat3x3]
+//! - `mat3x4`: [mat3x4][type@mat3x4]
+//! - `mat4x2`: [mat4x2][type@mat4x2]
+//! - `mat4x3`: [mat4x3][type@mat4x3]
+//! - `mat4x4`: [mat4x4][type@mat4x4]
 //! - `int`: [int]
 //! - `ivec2`: [ivec2]
 //! - `ivec3`: [ivec3]
@@ -113,158 +114,2646 @@
 //! Note that although the field names match the block member names in this example, this is not
 //! strictly necessary: only pairwise field-type compatibility is required.
 //!
+//! # Std430
+//!
+//! Shader storage blocks may use the tighter std430 layout instead. The [std430] module provides
+//! the array type and the [`#[repr_std430]`][std430::repr_std430] attribute for declaring structs
+//! with a std430 compatible memory layout.
+//!
+//! # Integrating with other crates
+//!
+//! Crates that upload data to GPU buffers can accept any std140 value without `unsafe` code by
+//! bounding on [AsBytes]: it is implemented for all scalar, vector, matrix and array types, as well
+//! as for all structs marked with [`#[repr_std140]`][repr_std140]. Its [ReprStd140] supertrait
+//! exposes the base alignment and size of the value through
+//! [STD140_ALIGNMENT][ReprStd140::STD140_ALIGNMENT] and [STD140_SIZE][ReprStd140::STD140_SIZE]:
+//!
+//! ```
+//! use std140::AsBytes;
+//!
+//! /// Appends a std140 value to a buffer and returns the offset at which it was written.
+//! fn push<T: AsBytes>(buffer: &mut Vec<u8>, value: &T) -> usize {
+//!     let offset = buffer.len().next_multiple_of(T::STD140_ALIGNMENT);
+//!
+//!     buffer.resize(offset + std::mem::size_of::<T>(), 0);
+//!     value.write_bytes(&mut buffer[offset..]);
+//!
+//!     offset
+//! }
+//!
+//! let mut buffer = Vec::new();
+//!
+//! assert_eq!(push(&mut buffer, &std140::float(1.0)), 0);
+//! assert_eq!(push(&mut buffer, &std140::vec3(0.0, 0.0, 1.0)), 16);
+//! ```
+//!
+//! This is also the way to hand values to crates with their own layout machinery (such as
+//! `encase`): pass the bytes written by [write_bytes][AsBytes::write_bytes] to their byte-level
+//! APIs. [write_bytes][AsBytes::write_bytes] writes zeros in place of the padding bytes the std140
+//! layout rules require, which are uninitialized in the value itself.
+//!
+//! # Optional features
+//!
+//! - `std` (enabled by default): links the standard library. Without it, the crate is `no_std` and
+//!   does not require an allocator; [Std140Struct::flat_field_paths],
+//!   [Std140Struct::glsl_declaration], [array::to_vec] and the `rsqrt` methods are only available
+//!   with this feature.
+//! - `mint`: enables conversions between the std140 vector and matrix types and the corresponding
+//!   [mint](https://docs.rs/mint) types, as a bridge to any math library that supports `mint`.
+//! - `serde`: implements `Serialize` and `Deserialize` from [serde](https://docs.rs/serde) for all
+//!   scalar, vector, matrix and array types.
+//! - `bytemuck`: implements `Zeroable` from [bytemuck](https://docs.rs/bytemuck) for all types,
+//!   including structs marked with [`#[repr_std140]`][repr_std140], and `Pod` for the types that
+//!   contain neither padding nor [boolean]s.
+//! - `glam`: enables conversions from std140 matrix types to [glam](https://docs.rs/glam) matrix
+//!   types.
+//! - `approx`: implements `AbsDiffEq`, `RelativeEq` and `UlpsEq` from
+//!   [approx](https://docs.rs/approx) for the floating point scalar, vector and matrix types, and
+//!   for arrays of types that implement them, so that values can be compared with
+//!   `assert_relative_eq!`.
+//!
 //! [repr_std140]: attr.repr_std140.html
 
-use std::fmt;
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator};
+use core::mem;
+use core::ops::{
+    BitAnd, BitOr, BitXor, Deref, DerefMut, Index, IndexMut, Not, Range, RangeFrom, RangeFull,
+    RangeInclusive, RangeTo, RangeToInclusive,
+};
+use core::slice;
+
+#[cfg(feature = "std")]
+use std::{format, string::String, string::ToString, vec, vec::Vec};
+
+mod as_std140;
+mod boolean_mask;
+mod convert;
+mod display;
+mod half;
+mod layout_description;
+mod le_bytes;
+mod ops;
+mod padding;
+mod row_major;
+mod swizzle;
+mod writer;
+
+pub mod prelude;
+pub mod std430;
+
+#[cfg(feature = "approx")]
+mod approx_impls;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+
+#[cfg(feature = "glam")]
+mod glam_impls;
+
+#[cfg(feature = "mint")]
+mod mint_impls;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+/// Attribute macro that can be applied to a struct to ensure its representation is compatible with
+/// the std140 memory layout convention.
+///
+/// Can only be applied to a struct if all of its fields implement [ReprStd140].
+///
+/// Any struct marked with this attribute will automatically implement [Std140Struct]
+///
+/// The struct is given a base alignment of 16 bytes (or 32 bytes if it contains a `dvec3` or
+/// `dvec4`), as std140 requires. Because the size of a Rust type is always a multiple of its
+/// alignment, this also pads the size of the struct to a multiple of 16 bytes, even if it only
+/// holds a single `float`; no explicit padding fields are necessary.
+///
+/// # Example
+///
+/// ```rust
+/// #[std140::repr_std140]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// #[std140::repr_std140]
+/// struct Exposure {
+///     value: std140::float,
+/// }
+///
+/// assert_eq!(std::mem::size_of::<Exposure>(), 16);
+/// ```
+///
+/// # Combining with other attributes
+///
+/// Other attributes on the struct and its fields are preserved, including doc comments, `#[cfg]`
+/// attributes and derives (e.g. `#[derive(Clone, Copy, PartialEq, Debug)]`). The macro determines
+/// the struct's representation itself (`#[repr(C, align(16))]`), so a `#[repr]` attribute may only
+/// contain the redundant hints `C` and `align(N)` with `N` no greater than `16`; other hints, such
+/// as `packed` or `transparent`, would break the std140 layout and result in a compile error.
+///
+/// ```rust
+/// /// A point light.
+/// #[std140::repr_std140]
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// struct PointLight {
+///     /// The position of the light in world space.
+///     position: std140::vec3,
+///     #[cfg(feature = "falloff")]
+///     falloff: std140::float,
+///     intensity: std140::float,
+/// }
+/// ```
+///
+/// Note that a conditionally compiled field changes the std140 layout of the struct between build
+/// configurations: the GLSL declaration has to change along with it. The `warn_cfg_fields`
+/// argument makes the macro emit a (deprecation) warning for each field with a `#[cfg]` attribute,
+/// regardless of whether the field is currently included, to flag such layout changes:
+///
+/// ```rust
+/// #[std140::repr_std140(warn_cfg_fields)]
+/// struct Uniforms {
+///     transform: std140::mat4x4,
+///     // Warns: including this field depends on the build configuration.
+///     #[cfg(debug_assertions)]
+///     debug_color: std140::vec4,
+/// }
+/// ```
+///
+/// # Generic structs
+///
+/// The attribute may also be applied to generic structs, including structs with const generic
+/// parameters. Type parameters that are used as field types must be bounded by [ReprStd140] (or
+/// [Std140ArrayElement], when used as the element type of an [array][struct@array]):
+///
+/// ```rust
+/// #[std140::repr_std140]
+/// struct Lights<T, const N: usize>
+/// where
+///     T: std140::Std140ArrayElement,
+/// {
+///     count: std140::uint,
+///     lights: std140::array<T, N>,
+/// }
+/// ```
+///
+/// # Tuple structs
+///
+/// Tuple structs are supported; their fields are named by their position (e.g. `1` or `2[0]` in
+/// [Std140Struct::std140_layout] and [std140_offset_of]). Unit structs and structs without fields
+/// are rejected, as GLSL does not allow empty structs or blocks:
+///
+/// ```rust
+/// #[std140::repr_std140]
+/// struct PointLight(std140::vec3, std140::float);
+/// ```
+///
+/// # Layout assertions
+///
+/// Note that a `vec3` (or `ivec3`, `uvec3`, `bvec3`, `dvec3`) occupies the full 16 (or 32) bytes
+/// of its alignment in Rust, whereas std140 allows a following scalar to be packed into its last 4
+/// (or 8) bytes. The `assert_layout` argument makes the macro verify at compile time that every
+/// field starts at the offset std140 mandates, and that the size of the struct matches the std140
+/// size. A mismatch results in a compile error for the offending field:
+///
+/// ```compile_fail
+/// #[std140::repr_std140(assert_layout)]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float, // std140 places this field at offset 12, Rust at offset 16
+/// }
+/// ```
+///
+/// Reordering the fields (or using a `vec4` for `position`) resolves the mismatch:
+///
+/// ```rust
+/// #[std140::repr_std140(assert_layout)]
+/// struct PointLight {
+///     intensity: std140::float,
+///     position: std140::vec3,
+/// }
+/// ```
+///
+/// Layout assertions are not supported on generic structs.
+///
+/// # Padding warnings
+///
+/// The `warn_padding` argument makes the macro compare the size of the struct with the size that
+/// results from a reordering of its fields, and emit a (deprecation) warning if reordering would
+/// reduce the padding. As the order of the fields must match the order of the GLSL declaration, the
+/// warning is advisory only. The following struct is 48 bytes, but only needs 32 bytes if the
+/// `vec2` fields are declared next to each other:
+///
+/// ```rust
+/// #[std140::repr_std140(warn_padding)]
+/// struct Sprite {
+///     offset: std140::vec2,
+///     color: std140::vec4,
+///     scale: std140::vec2,
+/// }
+/// ```
+///
+/// Padding warnings are not supported on generic structs.
+///
+/// # Descriptor bindings
+///
+/// The `set` and `binding` arguments attach the descriptor set and binding indices of the uniform
+/// block to the struct, as associated `SET` and `BINDING` constants of type `u32`. This keeps the
+/// binding metadata next to the layout definition:
+///
+/// ```rust
+/// #[std140::repr_std140(set = 0, binding = 2)]
+/// struct Camera {
+///     view: std140::mat4x4,
+///     projection: std140::mat4x4,
+/// }
+///
+/// assert_eq!(Camera::SET, 0);
+/// assert_eq!(Camera::BINDING, 2);
+/// ```
+///
+/// Either argument may also be specified on its own, in which case only the corresponding
+/// constant is defined.
+///
+/// # Crate path
+///
+/// The generated code refers to this crate as `std140`. If the crate is available under another
+/// name (e.g. because the dependency was renamed in `Cargo.toml`), specify its path with the
+/// `crate` argument:
+///
+/// ```rust
+/// use std140 as gl;
+///
+/// #[gl::repr_std140(crate = "gl")]
+/// struct PointLight {
+///     position: gl::vec3,
+///     intensity: gl::float,
+/// }
+/// ```
+///
+/// # Bytemuck
+///
+/// With the `bytemuck` feature enabled, the macro also implements `bytemuck::Zeroable` for the
+/// struct (when all of its field types implement `Zeroable`, which all std140 types do), so it
+/// should not be derived separately. This includes structs that contain [boolean]s. `Pod` is
+/// implemented as well if the struct contains neither padding nor [boolean]s: every field type must
+/// implement `Pod`, and the sizes of the fields must add up to the size of the struct (e.g. a struct
+/// with a `vec4` and a `mat4x4` field). `Pod` is never implemented for generic structs or structs
+/// with conditionally compiled fields.
+pub use std140_macros::repr_std140;
+
+/// Attribute macro that can be applied to a plain Rust struct to generate a std140 counterpart.
+///
+/// For a struct named `Name`, this generates a struct named `Std140Name` marked with
+/// [`#[repr_std140]`][repr_std140], with the same field names and visibility. The type of each
+/// field is the std140 counterpart of the original field's type, as defined by [AsStd140]; a field
+/// with a type that does not implement [AsStd140] results in a compile error. It also implements
+/// [AsStd140] and [FromStd140] for the original struct, which convert between the 2 structs.
+///
+/// Because the original struct implements [AsStd140], structs marked with this attribute may
+/// themselves be used as fields in other structs marked with this attribute.
+///
+/// Like [`#[repr_std140]`][repr_std140], this attribute accepts a `crate = "path"` argument for
+/// when this crate is not available as `std140`.
+///
+/// # Example
+///
+/// ```rust
+/// use std140::{AsStd140, FromStd140};
+///
+/// #[std140::derive_std140]
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// struct PointLight {
+///     position: [f32; 3],
+///     intensity: f32,
+///     enabled: bool,
+/// }
+///
+/// let light = PointLight {
+///     position: [0.0, 1.0, 0.0],
+///     intensity: 0.5,
+///     enabled: true,
+/// };
+///
+/// let std140_light: Std140PointLight = light.as_std140();
+///
+/// assert_eq!(std140_light.position, std140::vec3(0.0, 1.0, 0.0));
+/// assert_eq!(PointLight::from_std140(std140_light), light);
+/// ```
+///
+/// A field type without a std140 counterpart is rejected:
+///
+/// ```compile_fail
+/// #[std140::derive_std140]
+/// struct PointLight {
+///     position: [f32; 3],
+///     name: String,
+/// }
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+pub use std140_macros::derive_std140;
+
+/// Derives [Std140Enum][trait@Std140Enum] for a fieldless enum marked `#[repr(u32)]`.
+///
+/// See [Std140Enum][trait@Std140Enum] for details.
+pub use std140_macros::Std140Enum;
+
+pub use crate::as_std140::{AsStd140, FromStd140, Std140Enum};
+pub use crate::boolean_mask::BooleanMask;
+pub use crate::half::{f16vec2, f16vec3, f16vec4, float16};
+pub use crate::row_major::RowMajor;
+pub use crate::writer::Std140Writer;
+
+/// Marker trait for types that can be used as fields in structs marked with
+/// [`#[repr_std140]`][repr_std140].
+///
+/// # Safety
+///
+/// The memory layout of an implementing type must match the layout of a GLSL type according to the
+/// std140 layout rules. The type must not contain padding bytes, unless it is one of the types
+/// provided by this crate or a struct marked with [`#[repr_std140]`][repr_std140].
+///
+/// [repr_std140]: attr.repr_std140.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a std140-compatible type",
+    label = "not a std140-compatible type"
+)]
+pub unsafe trait ReprStd140: Sized {
+    /// The base alignment of this type according to the std140 layout rules, in bytes.
+    ///
+    /// A field of this type in a std140 block starts at the next offset that is a multiple of its
+    /// base alignment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::ReprStd140;
+    ///
+    /// assert_eq!(std140::float::STD140_ALIGNMENT, 4);
+    /// assert_eq!(std140::vec2::STD140_ALIGNMENT, 8);
+    /// assert_eq!(std140::vec3::STD140_ALIGNMENT, 16);
+    /// assert_eq!(<std140::array<std140::float, 2>>::STD140_ALIGNMENT, 16);
+    /// ```
+    const STD140_ALIGNMENT: usize = mem::align_of::<Self>();
+
+    /// The number of bytes a field of this type occupies in a std140 block.
+    ///
+    /// This is usually the size of the Rust type, except for 3-component vectors: those occupy 12
+    /// (or 24 for `dvec3`) bytes, which allows a scalar to be packed into the remaining space, but
+    /// are represented by a 16 (or 32) byte Rust type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::ReprStd140;
+    ///
+    /// assert_eq!(std140::vec3::STD140_SIZE, 12);
+    /// assert_eq!(std::mem::size_of::<std140::vec3>(), 16);
+    /// ```
+    const STD140_SIZE: usize = mem::size_of::<Self>();
+
+    /// The layouts of the fields of this type if it is a struct marked with
+    /// [`#[repr_std140]`][repr_std140], or an empty slice otherwise.
+    ///
+    /// [repr_std140]: attr.repr_std140.html
+    #[doc(hidden)]
+    const STRUCT_FIELD_LAYOUTS: &'static [FieldLayout] = &[];
+
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    fn collect_field_paths(path: &str, offset: usize, paths: &mut Vec<(String, usize, usize)>) {
+        paths.push((path.to_string(), offset, mem::size_of::<Self>()));
+    }
+
+    /// The name of the GLSL type that corresponds to this type; types for which the name is not a
+    /// constant (arrays, matrices and structs) override
+    /// [glsl_field_declaration][ReprStd140::glsl_field_declaration] instead.
+    #[doc(hidden)]
+    const GLSL_TYPE_NAME: &'static str = "";
+
+    /// Returns the GLSL declaration of a field of this type with the given `name` (without the
+    /// trailing `;`), and adds the definitions of any structs it depends on to `structs`.
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    fn glsl_field_declaration(name: &str, structs: &mut Vec<String>) -> String {
+        let _ = structs;
+
+        format!("{} {}", Self::GLSL_TYPE_NAME, name)
+    }
+
+    /// Returns `false` if the first `size_of::<Self>()` bytes of `bytes` (which must be at least
+    /// that long) are not a valid bit pattern for `Self`.
+    #[doc(hidden)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        let _ = bytes;
+
+        true
+    }
+
+    /// Copies the first [STD140_SIZE][ReprStd140::STD140_SIZE] bytes of this value to the start of
+    /// `dst` (which must be at least that long), writing zeros in place of any padding bytes.
+    ///
+    /// The padding bytes of a value are uninitialized, so they must never be read; types that
+    /// contain padding override this to copy only the bytes of their components.
+    #[doc(hidden)]
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
+
+/// Returns a view of the first `len` bytes of the memory that backs `value`.
+///
+/// # Safety
+///
+/// The first `len` bytes of `value` must not contain padding bytes.
+unsafe fn initialized_bytes<T>(value: &T, len: usize) -> &[u8] {
+    debug_assert!(len <= mem::size_of::<T>());
+
+    slice::from_raw_parts(value as *const T as *const u8, len)
+}
+
+/// Marker trait for std140 types that do not contain padding bytes.
+///
+/// The bytes of a value of such a type may be viewed directly with [AsBytes::as_bytes]. Types that
+/// do contain padding, such as `vec3` (the last 4 bytes of which are padding),
+/// [array][struct@array]s of `float`s (each element is padded to 16 bytes) and structs marked with
+/// [`#[repr_std140]`][repr_std140], may be copied to a byte buffer with
+/// [write_bytes][AsBytes::write_bytes] instead, which writes zeros in place of the padding.
+///
+/// Implemented for the scalar types, the 2 and 4 component vector types, the matrices with 4
+/// component (or `dvec2`) columns, and [array][struct@array]s of these types with an element size
+/// that is a multiple of 16 bytes.
+///
+/// # Safety
+///
+/// An implementing type must not contain padding bytes.
+///
+/// [repr_std140]: attr.repr_std140.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` contains padding bytes, which cannot be viewed as `&[u8]`",
+    label = "contains padding bytes",
+    note = "use `write_bytes` to copy the value to a byte buffer instead"
+)]
+pub unsafe trait Std140PaddingFree: ReprStd140 {}
+
+/// Marker trait for types that can be used as the element type for std140 [array][struct@array]s.
+///
+/// # Safety
+///
+/// The memory layout of an implementing type must match the layout of a GLSL type that may be used
+/// as an array element type according to the std140 layout rules.
+pub unsafe trait Std140ArrayElement: ReprStd140 {}
+
+/// Marker trait for array element types that already occupy a multiple of 16 bytes and have a base
+/// alignment of at least 16 bytes.
+///
+/// The elements of an std140 [array][struct@array] are padded to a multiple of 16 bytes. For
+/// element types that implement this trait no padding is necessary, so the elements of the array
+/// are laid out exactly like a Rust `[T]` slice. Arrays of such types implement `AsRef<[T]>` and
+/// `AsMut<[T]>`.
+///
+/// Implemented for the 3 and 4 component vector types, `dvec2`, the matrix types, nested
+/// [array][struct@array]s and structs marked with [`#[repr_std140]`][repr_std140]. Not
+/// implemented for e.g. `float` or `vec2`, which are padded to 16 bytes inside an array.
+///
+/// # Safety
+///
+/// The size of an implementing type must be a multiple of 16 and its alignment must be at least
+/// 16.
+///
+/// [repr_std140]: attr.repr_std140.html
+pub unsafe trait Std140UnpaddedArrayElement: Std140ArrayElement {}
+
+unsafe impl<T> Std140UnpaddedArrayElement for T where T: Std140Struct {}
+unsafe impl<T, const LEN: usize> Std140UnpaddedArrayElement for array<T, { LEN }> where
+    T: Std140ArrayElement
+{
+}
+unsafe impl<V, const C: usize> Std140UnpaddedArrayElement for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+    MatrixColumns<C>: MatrixColumnCount,
+{
+}
+unsafe impl<M> Std140UnpaddedArrayElement for RowMajor<M> where M: Std140UnpaddedArrayElement {}
+unsafe impl Std140UnpaddedArrayElement for vec3 {}
+unsafe impl Std140UnpaddedArrayElement for vec4 {}
+unsafe impl Std140UnpaddedArrayElement for ivec3 {}
+unsafe impl Std140UnpaddedArrayElement for ivec4 {}
+unsafe impl Std140UnpaddedArrayElement for uvec3 {}
+unsafe impl Std140UnpaddedArrayElement for uvec4 {}
+unsafe impl Std140UnpaddedArrayElement for bvec3 {}
+unsafe impl Std140UnpaddedArrayElement for bvec4 {}
+unsafe impl Std140UnpaddedArrayElement for dvec2 {}
+unsafe impl Std140UnpaddedArrayElement for dvec3 {}
+unsafe impl Std140UnpaddedArrayElement for dvec4 {}
+
+/// Marker trait for struct types that were marked with [`#[repr_std140]`][repr_std140].
+///
+/// Like any other std140 type, such a struct implements [ReprStd140], which makes its size (always
+/// a multiple of 16) and base alignment available at compile time as
+/// [STD140_SIZE][ReprStd140::STD140_SIZE] and [STD140_ALIGNMENT][ReprStd140::STD140_ALIGNMENT]:
+///
+/// ```
+/// use std140::ReprStd140;
+///
+/// #[std140::repr_std140]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// const BUFFER_SIZE: usize = PointLight::STD140_SIZE * 16;
+///
+/// assert_eq!(PointLight::STD140_SIZE, 32);
+/// assert_eq!(PointLight::STD140_ALIGNMENT, 16);
+/// ```
+///
+/// # Safety
+///
+/// Should not be implemented manually; implemented automatically for structs marked with
+/// [`#[repr_std140]`][repr_std140].
+///
+/// [repr_std140]: attr.repr_std140.html
+pub unsafe trait Std140Struct {
+    #[doc(hidden)]
+    const FIELD_LAYOUTS: &'static [FieldLayout];
+
+    /// The names of the fields of this struct, in declaration order.
+    ///
+    /// For tuple structs, the name of a field is its index (`"0"`, `"1"`, etc.). Fields that are
+    /// disabled by a `#[cfg]` attribute are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// #[std140::repr_std140]
+    /// struct Range(std140::float, std140::float);
+    ///
+    /// assert_eq!(PointLight::FIELD_NAMES, ["position", "intensity"]);
+    /// assert_eq!(Range::FIELD_NAMES, ["0", "1"]);
+    /// ```
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// The number of fields of this struct.
+    ///
+    /// Equal to the length of [FIELD_NAMES][Std140Struct::FIELD_NAMES].
+    const FIELD_COUNT: usize = Self::FIELD_NAMES.len();
+
+    #[doc(hidden)]
+    const LAYOUT_DESCRIPTION_BYTES: &'static [u8];
+
+    /// A human-readable description of the memory layout of this struct, with one line for each
+    /// field that lists its name, type, byte offset and size, in declaration order.
+    ///
+    /// As this is a constant, it may for example be embedded in a panic message or compared
+    /// against the output of a GLSL reflection tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    ///     colors: std140::array<std140::vec4, 2>,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     PointLight::LAYOUT_DESCRIPTION,
+    ///     "position: std140::vec3 (offset 0, size 16)\n\
+    ///      intensity: std140::float (offset 16, size 4)\n\
+    ///      colors: std140::array<std140::vec4, 2> (offset 32, size 32)"
+    /// );
+    /// ```
+    const LAYOUT_DESCRIPTION: &'static str =
+        __private::layout_description_str(Self::LAYOUT_DESCRIPTION_BYTES);
+
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    fn collect_struct_field_paths(
+        prefix: &str,
+        offset: usize,
+        paths: &mut Vec<(String, usize, usize)>,
+    );
+
+    #[doc(hidden)]
+    fn is_valid_struct_bit_pattern(bytes: &[u8]) -> bool;
+
+    /// Copies the bytes of this struct to the start of `dst` (which must be at least as long as
+    /// the struct), writing zeros in place of the padding between and after its fields.
+    #[doc(hidden)]
+    fn copy_struct_bytes(&self, dst: &mut [u8]);
+
+    #[doc(hidden)]
+    const GLSL_STRUCT_NAME: &'static str;
+
+    /// Returns the GLSL declarations of the fields of this struct, and adds the definitions of any
+    /// nested structs to `structs`.
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    fn glsl_struct_members(structs: &mut Vec<String>) -> Vec<String>;
+
+    /// Returns the path, byte offset and size of every field in this struct, recursing into
+    /// (arrays of) nested structs.
+    ///
+    /// Fields of nested structs are joined with a `.`, elements of arrays are indexed with `[i]`
+    /// (e.g. `lights[0].position`). Offsets are absolute (relative to the start of this struct).
+    /// Only the innermost fields are listed: a field that holds a nested struct or an array is
+    /// represented by the fields of its struct or the elements of its array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     transform: std140::mat4x4,
+    ///     lights: std140::array<PointLight, 2>,
+    /// }
+    ///
+    /// let paths = Uniforms::flat_field_paths();
+    ///
+    /// assert_eq!(paths[0], ("transform".to_string(), 0, 64));
+    /// assert_eq!(paths[1], ("lights[0].position".to_string(), 64, 16));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    fn flat_field_paths() -> Vec<(String, usize, usize)> {
+        let mut paths = Vec::new();
+
+        Self::collect_struct_field_paths("", 0, &mut paths);
+
+        paths
+    }
+
+    /// Returns GLSL source code that declares a `layout(std140)` uniform block that matches this
+    /// struct.
+    ///
+    /// The block is named after the struct, and each field is declared with the GLSL type that
+    /// corresponds to its std140 type (e.g. `mat4` for a [mat4x4][type@mat4x4], `float name[N]`
+    /// for an [array][struct@array] of [float]s). Nested structs marked with
+    /// [`#[repr_std140]`][repr_std140] are declared as GLSL structs that precede the block. The
+    /// fields of tuple structs are named `_0`, `_1`, etc.
+    ///
+    /// This may be used to generate the declaration for a shader at build time, or to compare it
+    /// against the declaration in an existing shader to catch drift between the shader and the
+    /// Rust code. Note that generic structs are declared with the name of the struct, regardless
+    /// of their parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     transform: std140::mat4x4,
+    ///     lights: std140::array<PointLight, 4>,
+    ///     light_count: std140::uint,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Uniforms::glsl_declaration(),
+    ///     "struct PointLight {
+    ///     vec3 position;
+    ///     float intensity;
+    /// };
+    ///
+    /// layout(std140) uniform Uniforms {
+    ///     mat4 transform;
+    ///     PointLight lights[4];
+    ///     uint light_count;
+    /// };"
+    /// );
+    /// ```
+    ///
+    /// [repr_std140]: attr.repr_std140.html
+    #[cfg(feature = "std")]
+    #[must_use]
+    fn glsl_declaration() -> String {
+        let mut structs = Vec::new();
+        let members = Self::glsl_struct_members(&mut structs);
+
+        structs.push(glsl_block(
+            &format!("layout(std140) uniform {}", Self::GLSL_STRUCT_NAME),
+            &members,
+        ));
+
+        structs.join("\n\n")
+    }
+
+    /// Returns a description of the memory layout of each field of this struct, in declaration
+    /// order.
+    ///
+    /// The offsets are the offsets the compiler actually produced for the fields (as reported by
+    /// [core::mem::offset_of]), which makes this suitable for comparing against the layout of a GLSL
+    /// interface block obtained through reflection, e.g. to log discrepancies at startup.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let layout = PointLight::std140_layout();
+    ///
+    /// assert_eq!(layout[1].name, "intensity");
+    /// assert_eq!(layout[1].offset, 16);
+    /// assert_eq!(layout[1].size, 4);
+    /// assert_eq!(layout[1].align, 4);
+    /// ```
+    fn std140_layout() -> &'static [FieldLayout] {
+        Self::FIELD_LAYOUTS
+    }
+
+    /// Returns the range of bytes occupied by the field with the given `name`, or `None` if this
+    /// struct has no such field.
+    ///
+    /// The range is relative to the start of the struct and may be used to update a single field
+    /// of a uniform buffer without re-uploading the whole struct, e.g. with `glBufferSubData` or
+    /// `wgpu::Queue::write_buffer`. For tuple structs, the name of a field is its index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{AsBytes, Std140Struct};
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     intensity: std140::float,
+    ///     position: std140::vec3,
+    ///     radius: std140::float,
+    /// }
+    ///
+    /// let light = PointLight {
+    ///     intensity: std140::float(1.0),
+    ///     position: std140::vec3(0.0, 1.0, 2.0),
+    ///     radius: std140::float(5.0),
+    /// };
+    ///
+    /// let range = PointLight::field_range("position").unwrap();
+    ///
+    /// assert_eq!(range, 16..28);
+    ///
+    /// // Only the bytes of the `position` field would be written to the buffer.
+    /// let bytes = light.to_bytes();
+    ///
+    /// assert_eq!(&bytes[range], &light.position.to_bytes()[..12]);
+    /// assert_eq!(PointLight::field_range("color"), None);
+    /// ```
+    #[must_use]
+    fn field_range(name: &str) -> Option<Range<usize>> {
+        Self::FIELD_LAYOUTS
+            .iter()
+            .find(|layout| layout.name == name)
+            .map(FieldLayout::range)
+    }
+
+    /// Returns an instance of this struct with all bytes set to zero.
+    ///
+    /// An all-zero bit pattern is valid for every std140 type: numeric fields are `0`, and
+    /// [boolean] fields (including the components of [bvec2], [bvec3] and [bvec4]) are
+    /// [boolean::False], which is represented by `0`. Unlike [Default], this does not require the
+    /// field types to implement any additional traits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct Material {
+    ///     color: std140::vec3,
+    ///     visible: std140::boolean,
+    /// }
+    ///
+    /// let mut material = Material::zeroed();
+    ///
+    /// assert_eq!(material.color, std140::vec3(0.0, 0.0, 0.0));
+    /// assert_eq!(material.visible, std140::boolean::False);
+    ///
+    /// material.visible = std140::boolean::True;
+    /// ```
+    #[must_use]
+    fn zeroed() -> Self
+    where
+        Self: Sized,
+    {
+        // SAFETY: `Std140Struct` is only implemented for structs of which all fields are std140
+        // types, for which all zero bytes is a valid bit pattern.
+        unsafe { mem::zeroed() }
+    }
+
+    /// Copies the bytes of this struct into `dst`, starting at `offset`, and returns the offset
+    /// directly after the struct.
+    ///
+    /// The `offset` is relative to the start of `dst` (which is assumed to correspond to the start
+    /// of the GPU buffer) and must be a multiple of the alignment of this struct (16, or 32 if it
+    /// contains a `dvec3` or `dvec4`). To pack structs at dynamic offsets, combine this with
+    /// [dynamic_offset_stride].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct Transform {
+    ///     model: std140::mat4x4,
+    ///     tint: std140::vec4,
+    /// }
+    ///
+    /// let transform = Transform {
+    ///     model: std140::mat4x4::identity(),
+    ///     tint: std140::vec4(1.0, 1.0, 1.0, 1.0),
+    /// };
+    ///
+    /// let mut buffer = vec![0u8; 512];
+    /// let stride = std140::dynamic_offset_stride::<Transform>(256);
+    ///
+    /// assert_eq!(transform.write_to(&mut buffer, 0), Ok(80));
+    /// assert_eq!(transform.write_to(&mut buffer, stride), Ok(336));
+    /// assert!(transform.write_to(&mut buffer, 8).is_err());
+    /// assert!(transform.write_to(&mut buffer, 448).is_err());
+    /// ```
+    fn write_to(&self, dst: &mut [u8], offset: usize) -> Result<usize, WriteError>
+    where
+        Self: Sized,
+    {
+        let alignment = mem::align_of::<Self>();
+
+        if offset % alignment != 0 {
+            return Err(WriteError::Misaligned { offset, alignment });
+        }
+
+        let end = match offset.checked_add(mem::size_of::<Self>()) {
+            Some(end) if end <= dst.len() => end,
+            end => {
+                return Err(WriteError::BufferTooSmall {
+                    required: end.unwrap_or(usize::MAX),
+                    actual: dst.len(),
+                })
+            }
+        };
+
+        AsBytes::write_bytes(self, &mut dst[offset..end]);
+
+        Ok(end)
+    }
+}
+
+/// Describes the memory layout of a field in a struct marked with [`#[repr_std140]`][repr_std140].
+///
+/// See [Std140Struct::std140_layout].
+///
+/// [repr_std140]: attr.repr_std140.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FieldLayout {
+    /// The name of the field (the index of the field for tuple structs).
+    pub name: &'static str,
+
+    /// The offset of the field in bytes, relative to the start of the struct.
+    pub offset: usize,
+
+    /// The number of bytes the field occupies in a std140 block, see [ReprStd140::STD140_SIZE].
+    ///
+    /// This excludes any trailing padding of the field's Rust type (e.g. a `vec3` field has a size
+    /// of 12, even though its Rust type is 16 bytes), and matches the size reported by
+    /// [dump_layout] for the field's type.
+    pub size: usize,
+
+    /// The base alignment of the field in bytes, see [ReprStd140::STD140_ALIGNMENT].
+    pub align: usize,
+}
+
+impl FieldLayout {
+    /// Returns the range of bytes occupied by the field, relative to the start of the struct.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.size
+    }
+}
+
+/// Returns the size and alignment of the std140 type `T` and, if `T` is a struct marked with
+/// [`#[repr_std140]`][repr_std140], the layouts of its fields.
+///
+/// This provides a single entry point for inspecting the layout of any std140 type, e.g. to
+/// include in a panic message when diagnosing a mismatch with the layout a shader expects. The
+/// returned [LayoutInfo] implements [Display][fmt::Display].
+///
+/// # Example
+///
+/// ```
+/// #[std140::repr_std140]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// let layout = std140::dump_layout::<std140::vec3>();
+///
+/// assert_eq!(layout.size, 12);
+/// assert_eq!(layout.align, 16);
+/// assert!(layout.fields.is_empty());
+///
+/// assert_eq!(
+///     std140::dump_layout::<PointLight>().to_string(),
+///     "size 32, align 16\n\
+///      position: offset 0, size 12, align 16\n\
+///      intensity: offset 16, size 4, align 4"
+/// );
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+#[must_use]
+pub const fn dump_layout<T>() -> LayoutInfo
+where
+    T: ReprStd140,
+{
+    LayoutInfo {
+        size: T::STD140_SIZE,
+        align: T::STD140_ALIGNMENT,
+        fields: T::STRUCT_FIELD_LAYOUTS,
+    }
+}
+
+/// Describes the memory layout of a std140 type, as returned by [dump_layout].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LayoutInfo {
+    /// The number of bytes a field of the type occupies in a std140 block, see
+    /// [ReprStd140::STD140_SIZE].
+    pub size: usize,
+
+    /// The base alignment of the type in bytes, see [ReprStd140::STD140_ALIGNMENT].
+    pub align: usize,
+
+    /// The layouts of the fields if the type is a struct marked with
+    /// [`#[repr_std140]`][repr_std140], or an empty slice otherwise.
+    ///
+    /// [repr_std140]: attr.repr_std140.html
+    pub fields: &'static [FieldLayout],
+}
+
+impl fmt::Display for LayoutInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "size {}, align {}", self.size, self.align)?;
+
+        for field in self.fields {
+            write!(
+                f,
+                "\n{}: offset {}, size {}, align {}",
+                field.name, field.offset, field.size, field.align
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Provides access to the raw bytes of std140 values, e.g. for uploading them to GPU memory.
+///
+/// Implemented for all types that implement [ReprStd140], which includes all scalar, vector,
+/// matrix and array types, as well as all structs marked with [`#[repr_std140]`][repr_std140].
+///
+/// The padding bytes the std140 layout rules require (e.g. between struct fields, between array
+/// elements, or after a `vec3`) are uninitialized and must never be read. Values of types that
+/// contain padding are therefore copied with [write_bytes][AsBytes::write_bytes], which writes
+/// zeros in place of the padding; only types that implement [Std140PaddingFree] may be viewed
+/// directly with [as_bytes][AsBytes::as_bytes].
+///
+/// # Example
+///
+/// ```
+/// use std140::AsBytes;
+///
+/// let value = std140::vec2(1.0, 0.0);
+///
+/// assert_eq!(value.as_bytes().len(), 8);
+/// assert_eq!(std140::vec2::from_bytes(value.as_bytes()), Some(&value));
+///
+/// let mut buffer = [0xFF; 16];
+///
+/// std140::vec3(1.0, 0.0, 0.0).write_bytes(&mut buffer);
+///
+/// assert_eq!(&buffer[12..], &[0, 0, 0, 0]);
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+pub trait AsBytes: ReprStd140 {
+    /// Returns a view of the memory that backs this value as a byte slice.
+    ///
+    /// Only available for types that contain no padding bytes, see [Std140PaddingFree]; use
+    /// [write_bytes][AsBytes::write_bytes] for other types.
+    #[must_use]
+    fn as_bytes(&self) -> &[u8]
+    where
+        Self: Std140PaddingFree;
+
+    /// Returns a mutable view of the memory that backs this value as a byte slice.
+    ///
+    /// See also [as_bytes][AsBytes::as_bytes].
+    ///
+    /// # Safety
+    ///
+    /// A [boolean] must be `0` or `1` when interpreted as a `u32`. If this value contains any
+    /// [boolean]s (including [bvec2] and [bvec4], or arrays that contain these types), then the
+    /// caller must ensure no other bit patterns are written to their bytes. For types that don't
+    /// contain [boolean]s, any bytes may be written.
+    unsafe fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        Self: Std140PaddingFree;
+
+    /// Copies the bytes of this value to the start of `dst`, writing zeros in place of any padding
+    /// bytes, and returns the number of bytes written (the size of this type).
+    ///
+    /// Unlike [as_bytes][AsBytes::as_bytes], this is available for all types, including types
+    /// that contain padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than the size of this type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::AsBytes;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let light = PointLight {
+    ///     position: std140::vec3(0.0, 1.0, 0.0),
+    ///     intensity: std140::float(0.5),
+    /// };
+    ///
+    /// let mut buffer = [0xFF; 32];
+    ///
+    /// assert_eq!(light.write_bytes(&mut buffer), 32);
+    /// assert_eq!(&buffer[16..20], &0.5f32.to_ne_bytes());
+    /// // The padding after `position` and after `intensity` is zeroed.
+    /// assert!(buffer[12..16].iter().chain(&buffer[20..]).all(|byte| *byte == 0));
+    /// ```
+    fn write_bytes(&self, dst: &mut [u8]) -> usize;
+
+    /// Returns a copy of the bytes of this value, with zeros in place of any padding bytes.
+    ///
+    /// See also [write_bytes][AsBytes::write_bytes].
+    #[cfg(feature = "std")]
+    #[must_use]
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; mem::size_of::<Self>()];
+
+        self.write_bytes(&mut bytes);
+
+        bytes
+    }
+
+    /// Interprets the given bytes as a reference to a value of this type.
+    ///
+    /// Returns `None` if the length of `bytes` does not equal the size of this type, if `bytes` is
+    /// not correctly aligned for this type, or if `bytes` is not a valid bit pattern for this type
+    /// (this type contains a [boolean] and the corresponding `u32` is neither `0` nor `1`).
+    #[must_use]
+    fn from_bytes(bytes: &[u8]) -> Option<&Self>;
+
+    /// Reads a copy of a value of this type from the given bytes.
+    ///
+    /// Unlike [from_bytes][AsBytes::from_bytes], `bytes` does not need to be aligned for this type,
+    /// which makes this suitable for reading back data from a mapped GPU buffer.
+    ///
+    /// Returns an error if the length of `bytes` does not equal the size of this type, or if
+    /// `bytes` is not a valid bit pattern for this type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::AsBytes;
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(PartialEq, Debug)]
+    /// struct PointLight {
+    ///     position: std140::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let light = PointLight {
+    ///     position: std140::vec3(0.0, 1.0, 0.0),
+    ///     intensity: std140::float(0.5),
+    /// };
+    ///
+    /// // Copy the bytes to an offset that is not aligned to 16 bytes.
+    /// let mut buffer = vec![0u8; 33];
+    ///
+    /// light.write_bytes(&mut buffer[1..]);
+    ///
+    /// assert_eq!(PointLight::read_from_bytes(&buffer[1..]), Ok(light));
+    /// assert!(PointLight::read_from_bytes(&buffer).is_err());
+    /// ```
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError>;
+}
+
+impl<T> AsBytes for T
+where
+    T: ReprStd140,
+{
+    fn as_bytes(&self) -> &[u8]
+    where
+        Self: Std140PaddingFree,
+    {
+        unsafe { initialized_bytes(self, mem::size_of::<T>()) }
+    }
+
+    unsafe fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        Self: Std140PaddingFree,
+    {
+        slice::from_raw_parts_mut(self as *mut T as *mut u8, mem::size_of::<T>())
+    }
+
+    fn write_bytes(&self, dst: &mut [u8]) -> usize {
+        let size = mem::size_of::<T>();
+        let dst = &mut dst[..size];
+
+        self.copy_std140_bytes(dst);
+        dst[T::STD140_SIZE..].fill(0);
+
+        size
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+        if bytes.len() != mem::size_of::<T>()
+            || (bytes.as_ptr() as usize) % mem::align_of::<T>() != 0
+            || !T::is_valid_bit_pattern(bytes)
+        {
+            return None;
+        }
+
+        Some(unsafe { &*(bytes.as_ptr() as *const T) })
+    }
+
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        if bytes.len() != mem::size_of::<T>() {
+            return Err(FromBytesError::LengthMismatch {
+                expected: mem::size_of::<T>(),
+                actual: bytes.len(),
+            });
+        }
+
+        if !T::is_valid_bit_pattern(bytes) {
+            return Err(FromBytesError::InvalidBitPattern);
+        }
+
+        Ok(unsafe { (bytes.as_ptr() as *const T).read_unaligned() })
+    }
+}
+
+/// Object-safe access to the bytes of a struct marked with [`#[repr_std140]`][repr_std140].
+///
+/// Unlike [AsBytes] and [Std140Struct], this trait may be used as a trait object, e.g. to store
+/// uniform blocks of different types in a single collection and upload them uniformly. It is
+/// implemented for all types that implement [Std140Struct].
+///
+/// Note that this trait is not included in the [prelude]: its methods would be ambiguous with
+/// [AsBytes::write_bytes] for concrete types if both traits are in scope.
+///
+/// # Example
+///
+/// ```
+/// use std140::Std140Bytes;
+///
+/// #[std140::repr_std140]
+/// struct Camera {
+///     view: std140::mat4x4,
+/// }
+///
+/// #[std140::repr_std140]
+/// struct Exposure {
+///     value: std140::float,
+/// }
+///
+/// let blocks: Vec<Box<dyn Std140Bytes>> = vec![
+///     Box::new(Camera {
+///         view: std140::mat4x4::identity(),
+///     }),
+///     Box::new(Exposure {
+///         value: std140::float(1.0),
+///     }),
+/// ];
+///
+/// let sizes: Vec<usize> = blocks.iter().map(|block| block.std140_size()).collect();
+///
+/// assert_eq!(sizes, [64, 16]);
+///
+/// let mut buffer = vec![0u8; sizes.iter().sum()];
+/// let mut offset = 0;
+///
+/// for block in &blocks {
+///     offset += block.write_bytes(&mut buffer[offset..]);
+/// }
+///
+/// assert_eq!(&buffer[64..68], &1.0f32.to_ne_bytes());
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+pub trait Std140Bytes {
+    /// Copies the bytes of this value to the start of `dst`, writing zeros in place of any padding
+    /// bytes, and returns the number of bytes written, see [AsBytes::write_bytes].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [std140_size][Std140Bytes::std140_size].
+    fn write_bytes(&self, dst: &mut [u8]) -> usize;
+
+    /// Returns the size of this value in bytes, including any trailing padding.
+    ///
+    /// This is equal to the number of bytes written by [write_bytes][Std140Bytes::write_bytes].
+    fn std140_size(&self) -> usize;
+}
+
+impl<T> Std140Bytes for T
+where
+    T: Std140Struct,
+{
+    fn write_bytes(&self, dst: &mut [u8]) -> usize {
+        AsBytes::write_bytes(self, dst)
+    }
+
+    fn std140_size(&self) -> usize {
+        mem::size_of::<T>()
+    }
+}
+
+/// Error returned by [AsBytes::read_from_bytes] when the bytes do not represent a valid value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FromBytesError {
+    /// The number of bytes does not match the size of the type.
+    LengthMismatch {
+        /// The size of the type.
+        expected: usize,
+        /// The number of bytes that was provided.
+        actual: usize,
+    },
+    /// The bytes contain a [boolean] that is neither `0` nor `1` when interpreted as a `u32`.
+    InvalidBitPattern,
+}
+
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBytesError::LengthMismatch { expected, actual } => write!(
+                f,
+                "expected {} bytes for the std140 value, but got {}",
+                expected, actual
+            ),
+            FromBytesError::InvalidBitPattern => {
+                write!(f, "the bytes contain an invalid std140 boolean")
+            }
+        }
+    }
+}
+
+impl core::error::Error for FromBytesError {}
+
+/// Error returned by [Std140Struct::write_to] when a struct cannot be written at the requested
+/// offset.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WriteError {
+    /// The offset is not a multiple of the alignment of the struct.
+    Misaligned {
+        /// The requested offset.
+        offset: usize,
+        /// The alignment of the struct.
+        alignment: usize,
+    },
+    /// The buffer is too small to hold the struct at the requested offset.
+    BufferTooSmall {
+        /// The size the buffer needs to have at least (or `usize::MAX` if that size overflows a
+        /// `usize`).
+        required: usize,
+        /// The size of the buffer.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Misaligned { offset, alignment } => write!(
+                f,
+                "offset {} is not a multiple of the std140 alignment {}",
+                offset, alignment
+            ),
+            WriteError::BufferTooSmall { required, actual } => write!(
+                f,
+                "the buffer needs at least {} bytes, but has {}",
+                required, actual
+            ),
+        }
+    }
+}
+
+impl core::error::Error for WriteError {}
+
+/// Reads a copy of a struct marked with [`#[repr_std140]`][repr_std140] from `bytes`, starting at
+/// the given `offset`.
+///
+/// This is intended for reading back data from a sub-range of a mapped GPU buffer: the alignment
+/// of such a range is not guaranteed to match the alignment of `T`, so the value is read
+/// unaligned rather than by casting the pointer.
+///
+/// Returns `None` if `bytes` does not contain enough bytes for a `T` starting at `offset`, or if
+/// these bytes are not a valid bit pattern for `T` (see [AsBytes::read_from_bytes]).
+///
+/// # Example
+///
+/// ```
+/// use std140::AsBytes;
+///
+/// #[std140::repr_std140]
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// let light = PointLight {
+///     position: std140::vec3(0.0, 1.0, 0.0),
+///     intensity: std140::float(0.5),
+/// };
+///
+/// let mut buffer = vec![0u8; 40];
+///
+/// light.write_bytes(&mut buffer[3..35]);
+///
+/// assert_eq!(std140::read_std140::<PointLight>(&buffer, 3), Some(light));
+/// assert_eq!(std140::read_std140::<PointLight>(&buffer, 16), None);
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+#[must_use]
+pub fn read_std140<T>(bytes: &[u8], offset: usize) -> Option<T>
+where
+    T: Std140Struct + Copy,
+{
+    let end = offset.checked_add(mem::size_of::<T>())?;
+    let bytes = bytes.get(offset..end)?;
+
+    T::read_from_bytes(bytes).ok()
+}
+
+unsafe impl<T> ReprStd140 for T
+where
+    T: Std140Struct,
+{
+    const STRUCT_FIELD_LAYOUTS: &'static [FieldLayout] = T::FIELD_LAYOUTS;
+
+    #[cfg(feature = "std")]
+    fn collect_field_paths(path: &str, offset: usize, paths: &mut Vec<(String, usize, usize)>) {
+        T::collect_struct_field_paths(path, offset, paths);
+    }
+
+    #[cfg(feature = "std")]
+    fn glsl_field_declaration(name: &str, structs: &mut Vec<String>) -> String {
+        let members = T::glsl_struct_members(structs);
+        let definition = glsl_block(&format!("struct {}", T::GLSL_STRUCT_NAME), &members);
+
+        if !structs.contains(&definition) {
+            structs.push(definition);
+        }
+
+        format!("{} {}", T::GLSL_STRUCT_NAME, name)
+    }
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        T::is_valid_struct_bit_pattern(bytes)
+    }
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        self.copy_struct_bytes(dst);
+    }
+}
+
+unsafe impl<T> Std140ArrayElement for T where T: Std140Struct {}
+
+/// Represents an std140 compatible array.
+///
+/// All elements in an std140 array are aligned to at least 16 bytes.
+///
+/// GLSL arrays must have a length of at least 1. Constructing an array with a length of `0`
+/// results in a compile error:
+///
+/// ```compile_fail
+/// let empty: std140::array<std140::float, 0> = std140::array![];
+/// ```
+///
+/// The [array!][macro@array] macro may be used to initialize an array.
+///
+/// If the element type needs no padding inside the array (see [Std140UnpaddedArrayElement]), a
+/// range of elements may be viewed as a Rust slice by indexing with a range, e.g. `&lights[1..3]`:
+///
+/// ```
+/// fn brightest(colors: &[std140::vec4]) -> f32 {
+///     colors.iter().map(|c| c.0 + c.1 + c.2).fold(0.0, f32::max)
+/// }
+///
+/// let colors = std140::array![
+///     std140::vec4(1.0, 1.0, 1.0, 1.0),
+///     std140::vec4(0.5, 0.0, 0.0, 1.0),
+///     std140::vec4(0.0, 0.25, 0.0, 1.0),
+/// ];
+///
+/// assert_eq!(brightest(&colors[1..]), 0.5);
+/// ```
+///
+/// This is not possible for element types that are padded, such as [float] or [vec2]: their
+/// elements are not laid out like a Rust slice.
+///
+/// # Example
+///
+/// ```
+/// let std140_array: std140::array<std140::vec2, 2> = std140::array![
+///     std140::vec2(1.0, 0.0),
+///     std140::vec2(0.0, 1.0),
+/// ];
+/// ```
+#[derive(Clone, Copy)]
+pub struct array<T, const LEN: usize>
+where
+    T: Std140ArrayElement,
+{
+    internal: [ArrayElementWrapper<T>; LEN],
+}
+
+impl<T, const LEN: usize> array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    /// Creates a new [array][struct@array] where each element is the result of calling `f` with
+    /// the index of that element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::array::<std140::uint, 3>::from_fn(|i| std140::uint(i as u32 * 2));
+    ///
+    /// assert_eq!(value, std140::array![std140::uint(0), std140::uint(2), std140::uint(4)]);
+    /// ```
+    #[must_use]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::from_wrapped(core::array::from_fn(|i| ArrayElementWrapper {
+            element: f(i),
+        }))
+    }
+
+    /// Creates a new [array][struct@array] from the elements in the `slice`, or returns `None` if
+    /// the length of the `slice` does not match the length of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let lights = vec![std140::vec3(1.0, 0.0, 0.0), std140::vec3(0.0, 1.0, 0.0)];
+    ///
+    /// assert!(std140::array::<std140::vec3, 2>::from_slice(&lights).is_some());
+    /// assert!(std140::array::<std140::vec3, 3>::from_slice(&lights).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_slice(slice: &[T]) -> Option<Self>
+    where
+        T: Copy,
+    {
+        if slice.len() != LEN {
+            return None;
+        }
+
+        Some(Self::from_fn(|i| slice[i]))
+    }
+
+    /// Returns a new [array][struct@array] with the result of calling `f` on each element of this
+    /// array, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let positions = std140::array![std140::vec3(1.0, 2.0, 3.0), std140::vec3(4.0, 5.0, 6.0)];
+    /// let homogeneous = positions.map(|p| std140::vec4(p.0, p.1, p.2, 1.0));
+    ///
+    /// assert_eq!(
+    ///     homogeneous,
+    ///     std140::array![std140::vec4(1.0, 2.0, 3.0, 1.0), std140::vec4(4.0, 5.0, 6.0, 1.0)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn map<U, F>(self, mut f: F) -> array<U, { LEN }>
+    where
+        U: Std140ArrayElement,
+        F: FnMut(T) -> U,
+    {
+        array::from_wrapped(self.internal.map(|wrapper| ArrayElementWrapper {
+            element: f(wrapper.element),
+        }))
+    }
+
+    /// Folds every element of this array into an accumulator by applying `f`, in order, and
+    /// returns the final accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let intensities = std140::array![
+    ///     std140::float(0.5),
+    ///     std140::float(1.0),
+    ///     std140::float(0.25),
+    /// ];
+    ///
+    /// let total = intensities.fold(0.0, |total, intensity| total + intensity.0);
+    ///
+    /// assert_eq!(total, 1.75);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.internal
+            .iter()
+            .fold(init, |acc, wrapper| f(acc, &wrapper.element))
+    }
+
+    /// Returns a new [array][struct@array] that contains the elements of this array followed by the
+    /// elements of `other`.
+    ///
+    /// The length `OUT` of the resulting array must equal `LEN + OTHER`; it is usually inferred
+    /// from the context. Expressing the length as `LEN + OTHER` directly would require the unstable
+    /// `generic_const_exprs` feature, so the length is instead checked at compile time: a mismatch
+    /// results in a compile error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let static_lights = std140::array![std140::vec4(1.0, 0.0, 0.0, 1.0)];
+    /// let dynamic_lights = std140::array![
+    ///     std140::vec4(0.0, 1.0, 0.0, 1.0),
+    ///     std140::vec4(0.0, 0.0, 1.0, 1.0),
+    /// ];
+    ///
+    /// let lights: std140::array<std140::vec4, 3> = static_lights.concat(dynamic_lights);
+    ///
+    /// assert_eq!(lights.get(2), Some(&std140::vec4(0.0, 0.0, 1.0, 1.0)));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// let a = std140::array![std140::float(0.0)];
+    /// let b = std140::array![std140::float(1.0)];
+    ///
+    /// let c: std140::array<std140::float, 3> = a.concat(b);
+    /// ```
+    #[must_use]
+    pub fn concat<const OTHER: usize, const OUT: usize>(
+        self,
+        other: array<T, { OTHER }>,
+    ) -> array<T, { OUT }>
+    where
+        T: Copy,
+    {
+        const {
+            assert!(
+                OUT == LEN + OTHER,
+                "the length of the concatenated array must equal the sum of the lengths of the arrays"
+            )
+        };
+
+        array::from_fn(|i| {
+            if i < LEN {
+                self.internal[i].element
+            } else {
+                other.internal[i - LEN].element
+            }
+        })
+    }
+
+    /// Splits this array into an array of its first `LEFT` elements and an array of its remaining
+    /// `RIGHT` elements.
+    ///
+    /// `LEFT + RIGHT` must equal `LEN`; this is checked at compile time (see [concat][array::concat]
+    /// for why). As std140 arrays must have a length of at least 1, both parts must be non-empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let lights = std140::array![
+    ///     std140::vec4(1.0, 0.0, 0.0, 1.0),
+    ///     std140::vec4(0.0, 1.0, 0.0, 1.0),
+    ///     std140::vec4(0.0, 0.0, 1.0, 1.0),
+    /// ];
+    ///
+    /// let (first, rest) = lights.split_at::<1, 2>();
+    ///
+    /// assert_eq!(first, [std140::vec4(1.0, 0.0, 0.0, 1.0)]);
+    /// assert_eq!(rest.get(0), Some(&std140::vec4(0.0, 1.0, 0.0, 1.0)));
+    /// ```
+    #[must_use]
+    pub fn split_at<const LEFT: usize, const RIGHT: usize>(
+        self,
+    ) -> (array<T, { LEFT }>, array<T, { RIGHT }>)
+    where
+        T: Copy,
+    {
+        const {
+            assert!(
+                LEFT + RIGHT == LEN,
+                "the lengths of the parts must add up to the length of the array"
+            )
+        };
+
+        (
+            array::from_fn(|i| self.internal[i].element),
+            array::from_fn(|i| self.internal[LEFT + i].element),
+        )
+    }
+
+    /// Copies the elements of this array into `slice`.
+    ///
+    /// The elements of a std140 array are not contiguous in memory (each element is padded to a
+    /// multiple of 16 bytes), so the elements cannot be viewed as a slice; they are copied instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slice` does not equal the length of this array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::array![std140::float(0.0), std140::float(1.0)];
+    /// let mut floats = [std140::float(0.5); 2];
+    ///
+    /// value.copy_to_slice(&mut floats);
+    ///
+    /// assert_eq!(floats, [std140::float(0.0), std140::float(1.0)]);
+    /// ```
+    pub fn copy_to_slice(&self, slice: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            slice.len(),
+            LEN,
+            "the length of the slice does not match the length of the std140 array"
+        );
+
+        for (element, wrapper) in slice.iter_mut().zip(self.internal.iter()) {
+            *element = wrapper.element;
+        }
+    }
+
+    /// Copies the elements of this array into a new [Vec].
+    ///
+    /// See also [copy_to_slice][array::copy_to_slice]. Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Copy,
+    {
+        self.internal
+            .iter()
+            .map(|wrapper| wrapper.element)
+            .collect()
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::array![std140::float(0.0), std140::float(1.0)];
+    ///
+    /// assert_eq!(value.get(1), Some(&std140::float(1.0)));
+    /// assert_eq!(value.get(2), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.internal.get(index).map(|wrapper| &wrapper.element)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if `index` is out of
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.internal
+            .get_mut(index)
+            .map(|wrapper| &mut wrapper.element)
+    }
+
+    /// Returns a copy of the element at `index`.
+    ///
+    /// Unlike [get][array::get], this may be used in `const` contexts, e.g. to derive a constant
+    /// uniform value from another constant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds; in a `const` context, this results in a compile error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// const PALETTE: std140::array<std140::vec4, 2> = std140::array![
+    ///     std140::vec4(1.0, 0.0, 0.0, 1.0),
+    ///     std140::vec4(0.0, 0.0, 1.0, 1.0),
+    /// ];
+    ///
+    /// const HIGHLIGHT: std140::vec4 = PALETTE.get_const(1);
+    ///
+    /// assert_eq!(HIGHLIGHT, std140::vec4(0.0, 0.0, 1.0, 1.0));
+    /// ```
+    #[must_use]
+    pub const fn get_const(&self, index: usize) -> T
+    where
+        T: Copy,
+    {
+        self.internal[index].element
+    }
+
+    #[doc(hidden)]
+    pub const fn from_wrapped(wrapped: [ArrayElementWrapper<T>; LEN]) -> Self {
+        const { assert!(LEN > 0, "a std140 array must have a length of at least 1") };
+
+        array { internal: wrapped }
+    }
+
+    #[doc(hidden)]
+    pub const fn element_ptr(array: *const Self, index: usize) -> *const T {
+        assert!(index < LEN, "array index out of bounds");
+
+        (array as *const ArrayElementWrapper<T>).wrapping_add(index) as *const T
+    }
+}
+
+impl<T, const LEN: usize> PartialEq for array<T, { LEN }>
+where
+    T: Std140ArrayElement + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        for i in 0..LEN {
+            if self.internal[i] != other.internal[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<T, const LEN: usize> PartialEq<[T; LEN]> for array<T, { LEN }>
+where
+    T: Std140ArrayElement + PartialEq,
+{
+    fn eq(&self, other: &[T; LEN]) -> bool {
+        self.internal
+            .iter()
+            .zip(other.iter())
+            .all(|(wrapper, element)| wrapper.element == *element)
+    }
+}
+
+impl<T, const LEN: usize> PartialEq<array<T, { LEN }>> for [T; LEN]
+where
+    T: Std140ArrayElement + PartialEq,
+{
+    fn eq(&self, other: &array<T, { LEN }>) -> bool {
+        other == self
+    }
+}
+
+impl<T, const LEN: usize> Eq for array<T, { LEN }> where T: Std140ArrayElement + Eq {}
+
+impl<T, const LEN: usize> Hash for array<T, { LEN }>
+where
+    T: Std140ArrayElement + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.internal.hash(state)
+    }
+}
+
+/// Views the elements of the array as a slice.
+///
+/// Only implemented for element types that need no padding inside an std140 array, see
+/// [Std140UnpaddedArrayElement].
+///
+/// # Example
+///
+/// ```
+/// fn sum(vectors: impl AsRef<[std140::vec4]>) -> f32 {
+///     vectors.as_ref().iter().map(|v| v.0 + v.1 + v.2 + v.3).sum()
+/// }
+///
+/// let value = std140::array![std140::vec4(1.0, 2.0, 3.0, 4.0), std140::vec4(1.0, 1.0, 1.0, 1.0)];
+///
+/// assert_eq!(sum(value), 14.0);
+/// ```
+impl<T, const LEN: usize> AsRef<[T]> for array<T, { LEN }>
+where
+    T: Std140UnpaddedArrayElement,
+{
+    fn as_ref(&self) -> &[T] {
+        // Safety: `ArrayElementWrapper<T>` has the same size and alignment as `T` for unpadded
+        // element types, so the wrapped elements are laid out exactly like a `[T; LEN]`.
+        unsafe { slice::from_raw_parts(self.internal.as_ptr() as *const T, LEN) }
+    }
+}
+
+/// Views the elements of the array as a mutable slice.
+///
+/// Only implemented for element types that need no padding inside an std140 array, see
+/// [Std140UnpaddedArrayElement].
+impl<T, const LEN: usize> AsMut<[T]> for array<T, { LEN }>
+where
+    T: Std140UnpaddedArrayElement,
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        // Safety: see `as_ref`.
+        unsafe { slice::from_raw_parts_mut(self.internal.as_mut_ptr() as *mut T, LEN) }
+    }
+}
+
+macro_rules! impl_array_range_index {
+    ($($range:ty),*) => {
+        $(
+            impl<T, const LEN: usize> Index<$range> for array<T, { LEN }>
+            where
+                T: Std140UnpaddedArrayElement,
+            {
+                type Output = [T];
+
+                fn index(&self, index: $range) -> &Self::Output {
+                    &self.as_ref()[index]
+                }
+            }
+
+            impl<T, const LEN: usize> IndexMut<$range> for array<T, { LEN }>
+            where
+                T: Std140UnpaddedArrayElement,
+            {
+                fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                    &mut self.as_mut()[index]
+                }
+            }
+        )*
+    };
+}
+
+// Slicing by range views a sub-range of the elements as a Rust slice, which is only sound for the
+// element types that need no padding inside an std140 array (the same condition as for `AsRef`).
+// Indexing by a single `usize` is not implemented; use `array::get` instead.
+impl_array_range_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
+/// Converts a Rust array into an [array][struct@array] with the same elements.
+///
+/// # Example
+///
+/// ```
+/// let colors = [std140::vec4(1.0, 0.0, 0.0, 1.0), std140::vec4(0.0, 0.0, 1.0, 1.0)];
+///
+/// let value: std140::array<std140::vec4, 2> = colors.into();
+///
+/// assert_eq!(value, colors);
+/// ```
+impl<T, const LEN: usize> From<[T; LEN]> for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    fn from(elements: [T; LEN]) -> Self {
+        array::from_wrapped(elements.map(|element| ArrayElementWrapper { element }))
+    }
+}
+
+/// Converts an [array][struct@array] into a Rust array with the same elements, without the padding
+/// between the elements.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::array![std140::float(0.25), std140::float(0.75)];
+///
+/// let weights: [std140::float; 2] = value.into();
+///
+/// assert_eq!(weights, [std140::float(0.25), std140::float(0.75)]);
+/// ```
+impl<T, const LEN: usize> From<array<T, { LEN }>> for [T; LEN]
+where
+    T: Std140ArrayElement,
+{
+    fn from(value: array<T, { LEN }>) -> Self {
+        value.internal.map(|wrapper| wrapper.element)
+    }
+}
+
+/// Copies the elements of a slice into an [array][struct@array].
+///
+/// Returns an error that reports the length of the slice if it does not equal the length of the
+/// array. See also [from_slice][array::from_slice].
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// let weights = vec![std140::float(0.25), std140::float(0.75)];
+///
+/// let value = std140::array::<std140::float, 2>::try_from(weights.as_slice()).unwrap();
+///
+/// assert_eq!(value, [std140::float(0.25), std140::float(0.75)]);
+///
+/// let error = std140::array::<std140::float, 3>::try_from(weights.as_slice()).unwrap_err();
+///
+/// assert_eq!(error.actual(), 2);
+/// ```
+impl<T, const LEN: usize> TryFrom<&[T]> for array<T, { LEN }>
+where
+    T: Std140ArrayElement + Copy,
+{
+    type Error = SliceLengthError;
+
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        array::from_slice(slice).ok_or(SliceLengthError {
+            expected: LEN,
+            actual: slice.len(),
+        })
+    }
+}
+
+/// Error returned when converting a slice into an [array][struct@array] of a different length.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SliceLengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl SliceLengthError {
+    /// The length of the array.
+    #[must_use]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The length of the slice.
+    #[must_use]
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a slice of {} elements for a std140 array, but the slice has {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for SliceLengthError {}
+
+/// Collects exactly `LEN` elements into an [array][struct@array].
+///
+/// # Panics
+///
+/// Panics if the iterator yields fewer or more than `LEN` elements. To report the actual count, the
+/// remaining elements are consumed when there are too many.
+///
+/// # Example
+///
+/// ```
+/// let value: std140::array<std140::float, 3> = (0..3).map(|i| std140::float(i as f32)).collect();
+///
+/// assert_eq!(value, [std140::float(0.0), std140::float(1.0), std140::float(2.0)]);
+/// ```
+///
+/// ```should_panic
+/// use std::iter::FromIterator;
+///
+/// // Panics with "expected 3 elements for a std140 array, but the iterator yielded 2"
+/// let value = std140::array::<std140::float, 3>::from_iter([std140::float(0.0); 2]);
+/// ```
+impl<T, const LEN: usize> FromIterator<T> for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        let result = Self::from_wrapped(core::array::from_fn(|i| match iter.next() {
+            Some(element) => ArrayElementWrapper { element },
+            None => panic!(
+                "expected {} elements for a std140 array, but the iterator yielded {}",
+                LEN, i
+            ),
+        }));
+
+        let remaining = iter.count();
+
+        if remaining > 0 {
+            panic!(
+                "expected {} elements for a std140 array, but the iterator yielded {}",
+                LEN,
+                LEN + remaining
+            );
+        }
+
+        result
+    }
+}
+
+/// Consumes the [array][struct@array] and yields its elements by value, in order.
+///
+/// # Example
+///
+/// ```
+/// let corners = std140::array![
+///     std140::vec2(0.0, 0.0),
+///     std140::vec2(1.0, 0.0),
+///     std140::vec2(1.0, 1.0),
+/// ];
+///
+/// let mut width = 0.0;
+///
+/// for corner in corners {
+///     width = f32::max(width, corner.0);
+/// }
+///
+/// assert_eq!(width, 1.0);
+/// ```
+impl<T, const LEN: usize> IntoIterator for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = T;
+
+    type IntoIter = ArrayIntoIter<T, LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayIntoIter {
+            internal: IntoIterator::into_iter(self.internal),
+        }
+    }
+}
+
+/// An iterator that moves the elements out of an [array][struct@array].
+///
+/// Returned by the [IntoIterator] implementation for [array][struct@array].
+#[derive(Clone)]
+pub struct ArrayIntoIter<T, const LEN: usize>
+where
+    T: Std140ArrayElement,
+{
+    internal: core::array::IntoIter<ArrayElementWrapper<T>, LEN>,
+}
+
+impl<T, const LEN: usize> Iterator for ArrayIntoIter<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.internal.next().map(|wrapper| wrapper.element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.internal.size_hint()
+    }
+}
+
+impl<T, const LEN: usize> DoubleEndedIterator for ArrayIntoIter<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.internal.next_back().map(|wrapper| wrapper.element)
+    }
+}
+
+impl<T, const LEN: usize> ExactSizeIterator for ArrayIntoIter<T, { LEN }> where T: Std140ArrayElement
+{}
+
+impl<T, const LEN: usize> FusedIterator for ArrayIntoIter<T, { LEN }> where T: Std140ArrayElement {}
+
+impl<T, const LEN: usize> fmt::Debug for ArrayIntoIter<T, { LEN }>
+where
+    T: Std140ArrayElement + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.internal
+                    .as_slice()
+                    .iter()
+                    .map(|wrapper| &wrapper.element),
+            )
+            .finish()
+    }
+}
+
+impl<T, const LEN: usize> Default for array<T, { LEN }>
+where
+    T: Std140ArrayElement + Default + Copy,
+{
+    fn default() -> Self {
+        array::from_wrapped(
+            [ArrayElementWrapper {
+                element: T::default(),
+            }; LEN],
+        )
+    }
+}
+
+/// Formats an [array] as a list of its elements.
+///
+/// The alternate format (`{:#?}`) prints each element on its own line, prefixed with its index
+/// (e.g. `[1] => vec2(0.0, 1.0),`), which helps to locate a specific element in a large array.
+impl<T, const LEN: usize> fmt::Debug for array<T, { LEN }>
+where
+    T: Std140ArrayElement + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_list()
+                .entries(
+                    self.internal
+                        .iter()
+                        .enumerate()
+                        .map(|(index, element)| IndexedElement(index, &element.element)),
+                )
+                .finish()
+        } else {
+            f.debug_list().entries(self.internal.iter()).finish()
+        }
+    }
+}
+
+/// Formats an array element prefixed with its index, see the [fmt::Debug] impl for [array].
+struct IndexedElement<'a, T>(usize, &'a T);
+
+impl<T> fmt::Debug for IndexedElement<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] => ", self.0)?;
+
+        self.1.fmt(f)
+    }
+}
+
+// TODO: something like this? (if that ever becomes possible)
+//impl<T, const LEN: usize> Unsize<slice<T>> for array<T, {LEN}> {}
+//
+//pub struct slice<T> where T: Std140ArrayElement {
+//    internal: *mut [ArrayElementWrapper<T>]
+//}
+
+#[doc(hidden)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C, align(16))]
+pub struct ArrayElementWrapper<T>
+where
+    T: Std140ArrayElement,
+{
+    pub element: T,
+}
+
+impl<T> fmt::Debug for ArrayElementWrapper<T>
+where
+    T: Std140ArrayElement + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as fmt::Debug>::fmt(&self.element, f)
+    }
+}
+
+/// Implemented for all [Copy] types; the element of the repeat form of [array!] must implement
+/// this trait.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the element of `array![element; N]` must implement `Copy`",
+    label = "the element is copied into each position of the array",
+    note = "derive `Clone` and `Copy` for `{Self}`, or list the elements individually"
+)]
+pub trait RepeatedArrayElement: Copy {}
+
+impl<T> RepeatedArrayElement for T where T: Copy {}
+
+#[doc(hidden)]
+pub const fn repeat_array_element<T, const LEN: usize>(element: T) -> [ArrayElementWrapper<T>; LEN]
+where
+    T: Std140ArrayElement + RepeatedArrayElement,
+{
+    [ArrayElementWrapper { element }; LEN]
+}
+
+/// Initializes a `std140` [array][struct@array].
+///
+/// Like a Rust array expression, this either takes a list of elements, or an element and a length
+/// (`array![element; N]`). The latter copies the element into each position of the array, so the
+/// element type must implement [Copy]. All std140 types implement [Copy]; structs marked with
+/// [`#[repr_std140]`][repr_std140] need to derive it.
+///
+/// # Example
+///
+/// ```
+/// let std140_array: std140::array<std140::vec2, 2> = std140::array![
+///     std140::vec2(1.0, 0.0),
+///     std140::vec2(0.0, 1.0),
+/// ];
+///
+/// let zeros: std140::array<std140::float, 3> = std140::array![std140::float(0.0); 3];
+/// ```
+///
+/// The length of the array is inferred from the number of elements (or from `N`), so the type
+/// annotation may be omitted:
+///
+/// ```
+/// let std140_array = std140::array![std140::vec2(1.0, 0.0), std140::vec2(0.0, 1.0)];
+///
+/// assert_eq!(std::mem::size_of_val(&std140_array), 32);
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+#[macro_export]
+macro_rules! array {
+    ($elem:expr; $n:expr) => {{
+        let elements: [$crate::ArrayElementWrapper<_>; $n] =
+            $crate::repeat_array_element($elem);
+
+        $crate::array::from_wrapped(elements)
+    }};
+    ($($x:expr),*) => {
+        $crate::array::from_wrapped([
+            $(
+                $crate::ArrayElementWrapper {
+                    element: $x
+                }
+            ),*
+        ])
+    };
+    ($($x:expr,)*) => ($crate::array![$($x),*])
+}
+
+/// Initializes a `std140` [array][struct@array] with an explicit length.
+///
+/// Behaves like [array!], except that it takes the length of the array as its first argument. The
+/// elements are counted when the macro is expanded; if their number does not match the length,
+/// a compile-time assertion fails with a message that states both the expected and the actual
+/// number of elements (e.g. "sized_array!: expected 3 elements, found 2"), rather than a mismatch
+/// between 2 array types.
+///
+/// # Example
+///
+/// ```
+/// let std140_array = std140::sized_array![2;
+///     std140::vec2(1.0, 0.0),
+///     std140::vec2(0.0, 1.0),
+/// ];
+///
+/// assert_eq!(
+///     std140_array,
+///     std140::array![std140::vec2(1.0, 0.0), std140::vec2(0.0, 1.0)]
+/// );
+/// ```
+///
+/// A mismatched number of elements fails to compile:
+///
+/// ```compile_fail
+/// let std140_array = std140::sized_array![3;
+///     std140::vec2(1.0, 0.0),
+///     std140::vec2(0.0, 1.0),
+/// ];
+/// ```
+#[macro_export]
+macro_rules! sized_array {
+    ($n:expr; $($x:expr),* $(,)?) => {{
+        const {
+            let expected: usize = $n;
+            let found = <[()]>::len(&[$($crate::__unit!($x)),*]);
+
+            if expected != found {
+                let message = $crate::__private::LayoutDescription::<96>::new()
+                    .push_str("sized_array!: expected ")
+                    .push_usize(expected)
+                    .push_str(" elements, found ")
+                    .push_usize(found);
+
+                panic!("{}", message.as_str());
+            }
+        }
+
+        $crate::array::from_wrapped([
+            $(
+                $crate::ArrayElementWrapper {
+                    element: $x
+                }
+            ),*
+        ])
+    }};
+}
+
+/// Expands to `()`, discarding the given expression without evaluating it; used to count the
+/// elements passed to [sized_array!].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unit {
+    ($x:expr) => {
+        ()
+    };
+}
+
+unsafe impl<T, const LEN: usize> ReprStd140 for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    #[cfg(feature = "std")]
+    fn collect_field_paths(path: &str, offset: usize, paths: &mut Vec<(String, usize, usize)>) {
+        let stride = element_stride::<T>();
+
+        for i in 0..LEN {
+            T::collect_field_paths(&format!("{}[{}]", path, i), offset + i * stride, paths);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn glsl_field_declaration(name: &str, structs: &mut Vec<String>) -> String {
+        T::glsl_field_declaration(&format!("{}[{}]", name, LEN), structs)
+    }
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        let stride = element_stride::<T>();
 
-/// Attribute macro that can be applied to a struct to ensure its representation is compatible with
-/// the std140 memory layout convention.
+        (0..LEN).all(|i| T::is_valid_bit_pattern(&bytes[i * stride..]))
+    }
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        let stride = element_stride::<T>();
+
+        for (i, wrapper) in self.internal.iter().enumerate() {
+            let element = &mut dst[i * stride..(i + 1) * stride];
+
+            wrapper.element.copy_std140_bytes(element);
+            element[T::STD140_SIZE..].fill(0);
+        }
+    }
+}
+
+unsafe impl<T, const LEN: usize> Std140PaddingFree for array<T, { LEN }> where
+    T: Std140UnpaddedArrayElement + Std140PaddingFree
+{
+}
+
+unsafe impl<T, const LEN: usize> Std140ArrayElement for array<T, { LEN }> where T: Std140ArrayElement
+{}
+
+/// Returns the distance in bytes between consecutive instances of `T` in a buffer that is bound
+/// with dynamic offsets.
 ///
-/// Can only be applied to a struct if all of its fields implement [ReprStd140].
+/// Each dynamically bound uniform block must start at a multiple of the minimum uniform buffer
+/// offset alignment of the device (`minUniformBufferOffsetAlignment` in Vulkan,
+/// `GL_UNIFORM_BUFFER_OFFSET_ALIGNMENT` in OpenGL, often 256). This rounds the size of `T` up to a
+/// multiple of `min_alignment`.
 ///
-/// Any struct marked with this attribute will automatically implement [Std140Struct]
+/// # Panics
+///
+/// Panics if `min_alignment` is zero.
 ///
 /// # Example
 ///
-/// ```rust
+/// ```
 /// #[std140::repr_std140]
-/// struct PointLight {
-///     position: std140::vec3,
-///     intensity: std140::float,
+/// struct Transform {
+///     model: std140::mat4x4,
+///     tint: std140::vec4,
 /// }
-/// ```
-pub use std140_macros::repr_std140;
-
-/// Marker trait for types that can be used as fields in structs marked with
-/// [`#[repr_std140]`][repr_std140].
 ///
-/// [repr_std140]: attr.repr_std140.html
-pub unsafe trait ReprStd140 {}
-
-/// Marker trait for types that can be used as the element type for std140 [array][struct@array]s.
-pub unsafe trait Std140ArrayElement: ReprStd140 {}
-
-/// Marker trait for struct types that were marked with [`#[repr_std140]`][repr_std140].
+/// let stride = std140::dynamic_offset_stride::<Transform>(256);
 ///
-/// [repr_std140]: attr.repr_std140.html
-pub unsafe trait Std140Struct {}
-
-unsafe impl<T> ReprStd140 for T where T: Std140Struct {}
-unsafe impl<T> Std140ArrayElement for T where T: Std140Struct {}
-
-/// Represents an std140 compatible array.
+/// assert_eq!(stride, 256);
 ///
-/// All elements in an std140 array are aligned to at least 16 bytes.
+/// // The offset of the 3rd instance in the buffer:
+/// assert_eq!(2 * stride, 512);
+/// ```
+#[must_use]
+pub const fn dynamic_offset_stride<T>(min_alignment: usize) -> usize
+where
+    T: Std140Struct,
+{
+    mem::size_of::<T>().next_multiple_of(min_alignment)
+}
+
+/// Returns the distance in bytes between the starts of consecutive elements of an std140
+/// [array][struct@array] with element type `T`.
 ///
-/// The [array!][macro@array] macro may be used to initialize an array.
+/// Every array element is padded to a multiple of 16 bytes, so the stride is always a multiple of
+/// 16: e.g. the stride of a `float` array is 16, the stride of a `dvec3` array is 32.
 ///
 /// # Example
 ///
 /// ```
-/// let std140_array: std140::array<std140::vec2, 2> = std140::array![
-///     std140::vec2(1.0, 0.0),
-///     std140::vec2(0.0, 1.0),
-/// ];
+/// assert_eq!(std140::element_stride::<std140::float>(), 16);
+/// assert_eq!(std140::element_stride::<std140::vec4>(), 16);
+/// assert_eq!(std140::element_stride::<std140::mat2x3>(), 32);
+///
+/// // The byte offset of the element at index 3 of an array of `vec2`s:
+/// assert_eq!(3 * std140::element_stride::<std140::vec2>(), 48);
 /// ```
-#[derive(Clone, Copy)]
-pub struct array<T, const LEN: usize>
+#[must_use]
+pub const fn element_stride<T>() -> usize
 where
     T: Std140ArrayElement,
 {
-    internal: [ArrayElementWrapper<T>; LEN],
+    mem::size_of::<ArrayElementWrapper<T>>()
 }
 
-impl<T, const LEN: usize> array<T, { LEN }>
-where
-    T: Std140ArrayElement,
-{
-    #[doc(hidden)]
-    pub fn from_wrapped(wrapped: [ArrayElementWrapper<T>; LEN]) -> Self {
-        array { internal: wrapped }
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub fn join_field_path(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
     }
 }
 
-impl<T, const LEN: usize> PartialEq for array<T, { LEN }>
-where
-    T: Std140ArrayElement + PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        for i in 0..LEN {
-            if self.internal[i] != other.internal[i] {
-                return false;
-            }
-        }
+/// Formats a GLSL struct definition or block declaration with the given `header` (e.g.
+/// `struct PointLight`) and `members`.
+#[cfg(feature = "std")]
+fn glsl_block(header: &str, members: &[String]) -> String {
+    let mut block = format!("{} {{\n", header);
 
-        true
+    for member in members {
+        block.push_str("    ");
+        block.push_str(member);
+        block.push_str(";\n");
     }
+
+    block.push_str("};");
+
+    block
 }
 
-impl<T, const LEN: usize> fmt::Debug for array<T, { LEN }>
-where
-    T: Std140ArrayElement + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.internal.iter()).finish()
-    }
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use std::{string::String, vec::Vec};
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    pub use crate::layout_description::{layout_description_str, LayoutDescription};
+    pub use crate::padding::{CfgFieldWarning, FieldSizes, NoPadding, PaddingCheck, PodCheck};
 }
 
-// TODO: something like this? (if that ever becomes possible)
-//impl<T, const LEN: usize> Unsize<slice<T>> for array<T, {LEN}> {}
-//
-//pub struct slice<T> where T: Std140ArrayElement {
-//    internal: *mut [ArrayElementWrapper<T>]
-//}
+/// Expands to its input if the `std` feature is enabled, or to nothing otherwise.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __if_std {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
 
+/// Expands to its input if the `std` feature is enabled, or to nothing otherwise.
 #[doc(hidden)]
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(C, align(16))]
-pub struct ArrayElementWrapper<T>
-where
-    T: Std140ArrayElement,
-{
-    pub element: T,
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! __if_std {
+    ($($tt:tt)*) => {};
 }
 
-impl<T> fmt::Debug for ArrayElementWrapper<T>
-where
-    T: Std140ArrayElement + fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <T as fmt::Debug>::fmt(&self.element, f)
-    }
+/// Expands to its input if the `bytemuck` feature is enabled, or to nothing otherwise.
+#[doc(hidden)]
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+macro_rules! __if_bytemuck {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
 }
 
-/// Initializes a `std140` [array][struct@array].
+/// Expands to its input if the `bytemuck` feature is enabled, or to nothing otherwise.
+#[doc(hidden)]
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+macro_rules! __if_bytemuck {
+    ($($tt:tt)*) => {};
+}
+
+/// Evaluates to the byte offset of a (nested) field, relative to the start of a std140 struct.
+///
+/// The field is specified as a path of field names and array indices, starting from the struct
+/// type. The offset is computed at compile time and may therefore be used in `const` contexts,
+/// e.g. to assert that a field is positioned at the expected offset, or to determine where to write
+/// a partial update to a buffer. Indexing an array out of bounds results in a compile error.
 ///
 /// # Example
 ///
 /// ```
-/// let std140_array: std140::array<std140::vec2, 2> = std140::array![
-///     std140::vec2(1.0, 0.0),
-///     std140::vec2(0.0, 1.0),
-/// ];
+/// #[std140::repr_std140]
+/// struct PointLight {
+///     position: std140::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// #[std140::repr_std140]
+/// struct Uniforms {
+///     transform: std140::mat4x4,
+///     lights: std140::array<PointLight, 2>,
+/// }
+///
+/// const LIGHTS_OFFSET: usize = std140::std140_offset_of!(Uniforms, lights);
+/// const INTENSITY_OFFSET: usize = std140::std140_offset_of!(Uniforms, lights[1].intensity);
+///
+/// assert_eq!(LIGHTS_OFFSET, 64);
+/// assert_eq!(INTENSITY_OFFSET, 112);
 /// ```
 #[macro_export]
-macro_rules! array {
-    ($elem:expr; $n:expr) => {
-        $crate::array::from_wrapped([$crate::ArrayElementWrapper {
-            element: $elem
-        }; $n])
+macro_rules! std140_offset_of {
+    (@path $ptr:expr, ) => {
+        $ptr
     };
-    ($($x:expr),*) => {
-        $crate::array::from_wrapped([
-            $(
-                $crate::ArrayElementWrapper {
-                    element: $x
-                }
-            ),*
-        ])
+    (@path $ptr:expr, . $field:tt $($path:tt)*) => {
+        $crate::std140_offset_of!(
+            @path unsafe { ::core::ptr::addr_of!((*$ptr).$field) },
+            $($path)*
+        )
     };
-    ($($x:expr,)*) => ($crate::array![$($x),*])
-}
+    (@path $ptr:expr, [$index:expr] $($path:tt)*) => {
+        $crate::std140_offset_of!(@path $crate::array::element_ptr($ptr, $index), $($path)*)
+    };
+    ($ty:ty, $field:tt $($path:tt)*) => {{
+        const OFFSET: usize = {
+            let uninit = ::core::mem::MaybeUninit::<$ty>::uninit();
+            let base = uninit.as_ptr();
+            let field = unsafe { ::core::ptr::addr_of!((*base).$field) };
+            let field = $crate::std140_offset_of!(@path field, $($path)*);
+
+            unsafe { (field as *const u8).offset_from(base as *const u8) as usize }
+        };
 
-unsafe impl<T, const LEN: usize> ReprStd140 for array<T, { LEN }> where T: Std140ArrayElement {}
+        OFFSET
+    }};
+}
 
 /// A 32-bit floating point value.
 ///
@@ -274,11 +2763,41 @@ unsafe impl<T, const LEN: usize> ReprStd140 for array<T, { LEN }> where T: Std14
 /// let value = std140::float(0.5);
 /// ```
 #[repr(C, align(4))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct float(pub f32);
 
-unsafe impl ReprStd140 for float {}
+unsafe impl ReprStd140 for float {
+    const GLSL_TYPE_NAME: &'static str = "float";
+}
 unsafe impl Std140ArrayElement for float {}
+unsafe impl Std140PaddingFree for float {}
+
+impl From<f32> for float {
+    fn from(value: f32) -> Self {
+        float(value)
+    }
+}
+
+impl From<float> for f32 {
+    fn from(value: float) -> Self {
+        value.0
+    }
+}
+
+impl Deref for float {
+    type Target = f32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for float {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
 
 /// A column vector of 2 [float] values.
 ///
@@ -289,17 +2808,48 @@ unsafe impl Std140ArrayElement for float {}
 /// ```
 #[repr(C, align(8))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct vec2(pub f32, pub f32);
 
 impl vec2 {
     /// Creates a new [vec2] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         vec2(0.0, 0.0)
     }
+
+    /// Returns a new [vec2] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        vec2(self.0.recip(), self.1.recip())
+    }
+
+    /// Returns a new [vec2] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        vec2(self.0.sqrt().recip(), self.1.sqrt().recip())
+    }
+}
+
+impl Default for vec2 {
+    fn default() -> Self {
+        vec2::zero()
+    }
 }
 
-unsafe impl ReprStd140 for vec2 {}
+unsafe impl ReprStd140 for vec2 {
+    const GLSL_TYPE_NAME: &'static str = "vec2";
+}
 unsafe impl Std140ArrayElement for vec2 {}
+unsafe impl Std140PaddingFree for vec2 {}
 
 impl Index<usize> for vec2 {
     type Output = f32;
@@ -332,16 +2882,58 @@ impl IndexMut<usize> for vec2 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct vec3(pub f32, pub f32, pub f32);
 
 impl vec3 {
     /// Creates a new [vec3] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         vec3(0.0, 0.0, 0.0)
     }
+
+    /// Returns a new [vec3] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        vec3(self.0.recip(), self.1.recip(), self.2.recip())
+    }
+
+    /// Returns a new [vec3] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        vec3(
+            self.0.sqrt().recip(),
+            self.1.sqrt().recip(),
+            self.2.sqrt().recip(),
+        )
+    }
+}
+
+impl Default for vec3 {
+    fn default() -> Self {
+        vec3::zero()
+    }
 }
 
-unsafe impl ReprStd140 for vec3 {}
+unsafe impl ReprStd140 for vec3 {
+    const STD140_SIZE: usize = 12;
+
+    const GLSL_TYPE_NAME: &'static str = "vec3";
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
 unsafe impl Std140ArrayElement for vec3 {}
 
 impl Index<usize> for vec3 {
@@ -377,17 +2969,58 @@ impl IndexMut<usize> for vec3 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct vec4(pub f32, pub f32, pub f32, pub f32);
 
 impl vec4 {
     /// Creates a new [vec4] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         vec4(0.0, 0.0, 0.0, 0.0)
     }
+
+    /// Returns a new [vec4] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        vec4(
+            self.0.recip(),
+            self.1.recip(),
+            self.2.recip(),
+            self.3.recip(),
+        )
+    }
+
+    /// Returns a new [vec4] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        vec4(
+            self.0.sqrt().recip(),
+            self.1.sqrt().recip(),
+            self.2.sqrt().recip(),
+            self.3.sqrt().recip(),
+        )
+    }
+}
+
+impl Default for vec4 {
+    fn default() -> Self {
+        vec4::zero()
+    }
 }
 
-unsafe impl ReprStd140 for vec4 {}
+unsafe impl ReprStd140 for vec4 {
+    const GLSL_TYPE_NAME: &'static str = "vec4";
+}
 unsafe impl Std140ArrayElement for vec4 {}
+unsafe impl Std140PaddingFree for vec4 {}
 
 impl Index<usize> for vec4 {
     type Output = f32;
@@ -423,11 +3056,79 @@ impl IndexMut<usize> for vec4 {
 /// let value = std140::int(1);
 /// ```
 #[repr(C, align(4))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct int(pub i32);
 
-unsafe impl ReprStd140 for int {}
+unsafe impl ReprStd140 for int {
+    const GLSL_TYPE_NAME: &'static str = "int";
+}
 unsafe impl Std140ArrayElement for int {}
+unsafe impl Std140PaddingFree for int {}
+
+impl int {
+    /// Returns a reference to the raw `i32` value.
+    ///
+    /// See [as_raw_mut][int::as_raw_mut] for pairing the value with an atomic type.
+    #[must_use]
+    pub const fn as_raw(&self) -> &i32 {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the raw `i32` value.
+    ///
+    /// This allows a [int] in mapped buffer memory (e.g. a counter in a storage buffer that a
+    /// compute shader increments atomically) to be accessed through a
+    /// [AtomicI32][core::sync::atomic::AtomicI32] by casting the pointer: a [int] has the same size
+    /// and alignment as an `AtomicI32`. This crate does not provide atomic operations itself;
+    /// whether an atomic operation on the host is also atomic with respect to the GPU depends on
+    /// the graphics API and on the kind of memory the buffer is mapped from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicI32, Ordering};
+    ///
+    /// let mut counter = std140::int(1);
+    ///
+    /// // SAFETY: the pointer is valid and suitably aligned, and `counter` is not accessed other
+    /// // than through `atomic` while `atomic` is in use.
+    /// let atomic = unsafe { AtomicI32::from_ptr(counter.as_raw_mut()) };
+    ///
+    /// atomic.fetch_add(1, Ordering::Relaxed);
+    ///
+    /// assert_eq!(counter, std140::int(2));
+    /// ```
+    pub fn as_raw_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+}
+
+impl From<i32> for int {
+    fn from(value: i32) -> Self {
+        int(value)
+    }
+}
+
+impl From<int> for i32 {
+    fn from(value: int) -> Self {
+        value.0
+    }
+}
+
+impl Deref for int {
+    type Target = i32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for int {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
 
 /// A column vector of 2 [int] values.
 ///
@@ -437,18 +3138,29 @@ unsafe impl Std140ArrayElement for int {}
 /// let value = std140::ivec2(0, 1);
 /// ```
 #[repr(C, align(8))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ivec2(pub i32, pub i32);
 
 impl ivec2 {
     /// Creates a new [ivec2] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         ivec2(0, 0)
     }
 }
 
-unsafe impl ReprStd140 for ivec2 {}
+impl Default for ivec2 {
+    fn default() -> Self {
+        ivec2::zero()
+    }
+}
+
+unsafe impl ReprStd140 for ivec2 {
+    const GLSL_TYPE_NAME: &'static str = "ivec2";
+}
 unsafe impl Std140ArrayElement for ivec2 {}
+unsafe impl Std140PaddingFree for ivec2 {}
 
 impl Index<usize> for ivec2 {
     type Output = i32;
@@ -480,17 +3192,35 @@ impl IndexMut<usize> for ivec2 {
 /// let value = std140::ivec3(0, 0, 1);
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ivec3(pub i32, pub i32, pub i32);
 
 impl ivec3 {
     /// Creates a new [ivec3] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         ivec3(0, 0, 0)
     }
 }
 
-unsafe impl ReprStd140 for ivec3 {}
+impl Default for ivec3 {
+    fn default() -> Self {
+        ivec3::zero()
+    }
+}
+
+unsafe impl ReprStd140 for ivec3 {
+    const STD140_SIZE: usize = 12;
+
+    const GLSL_TYPE_NAME: &'static str = "ivec3";
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
 unsafe impl Std140ArrayElement for ivec3 {}
 
 impl Index<usize> for ivec3 {
@@ -525,18 +3255,29 @@ impl IndexMut<usize> for ivec3 {
 /// let value = std140::ivec4(0, 0, 0, 1);
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ivec4(pub i32, pub i32, pub i32, pub i32);
 
 impl ivec4 {
     /// Creates a new [ivec4] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         ivec4(0, 0, 0, 0)
     }
 }
 
-unsafe impl ReprStd140 for ivec4 {}
+impl Default for ivec4 {
+    fn default() -> Self {
+        ivec4::zero()
+    }
+}
+
+unsafe impl ReprStd140 for ivec4 {
+    const GLSL_TYPE_NAME: &'static str = "ivec4";
+}
 unsafe impl Std140ArrayElement for ivec4 {}
+unsafe impl Std140PaddingFree for ivec4 {}
 
 impl Index<usize> for ivec4 {
     type Output = i32;
@@ -564,19 +3305,87 @@ impl IndexMut<usize> for ivec4 {
     }
 }
 
-/// A 32-bit unsigned integer value.
-///
-/// # Example
-///
-/// ```
-/// let value = std140::uint(1);
-/// ```
-#[repr(C, align(4))]
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct uint(pub u32);
-
-unsafe impl ReprStd140 for uint {}
-unsafe impl Std140ArrayElement for uint {}
+/// A 32-bit unsigned integer value.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::uint(1);
+/// ```
+#[repr(C, align(4))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct uint(pub u32);
+
+unsafe impl ReprStd140 for uint {
+    const GLSL_TYPE_NAME: &'static str = "uint";
+}
+unsafe impl Std140ArrayElement for uint {}
+unsafe impl Std140PaddingFree for uint {}
+
+impl uint {
+    /// Returns a reference to the raw `u32` value.
+    ///
+    /// See [as_raw_mut][uint::as_raw_mut] for pairing the value with an atomic type.
+    #[must_use]
+    pub const fn as_raw(&self) -> &u32 {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the raw `u32` value.
+    ///
+    /// This allows a [uint] in mapped buffer memory (e.g. a counter in a storage buffer that a
+    /// compute shader increments atomically) to be accessed through a
+    /// [AtomicU32][core::sync::atomic::AtomicU32] by casting the pointer: a [uint] has the same size
+    /// and alignment as an `AtomicU32`. This crate does not provide atomic operations itself;
+    /// whether an atomic operation on the host is also atomic with respect to the GPU depends on
+    /// the graphics API and on the kind of memory the buffer is mapped from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let mut counter = std140::uint(1);
+    ///
+    /// // SAFETY: the pointer is valid and suitably aligned, and `counter` is not accessed other
+    /// // than through `atomic` while `atomic` is in use.
+    /// let atomic = unsafe { AtomicU32::from_ptr(counter.as_raw_mut()) };
+    ///
+    /// atomic.fetch_add(1, Ordering::Relaxed);
+    ///
+    /// assert_eq!(counter, std140::uint(2));
+    /// ```
+    pub fn as_raw_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+}
+
+impl From<u32> for uint {
+    fn from(value: u32) -> Self {
+        uint(value)
+    }
+}
+
+impl From<uint> for u32 {
+    fn from(value: uint) -> Self {
+        value.0
+    }
+}
+
+impl Deref for uint {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for uint {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
 
 /// A column vector of 2 [uint] values.
 ///
@@ -586,18 +3395,29 @@ unsafe impl Std140ArrayElement for uint {}
 /// let value = std140::uvec2(0, 1);
 /// ```
 #[repr(C, align(8))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct uvec2(pub u32, pub u32);
 
 impl uvec2 {
     /// Creates a new [uvec2] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         uvec2(0, 0)
     }
 }
 
-unsafe impl ReprStd140 for uvec2 {}
+impl Default for uvec2 {
+    fn default() -> Self {
+        uvec2::zero()
+    }
+}
+
+unsafe impl ReprStd140 for uvec2 {
+    const GLSL_TYPE_NAME: &'static str = "uvec2";
+}
 unsafe impl Std140ArrayElement for uvec2 {}
+unsafe impl Std140PaddingFree for uvec2 {}
 
 impl Index<usize> for uvec2 {
     type Output = u32;
@@ -629,17 +3449,35 @@ impl IndexMut<usize> for uvec2 {
 /// let value = std140::uvec3(0, 0, 1);
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct uvec3(pub u32, pub u32, pub u32);
 
 impl uvec3 {
     /// Creates a new [uvec3] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         uvec3(0, 0, 0)
     }
 }
 
-unsafe impl ReprStd140 for uvec3 {}
+impl Default for uvec3 {
+    fn default() -> Self {
+        uvec3::zero()
+    }
+}
+
+unsafe impl ReprStd140 for uvec3 {
+    const STD140_SIZE: usize = 12;
+
+    const GLSL_TYPE_NAME: &'static str = "uvec3";
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
 unsafe impl Std140ArrayElement for uvec3 {}
 
 impl Index<usize> for uvec3 {
@@ -674,18 +3512,29 @@ impl IndexMut<usize> for uvec3 {
 /// let value = std140::uvec4(0, 0, 0, 1);
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct uvec4(pub u32, pub u32, pub u32, pub u32);
 
 impl uvec4 {
     /// Creates a new [uvec4] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         uvec4(0, 0, 0, 0)
     }
 }
 
-unsafe impl ReprStd140 for uvec4 {}
+impl Default for uvec4 {
+    fn default() -> Self {
+        uvec4::zero()
+    }
+}
+
+unsafe impl ReprStd140 for uvec4 {
+    const GLSL_TYPE_NAME: &'static str = "uvec4";
+}
 unsafe impl Std140ArrayElement for uvec4 {}
+unsafe impl Std140PaddingFree for uvec4 {}
 
 impl Index<usize> for uvec4 {
     type Output = u32;
@@ -718,20 +3567,43 @@ impl IndexMut<usize> for uvec4 {
 /// [boolean::False] is stored identically to a [uint] of `0`; [boolean::True] is stored identically
 /// to a [uint] of `1`.
 ///
+/// GLSL does not specify the size of a `bool`, but the std140 layout rules (and SPIR-V) store a
+/// `bool` in an interface block as a 32-bit value with a base alignment of 4 bytes. This type is
+/// guaranteed to have that size and alignment; consequently [bvec2], [bvec3] and [bvec4] have the
+/// same size and alignment as [uvec2], [uvec3] and [uvec4]. When interfacing with a pipeline that
+/// expects a different representation, convert explicitly with
+/// [uint_value][boolean::uint_value] and [from_uint][boolean::from_uint].
+///
 /// # Example
 ///
 /// ```
-/// let value = std140::uint(1);
+/// let value = std140::boolean::True;
+///
+/// assert_eq!(std::mem::size_of_val(&value), 4);
+/// assert_eq!(value.uint_value(), 1);
 /// ```
 #[repr(u32)]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
 pub enum boolean {
     True = 1,
+    #[default]
     False = 0,
 }
 
-unsafe impl ReprStd140 for boolean {}
+unsafe impl ReprStd140 for boolean {
+    const GLSL_TYPE_NAME: &'static str = "bool";
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        let mut raw = [0; 4];
+
+        raw.copy_from_slice(&bytes[..4]);
+
+        boolean::try_from_raw(u32::from_ne_bytes(raw)).is_ok()
+    }
+}
+
 unsafe impl Std140ArrayElement for boolean {}
+unsafe impl Std140PaddingFree for boolean {}
 
 impl From<bool> for boolean {
     fn from(value: bool) -> Self {
@@ -742,6 +3614,148 @@ impl From<bool> for boolean {
     }
 }
 
+impl boolean {
+    /// Returns `true` if this is [boolean::True], or `false` if this is [boolean::False].
+    #[must_use]
+    pub const fn as_bool(self) -> bool {
+        matches!(self, boolean::True)
+    }
+
+    /// Creates a [boolean] from its raw `u32` representation, where `0` results in
+    /// [boolean::False] and any other value results in [boolean::True].
+    ///
+    /// This matches the GLSL semantics for converting an integer to a `bool`. See
+    /// [try_from_raw][boolean::try_from_raw] for a strict alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::boolean::from_raw(0), std140::boolean::False);
+    /// assert_eq!(std140::boolean::from_raw(2), std140::boolean::True);
+    /// ```
+    #[must_use]
+    pub const fn from_raw(value: u32) -> boolean {
+        if value == 0 {
+            boolean::False
+        } else {
+            boolean::True
+        }
+    }
+
+    /// Returns the 32-bit unsigned integer that represents this [boolean] in memory: `1` for
+    /// [boolean::True] and `0` for [boolean::False].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::boolean::True.uint_value(), 1);
+    /// assert_eq!(std140::boolean::False.uint_value(), 0);
+    /// ```
+    #[must_use]
+    pub const fn uint_value(self) -> u32 {
+        self as u32
+    }
+
+    /// Same as [from_raw][boolean::from_raw]; named to pair with [uint_value][boolean::uint_value],
+    /// of which it is the inverse for the values `0` and `1`.
+    ///
+    /// Like the GLSL `bool(uint)` constructor, `0` results in [boolean::False] and any other value
+    /// results in [boolean::True].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::boolean::from_uint(1), std140::boolean::True);
+    /// assert_eq!(std140::boolean::from_uint(7), std140::boolean::True);
+    /// assert_eq!(std140::boolean::from_uint(0), std140::boolean::False);
+    /// ```
+    #[must_use]
+    pub const fn from_uint(value: u32) -> boolean {
+        boolean::from_raw(value)
+    }
+
+    /// Creates a [boolean] from its raw `u32` representation, or returns an error if the `value` is
+    /// neither `0` ([boolean::False]) nor `1` ([boolean::True]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::boolean::try_from_raw(1), Ok(std140::boolean::True));
+    /// assert!(std140::boolean::try_from_raw(2).is_err());
+    /// ```
+    pub const fn try_from_raw(value: u32) -> Result<boolean, InvalidBoolean> {
+        match value {
+            0 => Ok(boolean::False),
+            1 => Ok(boolean::True),
+            value => Err(InvalidBoolean { value }),
+        }
+    }
+}
+
+/// Error returned by [boolean::try_from_raw] when the raw value is not a valid [boolean].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidBoolean {
+    value: u32,
+}
+
+impl InvalidBoolean {
+    /// The raw value that is not a valid [boolean].
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for InvalidBoolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid std140 boolean (expected `0` or `1`)",
+            self.value
+        )
+    }
+}
+
+impl core::error::Error for InvalidBoolean {}
+
+impl From<boolean> for bool {
+    fn from(value: boolean) -> Self {
+        value.as_bool()
+    }
+}
+
+impl Not for boolean {
+    type Output = boolean;
+
+    fn not(self) -> Self::Output {
+        (!self.as_bool()).into()
+    }
+}
+
+impl BitAnd for boolean {
+    type Output = boolean;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        (self.as_bool() & rhs.as_bool()).into()
+    }
+}
+
+impl BitOr for boolean {
+    type Output = boolean;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        (self.as_bool() | rhs.as_bool()).into()
+    }
+}
+
+impl BitXor for boolean {
+    type Output = boolean;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (self.as_bool() ^ rhs.as_bool()).into()
+    }
+}
+
 /// A column vector of 2 [boolean] values.
 ///
 /// # Example
@@ -750,11 +3764,19 @@ impl From<bool> for boolean {
 /// let value = std140::bvec2(std140::boolean::False, std140::boolean::True);
 /// ```
 #[repr(C, align(8))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct bvec2(pub boolean, pub boolean);
 
-unsafe impl ReprStd140 for bvec2 {}
+unsafe impl ReprStd140 for bvec2 {
+    const GLSL_TYPE_NAME: &'static str = "bvec2";
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        (0..2).all(|i| boolean::is_valid_bit_pattern(&bytes[i * 4..]))
+    }
+}
 unsafe impl Std140ArrayElement for bvec2 {}
+unsafe impl Std140PaddingFree for bvec2 {}
 
 impl Index<usize> for bvec2 {
     type Output = boolean;
@@ -786,10 +3808,25 @@ impl IndexMut<usize> for bvec2 {
 /// let value = std140::bvec3(std140::boolean::False, std140::boolean::False, std140::boolean::True);
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct bvec3(pub boolean, pub boolean, pub boolean);
 
-unsafe impl ReprStd140 for bvec3 {}
+unsafe impl ReprStd140 for bvec3 {
+    const STD140_SIZE: usize = 12;
+
+    const GLSL_TYPE_NAME: &'static str = "bvec3";
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        (0..3).all(|i| boolean::is_valid_bit_pattern(&bytes[i * 4..]))
+    }
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
 unsafe impl Std140ArrayElement for bvec3 {}
 
 impl Index<usize> for bvec3 {
@@ -829,11 +3866,19 @@ impl IndexMut<usize> for bvec3 {
 /// );
 /// ```
 #[repr(C, align(16))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct bvec4(pub boolean, pub boolean, pub boolean, pub boolean);
 
-unsafe impl ReprStd140 for bvec4 {}
+unsafe impl ReprStd140 for bvec4 {
+    const GLSL_TYPE_NAME: &'static str = "bvec4";
+
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        (0..4).all(|i| boolean::is_valid_bit_pattern(&bytes[i * 4..]))
+    }
+}
 unsafe impl Std140ArrayElement for bvec4 {}
+unsafe impl Std140PaddingFree for bvec4 {}
 
 impl Index<usize> for bvec4 {
     type Output = boolean;
@@ -869,11 +3914,41 @@ impl IndexMut<usize> for bvec4 {
 /// let value = std140::double(0.5);
 /// ```
 #[repr(C, align(8))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct double(pub f64);
 
-unsafe impl ReprStd140 for double {}
+unsafe impl ReprStd140 for double {
+    const GLSL_TYPE_NAME: &'static str = "double";
+}
 unsafe impl Std140ArrayElement for double {}
+unsafe impl Std140PaddingFree for double {}
+
+impl From<f64> for double {
+    fn from(value: f64) -> Self {
+        double(value)
+    }
+}
+
+impl From<double> for f64 {
+    fn from(value: double) -> Self {
+        value.0
+    }
+}
+
+impl Deref for double {
+    type Target = f64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for double {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
 
 /// A column vector of 2 [double] values.
 ///
@@ -884,17 +3959,48 @@ unsafe impl Std140ArrayElement for double {}
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct dvec2(pub f64, pub f64);
 
 impl dvec2 {
     /// Creates a new [dvec2] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         dvec2(0.0, 0.0)
     }
+
+    /// Returns a new [dvec2] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        dvec2(self.0.recip(), self.1.recip())
+    }
+
+    /// Returns a new [dvec2] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        dvec2(self.0.sqrt().recip(), self.1.sqrt().recip())
+    }
+}
+
+impl Default for dvec2 {
+    fn default() -> Self {
+        dvec2::zero()
+    }
 }
 
-unsafe impl ReprStd140 for dvec2 {}
+unsafe impl ReprStd140 for dvec2 {
+    const GLSL_TYPE_NAME: &'static str = "dvec2";
+}
 unsafe impl Std140ArrayElement for dvec2 {}
+unsafe impl Std140PaddingFree for dvec2 {}
 
 impl Index<usize> for dvec2 {
     type Output = f64;
@@ -927,16 +4033,58 @@ impl IndexMut<usize> for dvec2 {
 /// ```
 #[repr(C, align(32))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct dvec3(pub f64, pub f64, pub f64);
 
 impl dvec3 {
     /// Creates a new [dvec3] with zeros in all positions.
-    pub fn zero() -> Self {
+    #[must_use]
+    pub const fn zero() -> Self {
         dvec3(0.0, 0.0, 0.0)
     }
+
+    /// Returns a new [dvec3] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        dvec3(self.0.recip(), self.1.recip(), self.2.recip())
+    }
+
+    /// Returns a new [dvec3] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        dvec3(
+            self.0.sqrt().recip(),
+            self.1.sqrt().recip(),
+            self.2.sqrt().recip(),
+        )
+    }
+}
+
+impl Default for dvec3 {
+    fn default() -> Self {
+        dvec3::zero()
+    }
 }
 
-unsafe impl ReprStd140 for dvec3 {}
+unsafe impl ReprStd140 for dvec3 {
+    const STD140_SIZE: usize = 24;
+
+    const GLSL_TYPE_NAME: &'static str = "dvec3";
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        // Only the components are copied; the trailing padding is not part of `STD140_SIZE`.
+        dst[..Self::STD140_SIZE]
+            .copy_from_slice(unsafe { initialized_bytes(self, Self::STD140_SIZE) });
+    }
+}
 unsafe impl Std140ArrayElement for dvec3 {}
 
 impl Index<usize> for dvec3 {
@@ -952,64 +4100,370 @@ impl Index<usize> for dvec3 {
     }
 }
 
-impl IndexMut<usize> for dvec3 {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        match index {
-            0 => &mut self.0,
-            1 => &mut self.1,
-            2 => &mut self.2,
-            _ => panic!("Index out of bounds"),
-        }
+impl IndexMut<usize> for dvec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+/// A column vector of 4 [double] values.
+///
+/// # Example
+///
+/// ```
+/// let value = std140::dvec4(0.0, 0.0, 0.0, 1.0);
+/// ```
+#[repr(C, align(32))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct dvec4(pub f64, pub f64, pub f64, pub f64);
+
+impl dvec4 {
+    /// Creates a new [dvec4] with zeros in all positions.
+    #[must_use]
+    pub const fn zero() -> Self {
+        dvec4(0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns a new [dvec4] with the reciprocal (`1 / x`) of each component.
+    ///
+    /// Components that are zero result in an infinity with the sign of the zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        dvec4(
+            self.0.recip(),
+            self.1.recip(),
+            self.2.recip(),
+            self.3.recip(),
+        )
+    }
+
+    /// Returns a new [dvec4] with the reciprocal of the square root (`1 / sqrt(x)`) of each
+    /// component, matching GLSL's `inversesqrt`.
+    ///
+    /// Components that are zero result in positive infinity; negative components result in NaN.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        dvec4(
+            self.0.sqrt().recip(),
+            self.1.sqrt().recip(),
+            self.2.sqrt().recip(),
+            self.3.sqrt().recip(),
+        )
+    }
+}
+
+impl Default for dvec4 {
+    fn default() -> Self {
+        dvec4::zero()
+    }
+}
+
+unsafe impl ReprStd140 for dvec4 {
+    const GLSL_TYPE_NAME: &'static str = "dvec4";
+}
+unsafe impl Std140ArrayElement for dvec4 {}
+unsafe impl Std140PaddingFree for dvec4 {}
+
+impl Index<usize> for dvec4 {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            3 => &self.3,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for dvec4 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            3 => &mut self.3,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+/// A matrix with `C` columns of type `V`, stored in column-major order.
+///
+/// Matrices are represented as an [array][struct@array] of their column vectors. Rather than using
+/// this type directly, use the aliases that correspond to the GLSL matrix types (e.g.
+/// [mat4x4][type@mat4x4] or [dmat3x2][type@dmat3x2]) and the constructor functions of the same
+/// name. Like GLSL matrices, a matrix must have 2, 3 or 4 columns to be a std140 type.
+///
+/// # Example
+///
+/// ```
+/// let value: std140::Matrix<std140::vec3, 2> = std140::mat2x3(
+///     std140::vec3(1.0, 0.0, 0.0),
+///     std140::vec3(0.0, 1.0, 0.0),
+/// );
+///
+/// assert_eq!(value.col(1), std140::vec3(0.0, 1.0, 0.0));
+/// ```
+///
+/// # Multiplication
+///
+/// The single and double precision floating point matrices may be multiplied with a vector or
+/// with another matrix of compatible dimensions, following the same column-major semantics as
+/// GLSL: a `matCxR` multiplied by a vector with `C` components produces a vector with `R`
+/// components, and a `matCxR` multiplied by a `matKxC` produces a `matKxR`.
+///
+/// ```
+/// let translation = std140::mat4x4(
+///     std140::vec4(1.0, 0.0, 0.0, 0.0),
+///     std140::vec4(0.0, 1.0, 0.0, 0.0),
+///     std140::vec4(0.0, 0.0, 1.0, 0.0),
+///     std140::vec4(1.0, 2.0, 3.0, 1.0),
+/// );
+///
+/// let position = translation * std140::vec4(0.0, 0.0, 0.0, 1.0);
+///
+/// assert_eq!(position, std140::vec4(1.0, 2.0, 3.0, 1.0));
+/// assert_eq!(translation * translation * position, std140::vec4(3.0, 6.0, 9.0, 1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Matrix<V, const C: usize>
+where
+    V: MatrixColumn,
+{
+    columns: array<V, C>,
+}
+
+impl<V, const C: usize> Matrix<V, { C }>
+where
+    V: MatrixColumn,
+{
+    /// Creates a new matrix with zeros in all positions.
+    #[must_use]
+    pub const fn zero() -> Self {
+        Matrix {
+            columns: array::from_wrapped([ArrayElementWrapper { element: V::ZERO }; C]),
+        }
+    }
+
+    /// Creates a new matrix from an array of its columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::mat2x2::from_columns([std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0)]);
+    ///
+    /// assert_eq!(value, std140::mat2x2(std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0)));
+    /// ```
+    #[must_use]
+    pub fn from_columns(columns: [V; C]) -> Self {
+        Matrix {
+            columns: array::from_fn(|i| columns[i]),
+        }
+    }
+
+    /// Returns the columns of this matrix as a plain Rust array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::mat2x2(std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0));
+    ///
+    /// assert_eq!(value.into_columns(), [std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0)]);
+    /// ```
+    #[must_use]
+    pub fn into_columns(self) -> [V; C] {
+        core::array::from_fn(|i| self.columns.internal[i].element)
+    }
+
+    /// Returns a reference to the column at `index`.
+    ///
+    /// Prefer this method (or [col][Matrix::col], which returns a copy) over indexing the
+    /// [array][struct@array] this matrix dereferences to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let value = std140::mat2x2(std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0));
+    ///
+    /// assert_eq!(value.column(1), &std140::vec2(3.0, 4.0));
+    /// ```
+    #[must_use]
+    pub fn column(&self, index: usize) -> &V {
+        &self.columns.internal[index].element
+    }
+
+    /// Returns a mutable reference to the column at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut value = std140::mat2x2(std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0));
+    ///
+    /// value.column_mut(0).1 = 5.0;
+    ///
+    /// assert_eq!(value, std140::mat2x2(std140::vec2(1.0, 5.0), std140::vec2(3.0, 4.0)));
+    /// ```
+    pub fn column_mut(&mut self, index: usize) -> &mut V {
+        &mut self.columns.internal[index].element
+    }
+
+    /// Returns a copy of the column at `index`, see [column][Matrix::column].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn col(&self, index: usize) -> V {
+        *self.column(index)
+    }
+}
+
+impl<V, const C: usize> Default for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+{
+    fn default() -> Self {
+        Matrix::zero()
+    }
+}
+
+unsafe impl<V, const C: usize> ReprStd140 for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+    MatrixColumns<C>: MatrixColumnCount,
+{
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        self.columns.copy_std140_bytes(dst);
+    }
+
+    #[cfg(feature = "std")]
+    fn glsl_field_declaration(name: &str, structs: &mut Vec<String>) -> String {
+        let _ = structs;
+
+        if V::ROWS == C {
+            format!("{}mat{} {}", V::PREFIX, C, name)
+        } else {
+            format!("{}mat{}x{} {}", V::PREFIX, C, V::ROWS, name)
+        }
+    }
+}
+unsafe impl<V, const C: usize> Std140ArrayElement for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+    MatrixColumns<C>: MatrixColumnCount,
+{
+}
+unsafe impl<V, const C: usize> Std140PaddingFree for Matrix<V, { C }>
+where
+    V: MatrixColumn + Std140UnpaddedArrayElement + Std140PaddingFree,
+    MatrixColumns<C>: MatrixColumnCount,
+{
+}
+
+/// Dereferences to the [array][struct@array] of columns that stores the matrix.
+///
+/// This is kept for backwards compatibility, but exposes how the matrix is stored rather than what
+/// it represents, and lets the methods of [array][struct@array] mix with those of the matrix.
+/// Prefer the explicit column accessors: [column][Matrix::column], [column_mut][Matrix::column_mut],
+/// [from_columns][Matrix::from_columns] and [into_columns][Matrix::into_columns].
+impl<V, const C: usize> Deref for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+{
+    type Target = array<V, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.columns
+    }
+}
+
+impl<V, const C: usize> DerefMut for Matrix<V, { C }>
+where
+    V: MatrixColumn,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.columns
     }
 }
 
-/// A column vector of 4 [double] values.
-///
-/// # Example
-///
-/// ```
-/// let value = std140::dvec4(0.0, 0.0, 0.0, 1.0);
-/// ```
-#[repr(C, align(32))]
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct dvec4(pub f64, pub f64, pub f64, pub f64);
-
-impl dvec4 {
-    /// Creates a new [dvec4] with zeros in all positions.
-    pub fn zero() -> Self {
-        dvec4(0.0, 0.0, 0.0, 0.0)
+impl<V, const C: usize> fmt::Debug for Matrix<V, { C }>
+where
+    V: MatrixColumn + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}mat{}x{}{:?}", V::PREFIX, C, V::ROWS, &self.columns)
     }
 }
 
-unsafe impl ReprStd140 for dvec4 {}
-unsafe impl Std140ArrayElement for dvec4 {}
+/// Implemented for the vector types that may be used as the columns of a [Matrix].
+#[doc(hidden)]
+pub trait MatrixColumn: Std140ArrayElement + Copy {
+    const ZERO: Self;
 
-impl Index<usize> for dvec4 {
-    type Output = f64;
+    /// The prefix of the GLSL matrix type name (`"d"` for double precision matrices).
+    const PREFIX: &'static str;
 
-    fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.0,
-            1 => &self.1,
-            2 => &self.2,
-            3 => &self.3,
-            _ => panic!("Index out of bounds"),
-        }
-    }
+    const ROWS: usize;
 }
 
-impl IndexMut<usize> for dvec4 {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        match index {
-            0 => &mut self.0,
-            1 => &mut self.1,
-            2 => &mut self.2,
-            3 => &mut self.3,
-            _ => panic!("Index out of bounds"),
+macro_rules! impl_matrix_column {
+    ($vector:ident, $prefix:literal, $rows:literal) => {
+        impl MatrixColumn for $vector {
+            const ZERO: Self = $vector::zero();
+
+            const PREFIX: &'static str = $prefix;
+
+            const ROWS: usize = $rows;
         }
-    }
+    };
 }
 
+impl_matrix_column!(vec2, "", 2);
+impl_matrix_column!(vec3, "", 3);
+impl_matrix_column!(vec4, "", 4);
+impl_matrix_column!(dvec2, "d", 2);
+impl_matrix_column!(dvec3, "d", 3);
+impl_matrix_column!(dvec4, "d", 4);
+
+/// The number of columns of a [Matrix], as a type.
+#[doc(hidden)]
+pub struct MatrixColumns<const C: usize>;
+
+/// Implemented for the numbers of columns a std140 [Matrix] may have: like GLSL matrices, it must
+/// have 2, 3 or 4 columns.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "a std140 matrix must have 2, 3 or 4 columns",
+    label = "unsupported number of columns"
+)]
+pub trait MatrixColumnCount {}
+
+impl MatrixColumnCount for MatrixColumns<2> {}
+impl MatrixColumnCount for MatrixColumns<3> {}
+impl MatrixColumnCount for MatrixColumns<4> {}
+
 /// A matrix with 2 columns and 2 rows, represented by 2 [vec2] vectors.
 ///
 /// # Example
@@ -1020,52 +4474,20 @@ impl IndexMut<usize> for dvec4 {
 ///     std140::vec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat2x2 {
-    columns: array<vec2, 2>,
-}
-
-impl mat2x2 {
-    /// Creates a new [mat2x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat2x2(vec2::zero(), vec2::zero())
-    }
-}
+pub type mat2x2 = Matrix<vec2, 2>;
 
-/// Initializes a [mat2x2][struct@mat2x2]
+/// Initializes a [mat2x2][type@mat2x2]
 ///
 /// # Example
 ///
-/// See [mat2x2][struct@mat2x2].
-pub fn mat2x2(c0: vec2, c1: vec2) -> mat2x2 {
-    mat2x2 {
+/// See [mat2x2][type@mat2x2].
+#[must_use]
+pub const fn mat2x2(c0: vec2, c1: vec2) -> mat2x2 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for mat2x2 {}
-unsafe impl Std140ArrayElement for mat2x2 {}
-
-impl Deref for mat2x2 {
-    type Target = array<vec2, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat2x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat2x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 2 columns and 3 rows, represented by 2 [vec3] vectors.
 ///
 /// # Example
@@ -1076,52 +4498,20 @@ impl fmt::Debug for mat2x2 {
 ///     std140::vec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat2x3 {
-    columns: array<vec3, 2>,
-}
-
-impl mat2x3 {
-    /// Creates a new [mat2x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat2x3(vec3::zero(), vec3::zero())
-    }
-}
+pub type mat2x3 = Matrix<vec3, 2>;
 
-/// Initializes a [mat2x3][struct@mat2x3]
+/// Initializes a [mat2x3][type@mat2x3]
 ///
 /// # Example
 ///
-/// See [mat2x3][struct@mat2x3].
-pub fn mat2x3(c0: vec3, c1: vec3) -> mat2x3 {
-    mat2x3 {
+/// See [mat2x3][type@mat2x3].
+#[must_use]
+pub const fn mat2x3(c0: vec3, c1: vec3) -> mat2x3 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for mat2x3 {}
-unsafe impl Std140ArrayElement for mat2x3 {}
-
-impl Deref for mat2x3 {
-    type Target = array<vec3, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat2x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat2x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 2 columns and 4 rows, represented by 2 [vec4] vectors.
 ///
 /// # Example
@@ -1132,52 +4522,20 @@ impl fmt::Debug for mat2x3 {
 ///     std140::vec4(0.0, 0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat2x4 {
-    columns: array<vec4, 2>,
-}
-
-impl mat2x4 {
-    /// Creates a new [mat2x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat2x4(vec4::zero(), vec4::zero())
-    }
-}
+pub type mat2x4 = Matrix<vec4, 2>;
 
-/// Initializes a [mat2x4][struct@mat2x4]
+/// Initializes a [mat2x4][type@mat2x4]
 ///
 /// # Example
 ///
-/// See [mat2x4][struct@mat2x4].
-pub fn mat2x4(c0: vec4, c1: vec4) -> mat2x4 {
-    mat2x4 {
+/// See [mat2x4][type@mat2x4].
+#[must_use]
+pub const fn mat2x4(c0: vec4, c1: vec4) -> mat2x4 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for mat2x4 {}
-unsafe impl Std140ArrayElement for mat2x4 {}
-
-impl Deref for mat2x4 {
-    type Target = array<vec4, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat2x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat2x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x4{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 2 rows, represented by 3 [vec2] vectors.
 ///
 /// # Example
@@ -1189,52 +4547,20 @@ impl fmt::Debug for mat2x4 {
 ///     std140::vec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat3x2 {
-    columns: array<vec2, 3>,
-}
+pub type mat3x2 = Matrix<vec2, 3>;
 
-impl mat3x2 {
-    /// Creates a new [mat3x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat3x2(vec2::zero(), vec2::zero(), vec2::zero())
-    }
-}
-
-/// Initializes a [mat3x2][struct@mat3x2]
+/// Initializes a [mat3x2][type@mat3x2]
 ///
 /// # Example
 ///
-/// See [mat3x2][struct@mat3x2].
-pub fn mat3x2(c0: vec2, c1: vec2, c2: vec2) -> mat3x2 {
-    mat3x2 {
+/// See [mat3x2][type@mat3x2].
+#[must_use]
+pub const fn mat3x2(c0: vec2, c1: vec2, c2: vec2) -> mat3x2 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for mat3x2 {}
-unsafe impl Std140ArrayElement for mat3x2 {}
-
-impl Deref for mat3x2 {
-    type Target = array<vec2, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat3x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat3x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 3 rows, represented by 3 [vec3] vectors.
 ///
 /// # Example
@@ -1246,52 +4572,20 @@ impl fmt::Debug for mat3x2 {
 ///     std140::vec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat3x3 {
-    columns: array<vec3, 3>,
-}
-
-impl mat3x3 {
-    /// Creates a new [mat3x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat3x3(vec3::zero(), vec3::zero(), vec3::zero())
-    }
-}
+pub type mat3x3 = Matrix<vec3, 3>;
 
-/// Initializes a [mat3x3][struct@mat3x3]
+/// Initializes a [mat3x3][type@mat3x3]
 ///
 /// # Example
 ///
-/// See [mat3x3][struct@mat3x3].
-pub fn mat3x3(c0: vec3, c1: vec3, c2: vec3) -> mat3x3 {
-    mat3x3 {
+/// See [mat3x3][type@mat3x3].
+#[must_use]
+pub const fn mat3x3(c0: vec3, c1: vec3, c2: vec3) -> mat3x3 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for mat3x3 {}
-unsafe impl Std140ArrayElement for mat3x3 {}
-
-impl Deref for mat3x3 {
-    type Target = array<vec3, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat3x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat3x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 4 rows, represented by 3 [vec4] vectors.
 ///
 /// # Example
@@ -1303,52 +4597,20 @@ impl fmt::Debug for mat3x3 {
 ///     std140::vec4(0.0, 0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat3x4 {
-    columns: array<vec4, 3>,
-}
-
-impl mat3x4 {
-    /// Creates a new [mat3x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat3x4(vec4::zero(), vec4::zero(), vec4::zero())
-    }
-}
+pub type mat3x4 = Matrix<vec4, 3>;
 
-/// Initializes a [mat3x4][struct@mat3x4]
+/// Initializes a [mat3x4][type@mat3x4]
 ///
 /// # Example
 ///
-/// See [mat3x4][struct@mat3x4].
-pub fn mat3x4(c0: vec4, c1: vec4, c2: vec4) -> mat3x4 {
-    mat3x4 {
+/// See [mat3x4][type@mat3x4].
+#[must_use]
+pub const fn mat3x4(c0: vec4, c1: vec4, c2: vec4) -> mat3x4 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for mat3x4 {}
-unsafe impl Std140ArrayElement for mat3x4 {}
-
-impl Deref for mat3x4 {
-    type Target = array<vec4, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat3x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat3x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x4{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 2 rows, represented by 4 [vec2] vectors.
 ///
 /// # Example
@@ -1361,52 +4623,20 @@ impl fmt::Debug for mat3x4 {
 ///     std140::vec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat4x2 {
-    columns: array<vec2, 4>,
-}
-
-impl mat4x2 {
-    /// Creates a new [mat4x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat4x2(vec2::zero(), vec2::zero(), vec2::zero(), vec2::zero())
-    }
-}
+pub type mat4x2 = Matrix<vec2, 4>;
 
-/// Initializes a [mat4x2][struct@mat4x2]
+/// Initializes a [mat4x2][type@mat4x2]
 ///
 /// # Example
 ///
-/// See [mat4x2][struct@mat4x2].
-pub fn mat4x2(c0: vec2, c1: vec2, c2: vec2, c3: vec2) -> mat4x2 {
-    mat4x2 {
+/// See [mat4x2][type@mat4x2].
+#[must_use]
+pub const fn mat4x2(c0: vec2, c1: vec2, c2: vec2, c3: vec2) -> mat4x2 {
+    Matrix {
         columns: array![c0, c1, c2, c3],
     }
 }
 
-unsafe impl ReprStd140 for mat4x2 {}
-unsafe impl Std140ArrayElement for mat4x2 {}
-
-impl Deref for mat4x2 {
-    type Target = array<vec2, 4>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat4x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat4x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 3 rows, represented by 4 [vec3] vectors.
 ///
 /// # Example
@@ -1419,52 +4649,20 @@ impl fmt::Debug for mat4x2 {
 ///     std140::vec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat4x3 {
-    columns: array<vec3, 4>,
-}
+pub type mat4x3 = Matrix<vec3, 4>;
 
-impl mat4x3 {
-    /// Creates a new [mat4x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat4x3(vec3::zero(), vec3::zero(), vec3::zero(), vec3::zero())
-    }
-}
-
-/// Initializes a [mat4x3][struct@mat4x3]
+/// Initializes a [mat4x3][type@mat4x3]
 ///
 /// # Example
 ///
-/// See [mat4x3][struct@mat4x3].
-pub fn mat4x3(c0: vec3, c1: vec3, c2: vec3, c3: vec3) -> mat4x3 {
-    mat4x3 {
+/// See [mat4x3][type@mat4x3].
+#[must_use]
+pub const fn mat4x3(c0: vec3, c1: vec3, c2: vec3, c3: vec3) -> mat4x3 {
+    Matrix {
         columns: array![c0, c1, c2, c3],
     }
 }
 
-unsafe impl ReprStd140 for mat4x3 {}
-unsafe impl Std140ArrayElement for mat4x3 {}
-
-impl Deref for mat4x3 {
-    type Target = array<vec3, 4>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for mat4x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for mat4x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 4 rows, represented by 4 [vec4] vectors.
 ///
 /// # Example
@@ -1472,54 +4670,70 @@ impl fmt::Debug for mat4x3 {
 /// ```
 /// let value = std140::mat4x4(
 ///     std140::vec4(0.0, 0.0, 0.0, 1.0),
-///     std140::vec4(0.0, 0.0, 0.0, 1.0),
-///     std140::vec4(0.0, 0.0, 0.0, 1.0),
-///     std140::vec4(0.0, 0.0, 0.0, 1.0),
-/// );
-/// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct mat4x4 {
-    columns: array<vec4, 4>,
-}
-
-impl mat4x4 {
-    /// Creates a new [mat4x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        mat4x4(vec4::zero(), vec4::zero(), vec4::zero(), vec4::zero())
-    }
-}
-
-/// Initializes a [mat4x4][struct@mat4x4]
-///
-/// # Example
-///
-/// See [mat4x4][struct@mat4x4].
-pub fn mat4x4(c0: vec4, c1: vec4, c2: vec4, c3: vec4) -> mat4x4 {
-    mat4x4 {
-        columns: array![c0, c1, c2, c3],
-    }
-}
-
-unsafe impl ReprStd140 for mat4x4 {}
-unsafe impl Std140ArrayElement for mat4x4 {}
-
-impl Deref for mat4x4 {
-    type Target = array<vec4, 4>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
+///     std140::vec4(0.0, 0.0, 0.0, 1.0),
+///     std140::vec4(0.0, 0.0, 0.0, 1.0),
+///     std140::vec4(0.0, 0.0, 0.0, 1.0),
+/// );
+/// ```
+pub type mat4x4 = Matrix<vec4, 4>;
 
-impl DerefMut for mat4x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
+/// Initializes a [mat4x4][type@mat4x4]
+///
+/// # Example
+///
+/// See [mat4x4][type@mat4x4].
+#[must_use]
+pub const fn mat4x4(c0: vec4, c1: vec4, c2: vec4, c3: vec4) -> mat4x4 {
+    Matrix {
+        columns: array![c0, c1, c2, c3],
     }
 }
 
-impl fmt::Debug for mat4x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x4{:?}", &self.columns))
+impl mat4x4 {
+    /// Creates a new [mat4x4][type@mat4x4] identity matrix.
+    #[must_use]
+    pub const fn identity() -> Self {
+        mat4x4::from_diagonal(vec4(1.0, 1.0, 1.0, 1.0))
+    }
+
+    /// Creates a new [mat4x4][type@mat4x4] with the components of `diagonal` on its diagonal and
+    /// zeros in all other positions.
+    #[must_use]
+    pub const fn from_diagonal(diagonal: vec4) -> Self {
+        mat4x4(
+            vec4(diagonal.0, 0.0, 0.0, 0.0),
+            vec4(0.0, diagonal.1, 0.0, 0.0),
+            vec4(0.0, 0.0, diagonal.2, 0.0),
+            vec4(0.0, 0.0, 0.0, diagonal.3),
+        )
+    }
+
+    /// Creates a new [mat4x4][type@mat4x4] that translates by `translation`.
+    ///
+    /// The translation is stored in the 4th column, as GLSL expects for a column vector multiplied
+    /// on the right (`transform * vec4(position, 1.0)`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let transform = std140::mat4x4::translation(std140::vec3(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(transform.col(3), std140::vec4(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    #[must_use]
+    pub const fn translation(translation: vec3) -> Self {
+        mat4x4(
+            vec4(1.0, 0.0, 0.0, 0.0),
+            vec4(0.0, 1.0, 0.0, 0.0),
+            vec4(0.0, 0.0, 1.0, 0.0),
+            vec4(translation.0, translation.1, translation.2, 1.0),
+        )
+    }
+
+    /// Creates a new [mat4x4][type@mat4x4] that scales by `scale` along the `x`, `y` and `z` axes.
+    #[must_use]
+    pub const fn scale(scale: vec3) -> Self {
+        mat4x4::from_diagonal(vec4(scale.0, scale.1, scale.2, 1.0))
     }
 }
 
@@ -1533,52 +4747,20 @@ impl fmt::Debug for mat4x4 {
 ///     std140::dvec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat2x2 {
-    columns: array<dvec2, 2>,
-}
-
-impl dmat2x2 {
-    /// Creates a new [dmat2x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat2x2(dvec2::zero(), dvec2::zero())
-    }
-}
+pub type dmat2x2 = Matrix<dvec2, 2>;
 
-/// Initializes a [dmat2x2][struct@dmat2x2]
+/// Initializes a [dmat2x2][type@dmat2x2]
 ///
 /// # Example
 ///
-/// See [dmat2x2][struct@dmat2x2].
-pub fn dmat2x2(c0: dvec2, c1: dvec2) -> dmat2x2 {
-    dmat2x2 {
+/// See [dmat2x2][type@dmat2x2].
+#[must_use]
+pub const fn dmat2x2(c0: dvec2, c1: dvec2) -> dmat2x2 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for dmat2x2 {}
-unsafe impl Std140ArrayElement for dmat2x2 {}
-
-impl Deref for dmat2x2 {
-    type Target = array<dvec2, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat2x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat2x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat2x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 2 columns and 3 rows, represented by 2 [dvec3] vectors.
 ///
 /// # Example
@@ -1589,52 +4771,20 @@ impl fmt::Debug for dmat2x2 {
 ///     std140::dvec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat2x3 {
-    columns: array<dvec3, 2>,
-}
+pub type dmat2x3 = Matrix<dvec3, 2>;
 
-impl dmat2x3 {
-    /// Creates a new [dmat2x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat2x3(dvec3::zero(), dvec3::zero())
-    }
-}
-
-/// Initializes a [dmat2x3][struct@dmat2x3]
+/// Initializes a [dmat2x3][type@dmat2x3]
 ///
 /// # Example
 ///
-/// See [dmat2x3][struct@dmat2x3].
-pub fn dmat2x3(c0: dvec3, c1: dvec3) -> dmat2x3 {
-    dmat2x3 {
+/// See [dmat2x3][type@dmat2x3].
+#[must_use]
+pub const fn dmat2x3(c0: dvec3, c1: dvec3) -> dmat2x3 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for dmat2x3 {}
-unsafe impl Std140ArrayElement for dmat2x3 {}
-
-impl Deref for dmat2x3 {
-    type Target = array<dvec3, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat2x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat2x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat2x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 2 columns and 4 rows, represented by 2 [dvec4] vectors.
 ///
 /// # Example
@@ -1645,52 +4795,20 @@ impl fmt::Debug for dmat2x3 {
 ///     std140::dvec4(0.0, 0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat2x4 {
-    columns: array<dvec4, 2>,
-}
-
-impl dmat2x4 {
-    /// Creates a new [dmat2x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat2x4(dvec4::zero(), dvec4::zero())
-    }
-}
+pub type dmat2x4 = Matrix<dvec4, 2>;
 
-/// Initializes a [dmat2x4][struct@dmat2x4]
+/// Initializes a [dmat2x4][type@dmat2x4]
 ///
 /// # Example
 ///
-/// See [dmat2x4][struct@dmat2x4].
-pub fn dmat2x4(c0: dvec4, c1: dvec4) -> dmat2x4 {
-    dmat2x4 {
+/// See [dmat2x4][type@dmat2x4].
+#[must_use]
+pub const fn dmat2x4(c0: dvec4, c1: dvec4) -> dmat2x4 {
+    Matrix {
         columns: array![c0, c1],
     }
 }
 
-unsafe impl ReprStd140 for dmat2x4 {}
-unsafe impl Std140ArrayElement for dmat2x4 {}
-
-impl Deref for dmat2x4 {
-    type Target = array<dvec4, 2>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat2x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat2x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat2x4{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 2 rows, represented by 3 [dvec2] vectors.
 ///
 /// # Example
@@ -1702,52 +4820,20 @@ impl fmt::Debug for dmat2x4 {
 ///     std140::dvec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat3x2 {
-    columns: array<dvec2, 3>,
-}
-
-impl dmat3x2 {
-    /// Creates a new [dmat3x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat3x2(dvec2::zero(), dvec2::zero(), dvec2::zero())
-    }
-}
+pub type dmat3x2 = Matrix<dvec2, 3>;
 
-/// Initializes a [dmat3x2][struct@dmat3x2]
+/// Initializes a [dmat3x2][type@dmat3x2]
 ///
 /// # Example
 ///
-/// See [dmat3x2][struct@dmat3x2].
-pub fn dmat3x2(c0: dvec2, c1: dvec2, c2: dvec2) -> dmat3x2 {
-    dmat3x2 {
+/// See [dmat3x2][type@dmat3x2].
+#[must_use]
+pub const fn dmat3x2(c0: dvec2, c1: dvec2, c2: dvec2) -> dmat3x2 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for dmat3x2 {}
-unsafe impl Std140ArrayElement for dmat3x2 {}
-
-impl Deref for dmat3x2 {
-    type Target = array<dvec2, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat3x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat3x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat3x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 3 rows, represented by 3 [dvec3] vectors.
 ///
 /// # Example
@@ -1759,52 +4845,20 @@ impl fmt::Debug for dmat3x2 {
 ///     std140::dvec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat3x3 {
-    columns: array<dvec3, 3>,
-}
-
-impl dmat3x3 {
-    /// Creates a new [dmat3x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat3x3(dvec3::zero(), dvec3::zero(), dvec3::zero())
-    }
-}
+pub type dmat3x3 = Matrix<dvec3, 3>;
 
-/// Initializes a [dmat3x3][struct@dmat3x3]
+/// Initializes a [dmat3x3][type@dmat3x3]
 ///
 /// # Example
 ///
-/// See [dmat3x3][struct@dmat3x3].
-pub fn dmat3x3(c0: dvec3, c1: dvec3, c2: dvec3) -> dmat3x3 {
-    dmat3x3 {
+/// See [dmat3x3][type@dmat3x3].
+#[must_use]
+pub const fn dmat3x3(c0: dvec3, c1: dvec3, c2: dvec3) -> dmat3x3 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for dmat3x3 {}
-unsafe impl Std140ArrayElement for dmat3x3 {}
-
-impl Deref for dmat3x3 {
-    type Target = array<dvec3, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat3x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat3x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat3x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 3 columns and 4 rows, represented by 3 [dvec4] vectors.
 ///
 /// # Example
@@ -1816,52 +4870,20 @@ impl fmt::Debug for dmat3x3 {
 ///     std140::dvec4(0.0, 0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat3x4 {
-    columns: array<dvec4, 3>,
-}
+pub type dmat3x4 = Matrix<dvec4, 3>;
 
-impl dmat3x4 {
-    /// Creates a new [dmat3x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat3x4(dvec4::zero(), dvec4::zero(), dvec4::zero())
-    }
-}
-
-/// Initializes a [dmat3x4][struct@dmat3x4]
+/// Initializes a [dmat3x4][type@dmat3x4]
 ///
 /// # Example
 ///
-/// See [dmat3x4][struct@dmat3x4].
-pub fn dmat3x4(c0: dvec4, c1: dvec4, c2: dvec4) -> dmat3x4 {
-    dmat3x4 {
+/// See [dmat3x4][type@dmat3x4].
+#[must_use]
+pub const fn dmat3x4(c0: dvec4, c1: dvec4, c2: dvec4) -> dmat3x4 {
+    Matrix {
         columns: array![c0, c1, c2],
     }
 }
 
-unsafe impl ReprStd140 for dmat3x4 {}
-unsafe impl Std140ArrayElement for dmat3x4 {}
-
-impl Deref for dmat3x4 {
-    type Target = array<dvec4, 3>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat3x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat3x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat3x4{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 2 rows, represented by 4 [dvec2] vectors.
 ///
 /// # Example
@@ -1874,52 +4896,20 @@ impl fmt::Debug for dmat3x4 {
 ///     std140::dvec2(0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat4x2 {
-    columns: array<dvec2, 4>,
-}
-
-impl dmat4x2 {
-    /// Creates a new [dmat4x2] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat4x2(dvec2::zero(), dvec2::zero(), dvec2::zero(), dvec2::zero())
-    }
-}
+pub type dmat4x2 = Matrix<dvec2, 4>;
 
-/// Initializes a [dmat4x2][struct@dmat4x2]
+/// Initializes a [dmat4x2][type@dmat4x2]
 ///
 /// # Example
 ///
-/// See [dmat4x2][struct@dmat4x2].
-pub fn dmat4x2(c0: dvec2, c1: dvec2, c2: dvec2, c3: dvec2) -> dmat4x2 {
-    dmat4x2 {
+/// See [dmat4x2][type@dmat4x2].
+#[must_use]
+pub const fn dmat4x2(c0: dvec2, c1: dvec2, c2: dvec2, c3: dvec2) -> dmat4x2 {
+    Matrix {
         columns: array![c0, c1, c2, c3],
     }
 }
 
-unsafe impl ReprStd140 for dmat4x2 {}
-unsafe impl Std140ArrayElement for dmat4x2 {}
-
-impl Deref for dmat4x2 {
-    type Target = array<dvec2, 4>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat4x2 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat4x2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat4x2{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 3 rows, represented by 4 [dvec3] vectors.
 ///
 /// # Example
@@ -1932,52 +4922,20 @@ impl fmt::Debug for dmat4x2 {
 ///     std140::dvec3(0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat4x3 {
-    columns: array<dvec3, 4>,
-}
-
-impl dmat4x3 {
-    /// Creates a new [dmat4x3] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat4x3(dvec3::zero(), dvec3::zero(), dvec3::zero(), dvec3::zero())
-    }
-}
+pub type dmat4x3 = Matrix<dvec3, 4>;
 
-/// Initializes a [dmat4x3][struct@dmat4x3]
+/// Initializes a [dmat4x3][type@dmat4x3]
 ///
 /// # Example
 ///
-/// See [dmat4x3][struct@dmat4x3].
-pub fn dmat4x3(c0: dvec3, c1: dvec3, c2: dvec3, c3: dvec3) -> dmat4x3 {
-    dmat4x3 {
+/// See [dmat4x3][type@dmat4x3].
+#[must_use]
+pub const fn dmat4x3(c0: dvec3, c1: dvec3, c2: dvec3, c3: dvec3) -> dmat4x3 {
+    Matrix {
         columns: array![c0, c1, c2, c3],
     }
 }
 
-unsafe impl ReprStd140 for dmat4x3 {}
-unsafe impl Std140ArrayElement for dmat4x3 {}
-
-impl Deref for dmat4x3 {
-    type Target = array<dvec3, 4>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
-}
-
-impl DerefMut for dmat4x3 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
-}
-
-impl fmt::Debug for dmat4x3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat4x3{:?}", &self.columns))
-    }
-}
-
 /// A matrix with 4 columns and 4 rows, represented by 4 [dvec4] vectors.
 ///
 /// # Example
@@ -1990,48 +4948,359 @@ impl fmt::Debug for dmat4x3 {
 ///     std140::dvec4(0.0, 0.0, 0.0, 1.0),
 /// );
 /// ```
-#[derive(Clone, Copy, PartialEq)]
-pub struct dmat4x4 {
-    columns: array<dvec4, 4>,
-}
-
-impl dmat4x4 {
-    /// Creates a new [dmat4x4] with zeros in all positions.
-    pub fn zero() -> Self {
-        dmat4x4(dvec4::zero(), dvec4::zero(), dvec4::zero(), dvec4::zero())
-    }
-}
+pub type dmat4x4 = Matrix<dvec4, 4>;
 
-/// Initializes a [dmat4x4][struct@dmat4x4]
+/// Initializes a [dmat4x4][type@dmat4x4]
 ///
 /// # Example
 ///
-/// See [dmat4x4][struct@dmat4x4].
-pub fn dmat4x4(c0: dvec4, c1: dvec4, c2: dvec4, c3: dvec4) -> dmat4x4 {
-    dmat4x4 {
+/// See [dmat4x4][type@dmat4x4].
+#[must_use]
+pub const fn dmat4x4(c0: dvec4, c1: dvec4, c2: dvec4, c3: dvec4) -> dmat4x4 {
+    Matrix {
         columns: array![c0, c1, c2, c3],
     }
 }
 
-unsafe impl ReprStd140 for dmat4x4 {}
-unsafe impl Std140ArrayElement for dmat4x4 {}
+macro_rules! impl_matrix_columns {
+    ($matrix:ident, $column:ident, $len:literal) => {
+        impl $matrix {
+            #[doc = concat!("Returns a reference to the columns of this [", stringify!($matrix), "].")]
+            #[must_use]
+            pub fn columns(&self) -> &array<$column, $len> {
+                &self.columns
+            }
+        }
 
-impl Deref for dmat4x4 {
-    type Target = array<dvec4, 4>;
+        impl From<[$column; $len]> for $matrix {
+            fn from(columns: [$column; $len]) -> Self {
+                $matrix {
+                    columns: array {
+                        internal: columns.map(|element| ArrayElementWrapper { element }),
+                    },
+                }
+            }
+        }
 
-    fn deref(&self) -> &Self::Target {
-        &self.columns
-    }
+        impl From<$matrix> for [$column; $len] {
+            fn from(matrix: $matrix) -> Self {
+                matrix.columns.internal.map(|wrapper| wrapper.element)
+            }
+        }
+    };
 }
 
-impl DerefMut for dmat4x4 {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.columns
-    }
+impl_matrix_columns!(mat2x2, vec2, 2);
+impl_matrix_columns!(mat2x3, vec3, 2);
+impl_matrix_columns!(mat2x4, vec4, 2);
+impl_matrix_columns!(mat3x2, vec2, 3);
+impl_matrix_columns!(mat3x3, vec3, 3);
+impl_matrix_columns!(mat3x4, vec4, 3);
+impl_matrix_columns!(mat4x2, vec2, 4);
+impl_matrix_columns!(mat4x3, vec3, 4);
+impl_matrix_columns!(mat4x4, vec4, 4);
+impl_matrix_columns!(dmat2x2, dvec2, 2);
+impl_matrix_columns!(dmat2x3, dvec3, 2);
+impl_matrix_columns!(dmat2x4, dvec4, 2);
+impl_matrix_columns!(dmat3x2, dvec2, 3);
+impl_matrix_columns!(dmat3x3, dvec3, 3);
+impl_matrix_columns!(dmat3x4, dvec4, 3);
+impl_matrix_columns!(dmat4x2, dvec2, 4);
+impl_matrix_columns!(dmat4x3, dvec3, 4);
+impl_matrix_columns!(dmat4x4, dvec4, 4);
+
+macro_rules! impl_matrix_accessors {
+    ($matrix:ident, $row:ident, $($c:tt),*) => {
+        impl $matrix {
+            #[doc = concat!("Returns a copy of the row at `index` of this [", stringify!($matrix), "].")]
+            ///
+            /// The matrix is stored in column-major order, which means the components of a row are
+            /// not contiguous in memory: the row is assembled from the component at `index` of each
+            /// column. Consequently, there is no mutable counterpart to this method.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[must_use]
+            pub fn row(&self, index: usize) -> $row {
+                $row($(self.columns.internal[$c].element[index]),*)
+            }
+        }
+    };
 }
 
-impl fmt::Debug for dmat4x4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("dmat4x4{:?}", &self.columns))
-    }
+impl_matrix_accessors!(mat2x2, vec2, 0, 1);
+impl_matrix_accessors!(mat2x3, vec2, 0, 1);
+impl_matrix_accessors!(mat2x4, vec2, 0, 1);
+impl_matrix_accessors!(mat3x2, vec3, 0, 1, 2);
+impl_matrix_accessors!(mat3x3, vec3, 0, 1, 2);
+impl_matrix_accessors!(mat3x4, vec3, 0, 1, 2);
+impl_matrix_accessors!(mat4x2, vec4, 0, 1, 2, 3);
+impl_matrix_accessors!(mat4x3, vec4, 0, 1, 2, 3);
+impl_matrix_accessors!(mat4x4, vec4, 0, 1, 2, 3);
+impl_matrix_accessors!(dmat2x2, dvec2, 0, 1);
+impl_matrix_accessors!(dmat2x3, dvec2, 0, 1);
+impl_matrix_accessors!(dmat2x4, dvec2, 0, 1);
+impl_matrix_accessors!(dmat3x2, dvec3, 0, 1, 2);
+impl_matrix_accessors!(dmat3x3, dvec3, 0, 1, 2);
+impl_matrix_accessors!(dmat3x4, dvec3, 0, 1, 2);
+impl_matrix_accessors!(dmat4x2, dvec4, 0, 1, 2, 3);
+impl_matrix_accessors!(dmat4x3, dvec4, 0, 1, 2, 3);
+impl_matrix_accessors!(dmat4x4, dvec4, 0, 1, 2, 3);
+
+macro_rules! impl_matrix_transpose {
+    ($matrix:ident, $transposed:ident, $($r:tt),*) => {
+        impl $matrix {
+            #[doc = concat!(
+                "Returns the transpose of this [", stringify!($matrix), "] as a [",
+                stringify!($transposed), "][type@", stringify!($transposed), "], such that the ",
+                "rows of this matrix are the columns of the transposed matrix."
+            )]
+            #[must_use]
+            pub fn transpose(&self) -> $transposed {
+                $transposed($(self.row($r)),*)
+            }
+        }
+    };
+}
+
+impl_matrix_transpose!(mat2x2, mat2x2, 0, 1);
+impl_matrix_transpose!(mat2x3, mat3x2, 0, 1, 2);
+impl_matrix_transpose!(mat2x4, mat4x2, 0, 1, 2, 3);
+impl_matrix_transpose!(mat3x2, mat2x3, 0, 1);
+impl_matrix_transpose!(mat3x3, mat3x3, 0, 1, 2);
+impl_matrix_transpose!(mat3x4, mat4x3, 0, 1, 2, 3);
+impl_matrix_transpose!(mat4x2, mat2x4, 0, 1);
+impl_matrix_transpose!(mat4x3, mat3x4, 0, 1, 2);
+impl_matrix_transpose!(mat4x4, mat4x4, 0, 1, 2, 3);
+impl_matrix_transpose!(dmat2x2, dmat2x2, 0, 1);
+impl_matrix_transpose!(dmat2x3, dmat3x2, 0, 1, 2);
+impl_matrix_transpose!(dmat2x4, dmat4x2, 0, 1, 2, 3);
+impl_matrix_transpose!(dmat3x2, dmat2x3, 0, 1);
+impl_matrix_transpose!(dmat3x3, dmat3x3, 0, 1, 2);
+impl_matrix_transpose!(dmat3x4, dmat4x3, 0, 1, 2, 3);
+impl_matrix_transpose!(dmat4x2, dmat2x4, 0, 1);
+impl_matrix_transpose!(dmat4x3, dmat3x4, 0, 1, 2);
+impl_matrix_transpose!(dmat4x4, dmat4x4, 0, 1, 2, 3);
+
+macro_rules! impl_matrix_mat3_conversions {
+    ($mat4:ident, $mat3:ident, $vec4:ident, $vec3:ident) => {
+        impl $mat4 {
+            #[doc = concat!(
+                "Creates a [", stringify!($mat4), "][type@", stringify!($mat4), "] with `value` in ",
+                "its upper-left 3x3 corner and the 4th row and column of the identity matrix."
+            )]
+            ///
+            /// This promotes e.g. a rotation matrix to a transformation matrix in homogeneous
+            /// coordinates; the inverse is `to_mat3`.
+            #[must_use]
+            pub const fn from_mat3(value: $mat3) -> Self {
+                let c = &value.columns.internal;
+
+                $mat4(
+                    $vec4(c[0].element.0, c[0].element.1, c[0].element.2, 0.0),
+                    $vec4(c[1].element.0, c[1].element.1, c[1].element.2, 0.0),
+                    $vec4(c[2].element.0, c[2].element.1, c[2].element.2, 0.0),
+                    $vec4(0.0, 0.0, 0.0, 1.0),
+                )
+            }
+
+            #[doc = concat!(
+                "Returns the upper-left 3x3 corner of this [", stringify!($mat4), "][type@",
+                stringify!($mat4), "], discarding the 4th row and column."
+            )]
+            #[must_use]
+            pub const fn to_mat3(&self) -> $mat3 {
+                let c = &self.columns.internal;
+
+                $mat3(
+                    $vec3(c[0].element.0, c[0].element.1, c[0].element.2),
+                    $vec3(c[1].element.0, c[1].element.1, c[1].element.2),
+                    $vec3(c[2].element.0, c[2].element.1, c[2].element.2),
+                )
+            }
+        }
+    };
+}
+
+impl_matrix_mat3_conversions!(mat4x4, mat3x3, vec4, vec3);
+impl_matrix_mat3_conversions!(dmat4x4, dmat3x3, dvec4, dvec3);
+
+macro_rules! splat_component {
+    ($c:tt, $value:expr) => {
+        $value
+    };
+}
+
+macro_rules! impl_vector_get {
+    ($vector:ident, $component:ty, $($c:tt),*) => {
+        impl $vector {
+            #[doc = concat!("Creates a [", stringify!($vector), "] with every component set to `value`.")]
+            ///
+            /// This is equivalent to the GLSL constructor that takes a single scalar.
+            #[must_use]
+            pub const fn splat(value: $component) -> Self {
+                $vector($(splat_component!($c, value)),*)
+            }
+
+            /// Returns a reference to the component at `index`, or `None` if `index` is out of
+            /// bounds.
+            #[must_use]
+            pub fn get(&self, index: usize) -> Option<&$component> {
+                match index {
+                    $($c => Some(&self.$c),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns a mutable reference to the component at `index`, or `None` if `index` is out
+            /// of bounds.
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut $component> {
+                match index {
+                    $($c => Some(&mut self.$c),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns a copy of the component at `index`.
+            ///
+            /// Unlike [Index], this may be used in `const` contexts, e.g. to derive a constant
+            /// uniform value from another constant vector.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds; in a `const` context, this results in a compile
+            /// error.
+            #[must_use]
+            pub const fn get_const(&self, index: usize) -> $component {
+                match index {
+                    $($c => self.$c,)*
+                    _ => panic!("Index out of bounds"),
+                }
+            }
+
+            /// Returns an iterator over the components of this vector, in order.
+            pub fn components(&self) -> impl Iterator<Item = $component> {
+                IntoIterator::into_iter([$(self.$c),*])
+            }
+
+            /// Returns an iterator over mutable references to the components of this vector, in
+            /// order.
+            pub fn components_mut(&mut self) -> impl Iterator<Item = &mut $component> {
+                IntoIterator::into_iter([$(&mut self.$c),*])
+            }
+        }
+    };
+}
+
+impl_vector_get!(vec2, f32, 0, 1);
+impl_vector_get!(vec3, f32, 0, 1, 2);
+impl_vector_get!(vec4, f32, 0, 1, 2, 3);
+impl_vector_get!(ivec2, i32, 0, 1);
+impl_vector_get!(ivec3, i32, 0, 1, 2);
+impl_vector_get!(ivec4, i32, 0, 1, 2, 3);
+impl_vector_get!(uvec2, u32, 0, 1);
+impl_vector_get!(uvec3, u32, 0, 1, 2);
+impl_vector_get!(uvec4, u32, 0, 1, 2, 3);
+impl_vector_get!(bvec2, boolean, 0, 1);
+impl_vector_get!(bvec3, boolean, 0, 1, 2);
+impl_vector_get!(bvec4, boolean, 0, 1, 2, 3);
+impl_vector_get!(dvec2, f64, 0, 1);
+impl_vector_get!(dvec3, f64, 0, 1, 2);
+impl_vector_get!(dvec4, f64, 0, 1, 2, 3);
+
+macro_rules! impl_vector_extend_truncate {
+    ($vector:ident, $from:ident, $smaller:ident, $component:ty, $last:ident, $($c:tt),*) => {
+        impl $vector {
+            #[doc = concat!(
+                "Creates a [", stringify!($vector), "][type@", stringify!($vector), "] from the ",
+                "components of `value`, followed by `",
+                stringify!($last), "`."
+            )]
+            ///
+            /// This is equivalent to the GLSL constructor that takes a smaller vector and a scalar,
+            /// e.g. to promote a position to homogeneous coordinates.
+            #[must_use]
+            pub const fn $from(value: $smaller, $last: $component) -> Self {
+                $vector($(value.$c,)* $last)
+            }
+
+            #[doc = concat!(
+                "Returns a [", stringify!($smaller), "][type@", stringify!($smaller), "] with the ",
+                "components of this vector, ",
+                "discarding the last component."
+            )]
+            #[must_use]
+            pub const fn truncate(&self) -> $smaller {
+                $smaller($(self.$c),*)
+            }
+        }
+    };
+}
+
+impl_vector_extend_truncate!(vec3, from_vec2, vec2, f32, z, 0, 1);
+impl_vector_extend_truncate!(vec4, from_vec3, vec3, f32, w, 0, 1, 2);
+impl_vector_extend_truncate!(ivec3, from_ivec2, ivec2, i32, z, 0, 1);
+impl_vector_extend_truncate!(ivec4, from_ivec3, ivec3, i32, w, 0, 1, 2);
+impl_vector_extend_truncate!(uvec3, from_uvec2, uvec2, u32, z, 0, 1);
+impl_vector_extend_truncate!(uvec4, from_uvec3, uvec3, u32, w, 0, 1, 2);
+impl_vector_extend_truncate!(bvec3, from_bvec2, bvec2, boolean, z, 0, 1);
+impl_vector_extend_truncate!(bvec4, from_bvec3, bvec3, boolean, w, 0, 1, 2);
+impl_vector_extend_truncate!(dvec3, from_dvec2, dvec2, f64, z, 0, 1);
+impl_vector_extend_truncate!(dvec4, from_dvec3, dvec3, f64, w, 0, 1, 2);
+
+// Comparing floating point values in a `const fn` requires Rust 1.82, so only the integer vectors
+// get `const` methods.
+macro_rules! impl_vector_min_max {
+    (const $vector:ident, $($c:tt),*) => {
+        impl_vector_min_max!(@impl [const] $vector, $($c),*);
+    };
+    ($vector:ident, $($c:tt),*) => {
+        impl_vector_min_max!(@impl [] $vector, $($c),*);
+    };
+    (@impl [$($constness:tt)*] $vector:ident, $($c:tt),*) => {
+        impl $vector {
+            /// Returns the component-wise minimum of this vector and `other`.
+            ///
+            /// Like the GLSL `min` function, each component is `other`'s component if it is less
+            /// than this vector's component, and this vector's component otherwise.
+            #[must_use]
+            pub $($constness)* fn min(self, other: Self) -> Self {
+                $vector($(if other.$c < self.$c { other.$c } else { self.$c }),*)
+            }
+
+            /// Returns the component-wise maximum of this vector and `other`.
+            ///
+            /// Like the GLSL `max` function, each component is `other`'s component if this
+            /// vector's component is less than it, and this vector's component otherwise.
+            #[must_use]
+            pub $($constness)* fn max(self, other: Self) -> Self {
+                $vector($(if self.$c < other.$c { other.$c } else { self.$c }),*)
+            }
+
+            /// Clamps each component of this vector to the range given by the corresponding
+            /// components of `min` and `max`.
+            ///
+            /// Like the GLSL `clamp` function, this is equivalent to `self.max(min).min(max)`; the
+            /// result is unspecified if a component of `min` is greater than the corresponding
+            /// component of `max`, but this does not panic.
+            #[must_use]
+            pub $($constness)* fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+        }
+    };
 }
+
+impl_vector_min_max!(vec2, 0, 1);
+impl_vector_min_max!(vec3, 0, 1, 2);
+impl_vector_min_max!(vec4, 0, 1, 2, 3);
+impl_vector_min_max!(const ivec2, 0, 1);
+impl_vector_min_max!(const ivec3, 0, 1, 2);
+impl_vector_min_max!(const ivec4, 0, 1, 2, 3);
+impl_vector_min_max!(const uvec2, 0, 1);
+impl_vector_min_max!(const uvec3, 0, 1, 2);
+impl_vector_min_max!(const uvec4, 0, 1, 2, 3);
+impl_vector_min_max!(dvec2, 0, 1);
+impl_vector_min_max!(dvec3, 0, 1, 2);
+impl_vector_min_max!(dvec4, 0, 1, 2, 3);
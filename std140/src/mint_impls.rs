@@ -0,0 +1,75 @@
+//! Conversions between the std140 vector and matrix types and their [mint] counterparts.
+//!
+//! Note that [mint] names matrices by their number of rows first (e.g. `ColumnMatrix2x3` has 2
+//! rows and 3 columns), whereas GLSL names matrices by their number of columns first (e.g. `mat3x2`
+//! has 3 columns and 2 rows).
+
+use crate::*;
+
+macro_rules! impl_mint_vector {
+    ($ty:ident, $mint:ident, $scalar:ty, $($component:tt => $field:ident),*) => {
+        impl From<mint::$mint<$scalar>> for $ty {
+            fn from(value: mint::$mint<$scalar>) -> Self {
+                $ty($(value.$field),*)
+            }
+        }
+
+        impl From<$ty> for mint::$mint<$scalar> {
+            fn from(value: $ty) -> Self {
+                mint::$mint {
+                    $($field: value.$component),*
+                }
+            }
+        }
+    };
+}
+
+impl_mint_vector!(vec2, Vector2, f32, 0 => x, 1 => y);
+impl_mint_vector!(vec3, Vector3, f32, 0 => x, 1 => y, 2 => z);
+impl_mint_vector!(vec4, Vector4, f32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_vector!(ivec2, Vector2, i32, 0 => x, 1 => y);
+impl_mint_vector!(ivec3, Vector3, i32, 0 => x, 1 => y, 2 => z);
+impl_mint_vector!(ivec4, Vector4, i32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_vector!(uvec2, Vector2, u32, 0 => x, 1 => y);
+impl_mint_vector!(uvec3, Vector3, u32, 0 => x, 1 => y, 2 => z);
+impl_mint_vector!(uvec4, Vector4, u32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_vector!(dvec2, Vector2, f64, 0 => x, 1 => y);
+impl_mint_vector!(dvec3, Vector3, f64, 0 => x, 1 => y, 2 => z);
+impl_mint_vector!(dvec4, Vector4, f64, 0 => x, 1 => y, 2 => z, 3 => w);
+
+macro_rules! impl_mint_matrix {
+    ($ty:ident, $mint:ident, $scalar:ty, $($column:tt => $field:ident),*) => {
+        impl From<mint::$mint<$scalar>> for $ty {
+            fn from(value: mint::$mint<$scalar>) -> Self {
+                $ty($(value.$field.into()),*)
+            }
+        }
+
+        impl From<$ty> for mint::$mint<$scalar> {
+            fn from(value: $ty) -> Self {
+                mint::$mint {
+                    $($field: value.columns.internal[$column].element.into()),*
+                }
+            }
+        }
+    };
+}
+
+impl_mint_matrix!(mat2x2, ColumnMatrix2, f32, 0 => x, 1 => y);
+impl_mint_matrix!(mat2x3, ColumnMatrix3x2, f32, 0 => x, 1 => y);
+impl_mint_matrix!(mat2x4, ColumnMatrix4x2, f32, 0 => x, 1 => y);
+impl_mint_matrix!(mat3x2, ColumnMatrix2x3, f32, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(mat3x3, ColumnMatrix3, f32, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(mat3x4, ColumnMatrix4x3, f32, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(mat4x2, ColumnMatrix2x4, f32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_matrix!(mat4x3, ColumnMatrix3x4, f32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_matrix!(mat4x4, ColumnMatrix4, f32, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_matrix!(dmat2x2, ColumnMatrix2, f64, 0 => x, 1 => y);
+impl_mint_matrix!(dmat2x3, ColumnMatrix3x2, f64, 0 => x, 1 => y);
+impl_mint_matrix!(dmat2x4, ColumnMatrix4x2, f64, 0 => x, 1 => y);
+impl_mint_matrix!(dmat3x2, ColumnMatrix2x3, f64, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(dmat3x3, ColumnMatrix3, f64, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(dmat3x4, ColumnMatrix4x3, f64, 0 => x, 1 => y, 2 => z);
+impl_mint_matrix!(dmat4x2, ColumnMatrix2x4, f64, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_matrix!(dmat4x3, ColumnMatrix3x4, f64, 0 => x, 1 => y, 2 => z, 3 => w);
+impl_mint_matrix!(dmat4x4, ColumnMatrix4, f64, 0 => x, 1 => y, 2 => z, 3 => w);
@@ -0,0 +1,232 @@
+//! Arithmetic operators for the scalar types, component-wise arithmetic operators for the vector
+//! types and matrix-vector and matrix-matrix multiplication for the floating point matrix types.
+//!
+//! Operations on the integer scalars and vectors wrap on overflow, matching the behavior of integer
+//! arithmetic in GLSL.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::*;
+
+macro_rules! impl_scalar_op {
+    ($scalar:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $method:ident) => {
+        impl $op for $scalar {
+            type Output = $scalar;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                $scalar(self.0.$method(rhs.0))
+            }
+        }
+
+        impl $assign for $scalar {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $op::$op_fn(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_scalar_ops {
+    ($scalar:ident) => {
+        impl_scalar_op!($scalar, Add, add, AddAssign, add_assign, add);
+        impl_scalar_op!($scalar, Sub, sub, SubAssign, sub_assign, sub);
+        impl_scalar_op!($scalar, Mul, mul, MulAssign, mul_assign, mul);
+        impl_scalar_op!($scalar, Div, div, DivAssign, div_assign, div);
+    };
+}
+
+impl_float_scalar_ops!(float);
+impl_float_scalar_ops!(double);
+
+// Division by zero panics, as it does for the primitive integer types; the result is undefined in
+// GLSL.
+macro_rules! impl_integer_scalar_ops {
+    ($scalar:ident) => {
+        impl_scalar_op!($scalar, Add, add, AddAssign, add_assign, wrapping_add);
+        impl_scalar_op!($scalar, Sub, sub, SubAssign, sub_assign, wrapping_sub);
+        impl_scalar_op!($scalar, Mul, mul, MulAssign, mul_assign, wrapping_mul);
+        impl_scalar_op!($scalar, Div, div, DivAssign, div_assign, wrapping_div);
+    };
+}
+
+impl_integer_scalar_ops!(int);
+impl_integer_scalar_ops!(uint);
+
+macro_rules! impl_float_vector_ops {
+    ($vector:ident, $scalar:ident, $primitive:ty, $($c:tt),*) => {
+        impl Add for $vector {
+            type Output = $vector;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $vector($(self.$c + rhs.$c),*)
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector($(self.$c - rhs.$c),*)
+            }
+        }
+
+        impl Mul<$primitive> for $vector {
+            type Output = $vector;
+
+            fn mul(self, rhs: $primitive) -> Self::Output {
+                $vector($(self.$c * rhs),*)
+            }
+        }
+
+        impl Mul<$scalar> for $vector {
+            type Output = $vector;
+
+            fn mul(self, rhs: $scalar) -> Self::Output {
+                self * rhs.0
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = $vector;
+
+            fn neg(self) -> Self::Output {
+                $vector($(-self.$c),*)
+            }
+        }
+    };
+}
+
+impl_float_vector_ops!(vec2, float, f32, 0, 1);
+impl_float_vector_ops!(vec3, float, f32, 0, 1, 2);
+impl_float_vector_ops!(vec4, float, f32, 0, 1, 2, 3);
+impl_float_vector_ops!(dvec2, double, f64, 0, 1);
+impl_float_vector_ops!(dvec3, double, f64, 0, 1, 2);
+impl_float_vector_ops!(dvec4, double, f64, 0, 1, 2, 3);
+
+macro_rules! impl_integer_vector_ops {
+    ($vector:ident, $scalar:ident, $primitive:ty, $($c:tt),*) => {
+        impl Add for $vector {
+            type Output = $vector;
+
+            /// Adds the vectors component-wise, wrapping around on overflow.
+            fn add(self, rhs: Self) -> Self::Output {
+                $vector($(self.$c.wrapping_add(rhs.$c)),*)
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+
+            /// Subtracts the vectors component-wise, wrapping around on overflow.
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector($(self.$c.wrapping_sub(rhs.$c)),*)
+            }
+        }
+
+        impl Mul<$primitive> for $vector {
+            type Output = $vector;
+
+            /// Multiplies each component by the scalar, wrapping around on overflow.
+            fn mul(self, rhs: $primitive) -> Self::Output {
+                $vector($(self.$c.wrapping_mul(rhs)),*)
+            }
+        }
+
+        impl Mul<$scalar> for $vector {
+            type Output = $vector;
+
+            /// Multiplies each component by the scalar, wrapping around on overflow.
+            fn mul(self, rhs: $scalar) -> Self::Output {
+                self * rhs.0
+            }
+        }
+
+        impl $vector {
+            /// Adds the vectors component-wise, or returns `None` if any of the components
+            /// overflows.
+            #[must_use]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some($vector($(self.$c.checked_add(rhs.$c)?),*))
+            }
+
+            /// Subtracts the vectors component-wise, or returns `None` if any of the components
+            /// overflows.
+            #[must_use]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some($vector($(self.$c.checked_sub(rhs.$c)?),*))
+            }
+
+            /// Multiplies each component by the scalar, or returns `None` if any of the components
+            /// overflows.
+            #[must_use]
+            pub fn checked_mul(self, rhs: $primitive) -> Option<Self> {
+                Some($vector($(self.$c.checked_mul(rhs)?),*))
+            }
+        }
+    };
+}
+
+impl_integer_vector_ops!(ivec2, int, i32, 0, 1);
+impl_integer_vector_ops!(ivec3, int, i32, 0, 1, 2);
+impl_integer_vector_ops!(ivec4, int, i32, 0, 1, 2, 3);
+impl_integer_vector_ops!(uvec2, uint, u32, 0, 1);
+impl_integer_vector_ops!(uvec3, uint, u32, 0, 1, 2);
+impl_integer_vector_ops!(uvec4, uint, u32, 0, 1, 2, 3);
+
+macro_rules! impl_integer_vector_neg {
+    ($vector:ident, $($c:tt),*) => {
+        impl Neg for $vector {
+            type Output = $vector;
+
+            /// Negates each component, wrapping around on overflow (`-i32::MIN` is `i32::MIN`).
+            fn neg(self) -> Self::Output {
+                $vector($(self.$c.wrapping_neg()),*)
+            }
+        }
+    };
+}
+
+impl_integer_vector_neg!(ivec2, 0, 1);
+impl_integer_vector_neg!(ivec3, 0, 1, 2);
+impl_integer_vector_neg!(ivec4, 0, 1, 2, 3);
+
+// Matrices are column-major, so multiplying a matrix with `C` columns by a vector with `C`
+// components produces a linear combination of the matrix's columns, and multiplying by a matrix
+// with `K` columns multiplies each of its columns in turn. As in GLSL, `matCxR * matKxC` produces a
+// `matKxR`.
+macro_rules! impl_matrix_mul {
+    ($column:ident, $columns:literal, $rhs:ident, $($c:tt),*) => {
+        impl Mul<$rhs> for Matrix<$column, $columns> {
+            type Output = $column;
+
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                $column::zero() $(+ self.columns.internal[$c].element * rhs.$c)*
+            }
+        }
+
+        impl<const K: usize> Mul<Matrix<$rhs, K>> for Matrix<$column, $columns> {
+            type Output = Matrix<$column, K>;
+
+            fn mul(self, rhs: Matrix<$rhs, K>) -> Self::Output {
+                Matrix {
+                    columns: array::from_fn(|k| self * rhs.columns.internal[k].element),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix_mul_for_columns {
+    ($column:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_matrix_mul!($column, 2, $vec2, 0, 1);
+        impl_matrix_mul!($column, 3, $vec3, 0, 1, 2);
+        impl_matrix_mul!($column, 4, $vec4, 0, 1, 2, 3);
+    };
+}
+
+impl_matrix_mul_for_columns!(vec2, vec2, vec3, vec4);
+impl_matrix_mul_for_columns!(vec3, vec2, vec3, vec4);
+impl_matrix_mul_for_columns!(vec4, vec2, vec3, vec4);
+impl_matrix_mul_for_columns!(dvec2, dvec2, dvec3, dvec4);
+impl_matrix_mul_for_columns!(dvec3, dvec2, dvec3, dvec4);
+impl_matrix_mul_for_columns!(dvec4, dvec2, dvec3, dvec4);
@@ -0,0 +1,148 @@
+//! Compile-time padding analysis backing `#[repr_std140(warn_padding)]` and the `bytemuck::Pod`
+//! implementation of structs, and the warning for conditionally compiled fields backing
+//! `#[repr_std140(warn_cfg_fields)]`.
+//!
+//! The size of a struct is computed for the declared field order and for an alternative order that
+//! is constructed greedily: at each offset, the remaining field that needs the least padding is
+//! placed next, preferring fields with a larger alignment. The Rust sizes and alignments of the
+//! fields are used (rather than the std140 ones), as the struct can only be reordered into a layout
+//! it can actually represent: a scalar cannot be placed in the last 4 bytes of a `vec3`. As every
+//! Rust size is a multiple of the corresponding alignment, the greedy order never needs more padding
+//! than any other order.
+
+/// The alignments and sizes of the fields of a struct, in declaration order.
+pub struct FieldSizes<const N: usize> {
+    fields: [(usize, usize); N],
+    len: usize,
+}
+
+impl<const N: usize> FieldSizes<N> {
+    pub const fn new() -> Self {
+        FieldSizes {
+            fields: [(0, 0); N],
+            len: 0,
+        }
+    }
+
+    pub const fn push(mut self, alignment: usize, size: usize) -> Self {
+        self.fields[self.len] = (alignment, size);
+        self.len += 1;
+
+        self
+    }
+
+    /// Returns `true` if reordering the fields results in a smaller struct.
+    pub const fn has_excess_padding(&self) -> bool {
+        let declared = self.declared_size();
+        let reordered = self.reordered_size();
+
+        reordered < declared
+    }
+
+    const fn declared_size(&self) -> usize {
+        let mut offset = 0usize;
+        let mut i = 0;
+
+        while i < self.len {
+            let (alignment, size) = self.fields[i];
+
+            offset = offset.next_multiple_of(alignment) + size;
+            i += 1;
+        }
+
+        offset.next_multiple_of(self.struct_alignment())
+    }
+
+    const fn reordered_size(&self) -> usize {
+        let mut placed = [false; N];
+        let mut offset = 0usize;
+        let mut count = 0;
+
+        while count < self.len {
+            let mut best = self.len;
+            let mut best_padding = 0;
+            let mut i = 0;
+
+            while i < self.len {
+                if !placed[i] {
+                    let (alignment, _) = self.fields[i];
+                    let padding = offset.next_multiple_of(alignment) - offset;
+
+                    if best == self.len
+                        || padding < best_padding
+                        || (padding == best_padding && alignment > self.fields[best].0)
+                    {
+                        best = i;
+                        best_padding = padding;
+                    }
+                }
+
+                i += 1;
+            }
+
+            placed[best] = true;
+            offset += best_padding + self.fields[best].1;
+            count += 1;
+        }
+
+        offset.next_multiple_of(self.struct_alignment())
+    }
+
+    const fn struct_alignment(&self) -> usize {
+        let mut alignment = 16;
+        let mut i = 0;
+
+        while i < self.len {
+            if self.fields[i].0 > alignment {
+                alignment = self.fields[i].0;
+            }
+
+            i += 1;
+        }
+
+        alignment
+    }
+}
+
+impl<const N: usize> Default for FieldSizes<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selects a deprecated (and therefore warning) `check` function if `EXCESS` is `true`.
+pub struct PaddingCheck<const EXCESS: bool>;
+
+impl PaddingCheck<false> {
+    pub const fn check() {}
+}
+
+impl PaddingCheck<true> {
+    #[deprecated(
+        note = "the fields of this #[repr_std140(warn_padding)] struct can be reordered to reduce padding (e.g. by ordering the fields by decreasing alignment); this is advisory only, as the field order must match the GLSL declaration"
+    )]
+    pub const fn check() {}
+}
+
+/// Implements [NoPadding] if `NO_PADDING` is `true`, which is the case if the sizes of the fields of
+/// a struct add up to the size of the struct; bounds the `bytemuck::Pod` implementation of a struct.
+pub struct PodCheck<const NO_PADDING: bool>;
+
+#[diagnostic::on_unimplemented(
+    message = "the struct contains padding bytes, so it does not implement `Pod`",
+    label = "contains padding bytes"
+)]
+pub trait NoPadding {}
+
+impl NoPadding for PodCheck<true> {}
+
+/// Provides a deprecated (and therefore warning) `warn` function that is called for each field with
+/// a `#[cfg]` attribute.
+pub struct CfgFieldWarning;
+
+impl CfgFieldWarning {
+    #[deprecated(
+        note = "this field of a #[repr_std140(warn_cfg_fields)] struct is conditionally compiled; including or excluding it changes the std140 layout of the struct, which must match the GLSL declaration in every build configuration"
+    )]
+    pub const fn warn() {}
+}
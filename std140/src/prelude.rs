@@ -0,0 +1,35 @@
+//! Re-exports the types and constructor functions that are commonly used to define and initialize
+//! std140 uniform blocks.
+//!
+//! The marker traits (e.g. [ReprStd140][crate::ReprStd140]) are not included, as they rarely need
+//! to be named in user code.
+//!
+//! # Example
+//!
+//! ```
+//! use std140::prelude::*;
+//!
+//! #[repr_std140]
+//! struct PointLight {
+//!     position: vec3,
+//!     intensity: float,
+//!     colors: array<vec4, 2>,
+//! }
+//!
+//! let light = PointLight {
+//!     position: vec3(0.0, 1.0, 0.0),
+//!     intensity: float(0.5),
+//!     colors: array![vec4(1.0, 0.0, 0.0, 1.0), vec4(0.0, 0.0, 1.0, 1.0)],
+//! };
+//! ```
+
+pub use crate::{array, derive_std140, repr_std140, sized_array};
+pub use crate::{boolean, double, float, int, uint};
+pub use crate::{bvec2, bvec3, bvec4};
+pub use crate::{dmat2x2, dmat2x3, dmat2x4, dmat3x2, dmat3x3, dmat3x4, dmat4x2, dmat4x3, dmat4x4};
+pub use crate::{dvec2, dvec3, dvec4};
+pub use crate::{ivec2, ivec3, ivec4};
+pub use crate::{mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4};
+pub use crate::{uvec2, uvec3, uvec4};
+pub use crate::{vec2, vec3, vec4};
+pub use crate::{AsBytes, AsStd140, FromStd140, RowMajor, Std140Enum};
@@ -0,0 +1,114 @@
+use core::ops::Deref;
+
+use crate::*;
+
+/// Wraps a matrix that was constructed from rows, rather than columns.
+///
+/// Std140 matrices are stored in column-major order. Data from row-major sources may be wrapped in
+/// a [RowMajor] to make its row-major origin explicit in the type, without having to transpose
+/// it manually: the constructor takes the rows of the matrix and dereferences to the corresponding
+/// (column-major) std140 matrix. A [RowMajor] has the same memory layout as the matrix it wraps
+/// and may be used as a field type in a [`#[repr_std140]`][repr_std140] struct.
+///
+/// # Example
+///
+/// ```
+/// use std140::RowMajor;
+///
+/// let value = RowMajor::<std140::mat2x3>::new(
+///     std140::vec2(1.0, 2.0),
+///     std140::vec2(3.0, 4.0),
+///     std140::vec2(5.0, 6.0),
+/// );
+///
+/// assert_eq!(
+///     *value,
+///     std140::mat2x3(
+///         std140::vec3(1.0, 3.0, 5.0),
+///         std140::vec3(2.0, 4.0, 6.0),
+///     )
+/// );
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct RowMajor<M> {
+    matrix: M,
+}
+
+impl<M> RowMajor<M> {
+    /// Returns the wrapped (column-major) matrix.
+    #[must_use]
+    pub fn into_inner(self) -> M {
+        self.matrix
+    }
+}
+
+impl<M> Deref for RowMajor<M> {
+    type Target = M;
+
+    fn deref(&self) -> &Self::Target {
+        &self.matrix
+    }
+}
+
+unsafe impl<M> ReprStd140 for RowMajor<M>
+where
+    M: ReprStd140,
+{
+    // The wrapped matrix is stored in column-major order, so it is declared without a `row_major`
+    // layout qualifier.
+    #[cfg(feature = "std")]
+    fn glsl_field_declaration(name: &str, structs: &mut Vec<String>) -> String {
+        M::glsl_field_declaration(name, structs)
+    }
+
+    fn copy_std140_bytes(&self, dst: &mut [u8]) {
+        self.matrix.copy_std140_bytes(dst);
+    }
+}
+unsafe impl<M> Std140ArrayElement for RowMajor<M> where M: Std140ArrayElement {}
+unsafe impl<M> Std140PaddingFree for RowMajor<M> where M: Std140PaddingFree {}
+
+macro_rules! row_major_column {
+    ($column:ident, $index:tt, ($($row:ident),*)) => {
+        $column($($row.$index),*)
+    };
+}
+
+macro_rules! impl_row_major {
+    ($matrix:ident, $row:ident, $column:ident, $rows:tt, $columns:tt) => {
+        impl_row_major!(@impl $matrix, $row, $column, $rows, $rows, $columns);
+    };
+    (@impl $matrix:ident, $row:ident, $column:ident, ($($r:ident),*), $rows:tt, ($($index:tt),*)) => {
+        impl RowMajor<$matrix> {
+            /// Initializes a row-major matrix from its rows.
+            #[must_use]
+            pub const fn new($($r: $row),*) -> Self {
+                RowMajor {
+                    matrix: $matrix($(row_major_column!($column, $index, $rows)),*),
+                }
+            }
+        }
+    };
+}
+
+impl_row_major!(mat2x2, vec2, vec2, (r0, r1), (0, 1));
+impl_row_major!(mat2x3, vec2, vec3, (r0, r1, r2), (0, 1));
+impl_row_major!(mat2x4, vec2, vec4, (r0, r1, r2, r3), (0, 1));
+impl_row_major!(mat3x2, vec3, vec2, (r0, r1), (0, 1, 2));
+impl_row_major!(mat3x3, vec3, vec3, (r0, r1, r2), (0, 1, 2));
+impl_row_major!(mat3x4, vec3, vec4, (r0, r1, r2, r3), (0, 1, 2));
+impl_row_major!(mat4x2, vec4, vec2, (r0, r1), (0, 1, 2, 3));
+impl_row_major!(mat4x3, vec4, vec3, (r0, r1, r2), (0, 1, 2, 3));
+impl_row_major!(mat4x4, vec4, vec4, (r0, r1, r2, r3), (0, 1, 2, 3));
+impl_row_major!(dmat2x2, dvec2, dvec2, (r0, r1), (0, 1));
+impl_row_major!(dmat2x3, dvec2, dvec3, (r0, r1, r2), (0, 1));
+impl_row_major!(dmat2x4, dvec2, dvec4, (r0, r1, r2, r3), (0, 1));
+impl_row_major!(dmat3x2, dvec3, dvec2, (r0, r1), (0, 1, 2));
+impl_row_major!(dmat3x3, dvec3, dvec3, (r0, r1, r2), (0, 1, 2));
+impl_row_major!(dmat3x4, dvec3, dvec4, (r0, r1, r2, r3), (0, 1, 2));
+impl_row_major!(dmat4x2, dvec4, dvec2, (r0, r1), (0, 1, 2, 3));
+impl_row_major!(dmat4x3, dvec4, dvec3, (r0, r1, r2), (0, 1, 2, 3));
+impl_row_major!(dmat4x4, dvec4, dvec4, (r0, r1, r2, r3), (0, 1, 2, 3));
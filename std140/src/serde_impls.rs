@@ -0,0 +1,148 @@
+//! [serde] implementations for the types that don't derive them.
+//!
+//! The scalar and vector types derive their implementations; matrices serialize transparently as
+//! an [array] of their columns.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::{array, boolean, ArrayElementWrapper, Std140ArrayElement};
+
+/// Serializes as a `bool` for human-readable formats and as a `u32` (its std140 representation)
+/// for compact formats.
+impl Serialize for boolean {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_bool(*self == boolean::True)
+        } else {
+            serializer.serialize_u32(*self as u32)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for boolean {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BooleanVisitor;
+
+        impl<'de> Visitor<'de> for BooleanVisitor {
+            type Value = boolean;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a bool, or an integer that is either `0` or `1`")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(value.into())
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    0 => Ok(boolean::False),
+                    1 => Ok(boolean::True),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_bool(BooleanVisitor)
+        } else {
+            deserializer.deserialize_u32(BooleanVisitor)
+        }
+    }
+}
+
+impl<T, const LEN: usize> Serialize for array<T, { LEN }>
+where
+    T: Std140ArrayElement + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(LEN)?;
+
+        for wrapper in self.internal.iter() {
+            tuple.serialize_element(&wrapper.element)?;
+        }
+
+        tuple.end()
+    }
+}
+
+impl<'de, T, const LEN: usize> Deserialize<'de> for array<T, { LEN }>
+where
+    T: Std140ArrayElement + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<T, const LEN: usize> {
+            marker: PhantomData<T>,
+        }
+
+        impl<'de, T, const LEN: usize> Visitor<'de> for ArrayVisitor<T, { LEN }>
+        where
+            T: Std140ArrayElement + Deserialize<'de>,
+        {
+            type Value = array<T, { LEN }>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array of length {}", LEN)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut elements: [Option<T>; LEN] = core::array::from_fn(|_| None);
+
+                for (i, element) in elements.iter_mut().enumerate() {
+                    match seq.next_element()? {
+                        Some(value) => *element = Some(value),
+                        None => return Err(de::Error::invalid_length(i, &self)),
+                    }
+                }
+
+                let mut excess = 0;
+
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    excess += 1;
+                }
+
+                if excess > 0 {
+                    return Err(de::Error::invalid_length(LEN + excess, &self));
+                }
+
+                let internal = elements.map(|element| ArrayElementWrapper {
+                    element: element.expect("all elements were initialized"),
+                });
+
+                Ok(array::from_wrapped(internal))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            LEN,
+            ArrayVisitor {
+                marker: PhantomData,
+            },
+        )
+    }
+}
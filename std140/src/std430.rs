@@ -0,0 +1,449 @@
+//! Types that may be used to define Rust struct types that match the GLSL std430 memory layout.
+//!
+//! Std430 is a layout convention for GLSL shader storage blocks (SSBOs) that is tighter than
+//! std140: arrays of scalars and 2-component vectors are not padded to 16 bytes per element, and
+//! structs are not rounded up to a multiple of 16 bytes. For details on the layout rules, please
+//! refer to section 7.6.2.2 "Standard Uniform Block Layout" of the
+//! [OpenGL 4.6 Specification](https://registry.khronos.org/OpenGL/specs/gl/glspec46.core.pdf).
+//!
+//! The scalar, vector and matrix types of the crate root are reused, with the exception of the
+//! single precision matrices with 2 rows: std140 pads their columns to 16 bytes, std430 does not.
+//! Use [mat2x2][struct@mat2x2], [mat3x2][struct@mat3x2] and [mat4x2][struct@mat4x2] from this
+//! module instead. Arrays must use this module's [array][struct@array] type and structs must be
+//! marked with [`#[repr_std430]`][repr_std430]. A trailing runtime-sized array (e.g.
+//! `vec4 colors[]`) is described with a final [runtime_array][struct@runtime_array] field.
+//!
+//! # Example
+//!
+//! Given the following GLSL declaration of a shader storage block:
+//!
+//! ```glsl
+//! struct Particle {
+//!     vec2 position;
+//!     vec2 velocity;
+//! }
+//!
+//! layout(std430) buffer Particles {
+//!     float weights[4];
+//!     Particle particles[2];
+//! }
+//! ```
+//!
+//! The following will produce a Rust struct instance with a compatible memory layout:
+//!
+//! ```rust
+//! use std140::std430;
+//!
+//! #[std430::repr_std430]
+//! struct Particle {
+//!     position: std140::vec2,
+//!     velocity: std140::vec2,
+//! }
+//!
+//! #[std430::repr_std430]
+//! struct Particles {
+//!     weights: std430::array<std140::float, 4>,
+//!     particles: std430::array<Particle, 2>,
+//! }
+//!
+//! let instance = Particles {
+//!     weights: std430::array::from([std140::float(0.5); 4]),
+//!     particles: std430::array::from([
+//!         Particle {
+//!             position: std140::vec2(0.0, 0.0),
+//!             velocity: std140::vec2(1.0, 0.0),
+//!         },
+//!         Particle {
+//!             position: std140::vec2(1.0, 1.0),
+//!             velocity: std140::vec2(0.0, 1.0),
+//!         },
+//!     ]),
+//! };
+//!
+//! assert_eq!(std::mem::size_of::<Particles>(), 48);
+//! ```
+//!
+//! [repr_std430]: attr.repr_std430.html
+
+use core::fmt;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+use crate::{
+    boolean, bvec2, bvec3, bvec4, dmat2x2, dmat2x3, dmat2x4, dmat3x2, dmat3x3, dmat3x4, dmat4x2,
+    dmat4x3, dmat4x4, double, dvec2, dvec3, dvec4, float, int, ivec2, ivec3, ivec4, mat2x3, mat2x4,
+    mat3x3, mat3x4, mat4x3, mat4x4, uint, uvec2, uvec3, uvec4, vec2, vec3, vec4,
+};
+
+/// Attribute macro that can be applied to a struct to ensure its representation is compatible with
+/// the std430 memory layout convention.
+///
+/// Can only be applied to a struct if all of its fields implement [ReprStd430].
+///
+/// Any struct marked with this attribute will automatically implement [Std430Struct]
+///
+/// # Example
+///
+/// ```rust
+/// #[std140::std430::repr_std430]
+/// struct Particle {
+///     position: std140::vec2,
+///     velocity: std140::vec2,
+/// }
+/// ```
+///
+/// Like [`#[repr_std140]`][crate::repr_std140], this attribute accepts a `crate = "path"` argument
+/// for when this crate is not available as `std140`. A redundant `#[repr(C)]` on the struct is
+/// accepted, but other `#[repr]` hints (including `align(N)`) would change the std430 layout and
+/// result in a compile error.
+///
+/// With the `bytemuck` feature enabled, the macro also implements `bytemuck::Zeroable` for the
+/// struct, and `bytemuck::Pod` if the struct contains neither padding nor [boolean]s (such as
+/// `Particle` above), under the same conditions as for [`#[repr_std140]`][crate::repr_std140].
+pub use std140_macros::repr_std430;
+
+/// Marker trait for types that can be used as fields in structs marked with
+/// [`#[repr_std430]`][repr_std430].
+///
+/// # Safety
+///
+/// The memory layout of an implementing type must match the layout of a GLSL type according to the
+/// std430 layout rules.
+///
+/// [repr_std430]: attr.repr_std430.html
+pub unsafe trait ReprStd430 {}
+
+/// Marker trait for types that can be used as the element type for std430 [array][struct@array]s.
+///
+/// # Safety
+///
+/// The memory layout of an implementing type must match the layout of a GLSL type that may be used
+/// as an array element type according to the std430 layout rules.
+pub unsafe trait Std430ArrayElement: ReprStd430 {}
+
+/// Marker trait for struct types that were marked with [`#[repr_std430]`][repr_std430].
+///
+/// # Safety
+///
+/// Should not be implemented manually; implemented automatically for structs marked with
+/// [`#[repr_std430]`][repr_std430].
+///
+/// [repr_std430]: attr.repr_std430.html
+pub unsafe trait Std430Struct {}
+
+/// Marker trait for struct types that were marked with [`#[repr_std430]`][repr_std430] and that
+/// end in a [runtime_array][struct@runtime_array].
+///
+/// Unlike a [Std430Struct], such a struct can only describe a complete shader storage block: it
+/// cannot be used as a field of another struct or as the element type of an array.
+///
+/// # Safety
+///
+/// Should not be implemented manually; implemented automatically for structs marked with
+/// [`#[repr_std430]`][repr_std430].
+///
+/// [repr_std430]: attr.repr_std430.html
+pub unsafe trait Std430RuntimeSizedStruct {
+    /// The offset in bytes of the first element of the trailing runtime-sized array.
+    const RUNTIME_ARRAY_OFFSET: usize;
+
+    /// The distance in bytes between the starts of consecutive elements of the trailing
+    /// runtime-sized array.
+    const RUNTIME_ARRAY_STRIDE: usize;
+
+    /// Returns the size in bytes of a buffer that holds this block with `len` elements in its
+    /// runtime-sized array.
+    fn buffer_size(len: usize) -> usize {
+        Self::RUNTIME_ARRAY_OFFSET + len * Self::RUNTIME_ARRAY_STRIDE
+    }
+}
+
+unsafe impl<T> ReprStd430 for T where T: Std430Struct {}
+unsafe impl<T> Std430ArrayElement for T where T: Std430Struct {}
+
+macro_rules! impl_std430 {
+    ($($ty:ident),*) => {
+        $(
+            unsafe impl ReprStd430 for $ty {}
+            unsafe impl Std430ArrayElement for $ty {}
+        )*
+    };
+}
+
+impl_std430!(float, vec2, vec3, vec4);
+impl_std430!(int, ivec2, ivec3, ivec4);
+impl_std430!(uint, uvec2, uvec3, uvec4);
+impl_std430!(boolean, bvec2, bvec3, bvec4);
+impl_std430!(double, dvec2, dvec3, dvec4);
+impl_std430!(mat2x3, mat2x4, mat3x3, mat3x4, mat4x3, mat4x4);
+impl_std430!(dmat2x2, dmat2x3, dmat2x4, dmat3x2, dmat3x3, dmat3x4, dmat4x2, dmat4x3, dmat4x4);
+
+/// Represents an std430 array.
+///
+/// Unlike a std140 [array][struct@crate::array], the elements of a std430 array are not padded to
+/// a multiple of 16 bytes: the array stride is the size of the element type.
+///
+/// # Example
+///
+/// ```
+/// use std140::std430;
+///
+/// let value: std430::array<std140::float, 3> =
+///     std430::array::from([std140::float(0.0), std140::float(0.5), std140::float(1.0)]);
+///
+/// assert_eq!(std::mem::size_of_val(&value), 12);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct array<T, const LEN: usize>
+where
+    T: Std430ArrayElement,
+{
+    internal: [T; LEN],
+}
+
+impl<T, const LEN: usize> array<T, { LEN }>
+where
+    T: Std430ArrayElement,
+{
+    /// Creates a new [array][struct@array] where each element is the result of calling `f` with
+    /// the index of that element.
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        array {
+            internal: core::array::from_fn(f),
+        }
+    }
+}
+
+impl<T, const LEN: usize> From<[T; LEN]> for array<T, { LEN }>
+where
+    T: Std430ArrayElement,
+{
+    fn from(internal: [T; LEN]) -> Self {
+        array { internal }
+    }
+}
+
+impl<T, const LEN: usize> Deref for array<T, { LEN }>
+where
+    T: Std430ArrayElement,
+{
+    type Target = [T; LEN];
+
+    fn deref(&self) -> &Self::Target {
+        &self.internal
+    }
+}
+
+impl<T, const LEN: usize> DerefMut for array<T, { LEN }>
+where
+    T: Std430ArrayElement,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.internal
+    }
+}
+
+impl<T, const LEN: usize> Default for array<T, { LEN }>
+where
+    T: Std430ArrayElement + Default + Copy,
+{
+    fn default() -> Self {
+        array {
+            internal: [T::default(); LEN],
+        }
+    }
+}
+
+impl<T, const LEN: usize> fmt::Debug for array<T, { LEN }>
+where
+    T: Std430ArrayElement + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.internal.iter()).finish()
+    }
+}
+
+unsafe impl<T, const LEN: usize> ReprStd430 for array<T, { LEN }> where T: Std430ArrayElement {}
+unsafe impl<T, const LEN: usize> Std430ArrayElement for array<T, { LEN }> where T: Std430ArrayElement
+{}
+
+/// Marks the trailing runtime-sized array of a shader storage block.
+///
+/// A GLSL shader storage block may end in an array without a size, the length of which is
+/// determined by the size of the buffer that is bound to the block. This zero-sized type describes
+/// the position and element type of such an array in a struct marked with
+/// [`#[repr_std430]`][repr_std430]. It must be the last field of the struct; the elements
+/// themselves are not part of the struct and are written directly after it, at
+/// [RUNTIME_ARRAY_OFFSET][Std430RuntimeSizedStruct::RUNTIME_ARRAY_OFFSET].
+///
+/// Runtime-sized arrays cannot be used in std140 uniform blocks.
+///
+/// # Example
+///
+/// Given the following GLSL declaration of a shader storage block:
+///
+/// ```glsl
+/// layout(std430) buffer Lights {
+///     uint count;
+///     vec4 colors[];
+/// }
+/// ```
+///
+/// The following describes its layout:
+///
+/// ```
+/// use std140::std430::{self, Std430RuntimeSizedStruct};
+///
+/// #[std430::repr_std430]
+/// struct Lights {
+///     count: std140::uint,
+///     colors: std430::runtime_array<std140::vec4>,
+/// }
+///
+/// assert_eq!(Lights::RUNTIME_ARRAY_OFFSET, 16);
+/// assert_eq!(Lights::buffer_size(3), 64);
+/// ```
+///
+/// [repr_std430]: attr.repr_std430.html
+#[repr(C)]
+pub struct runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    elements: [T; 0],
+}
+
+impl<T> runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    /// The distance in bytes between the starts of consecutive elements.
+    pub const STRIDE: usize = mem::size_of::<T>();
+
+    /// Creates a new runtime-sized array marker.
+    #[must_use]
+    pub const fn new() -> Self {
+        runtime_array { elements: [] }
+    }
+}
+
+impl<T> Clone for runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    fn clone(&self) -> Self {
+        runtime_array::new()
+    }
+}
+
+impl<T> Copy for runtime_array<T> where T: Std430ArrayElement + Copy {}
+
+impl<T> Default for runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    fn default() -> Self {
+        runtime_array::new()
+    }
+}
+
+impl<T> PartialEq for runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> fmt::Debug for runtime_array<T>
+where
+    T: Std430ArrayElement,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("runtime_array")
+    }
+}
+
+macro_rules! std430_matrix {
+    ($ty:ident, $columns:literal, $($c:ident),*) => {
+        #[doc = concat!(
+            "A matrix with ", $columns, " columns and 2 rows, represented by ", $columns,
+            " [vec2] vectors.\n\n",
+            "Unlike the std140 [", stringify!($ty), "][type@crate::", stringify!($ty), "], the ",
+            "columns of this matrix are not padded to 16 bytes."
+        )]
+        #[derive(Clone, Copy, PartialEq, Default)]
+        pub struct $ty {
+            columns: array<vec2, $columns>,
+        }
+
+        impl $ty {
+            #[doc = concat!("Creates a new [", stringify!($ty), "] with zeros in all positions.")]
+            #[must_use]
+            pub const fn zero() -> Self {
+                $ty {
+                    columns: array {
+                        internal: [vec2::zero(); $columns],
+                    },
+                }
+            }
+
+            #[doc = concat!("Creates a new [", stringify!($ty), "] from an array of its columns.")]
+            #[must_use]
+            pub const fn from_columns(columns: [vec2; $columns]) -> Self {
+                $ty {
+                    columns: array { internal: columns },
+                }
+            }
+
+            /// Returns the columns of this matrix as a plain Rust array.
+            #[must_use]
+            pub const fn into_columns(self) -> [vec2; $columns] {
+                self.columns.internal
+            }
+        }
+
+        #[doc = concat!("Initializes a [", stringify!($ty), "][struct@", stringify!($ty), "]")]
+        #[must_use]
+        pub const fn $ty($($c: vec2),*) -> $ty {
+            $ty {
+                columns: array {
+                    internal: [$($c),*],
+                },
+            }
+        }
+
+        unsafe impl ReprStd430 for $ty {}
+        unsafe impl Std430ArrayElement for $ty {}
+
+        impl Deref for $ty {
+            type Target = array<vec2, $columns>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.columns
+            }
+        }
+
+        impl DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.columns
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_fmt(format_args!(concat!(stringify!($ty), "{:?}"), &self.columns))
+            }
+        }
+    };
+}
+
+std430_matrix!(mat2x2, 2, c0, c1);
+std430_matrix!(mat3x2, 3, c0, c1, c2);
+std430_matrix!(mat4x2, 4, c0, c1, c2, c3);
@@ -0,0 +1,55 @@
+//! GLSL-style read swizzles for the floating point vector types.
+//!
+//! Only the swizzles that extract components in their original order are provided (e.g. `xz`, but
+//! not `zx` or `xx`).
+
+use crate::*;
+
+macro_rules! impl_swizzles {
+    ($vector:ident, $($swizzle:ident => $output:ident($($c:tt),*)),* $(,)?) => {
+        impl $vector {
+            $(
+                #[doc = concat!(
+                    "Returns the `", stringify!($swizzle), "` components of this vector as a [",
+                    stringify!($output), "]."
+                )]
+                #[must_use]
+                pub fn $swizzle(&self) -> $output {
+                    $output($(self.$c),*)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! impl_float_swizzles {
+    ($vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_swizzles!($vec2, xy => $vec2(0, 1));
+
+        impl_swizzles!(
+            $vec3,
+            xy => $vec2(0, 1),
+            xz => $vec2(0, 2),
+            yz => $vec2(1, 2),
+            xyz => $vec3(0, 1, 2),
+        );
+
+        impl_swizzles!(
+            $vec4,
+            xy => $vec2(0, 1),
+            xz => $vec2(0, 2),
+            xw => $vec2(0, 3),
+            yz => $vec2(1, 2),
+            yw => $vec2(1, 3),
+            zw => $vec2(2, 3),
+            xyz => $vec3(0, 1, 2),
+            xyw => $vec3(0, 1, 3),
+            xzw => $vec3(0, 2, 3),
+            yzw => $vec3(1, 2, 3),
+            xyzw => $vec4(0, 1, 2, 3),
+        );
+    };
+}
+
+impl_float_swizzles!(vec2, vec3, vec4);
+impl_float_swizzles!(dvec2, dvec3, dvec4);
@@ -0,0 +1,228 @@
+use core::mem;
+
+use crate::{
+    boolean, double, element_stride, float, int, mat2x2, mat3x3, mat4x4, uint, vec2, vec3, vec4,
+    ArrayElementWrapper, ReprStd140, Std140ArrayElement,
+};
+
+/// Writes values into a byte buffer according to the std140 layout rules, for blocks of which the
+/// layout is only known at runtime (e.g. when it is obtained through shader reflection).
+///
+/// Each write first advances the writer's offset to the next multiple of the base alignment of the
+/// value and then writes the value at that offset, zeroing any padding that was skipped. Values are
+/// written in the order in which their members are declared in the GLSL block.
+///
+/// For a nested struct, call [align_to][Std140Writer::align_to] with the base alignment of the
+/// struct (16, or 32 if it contains a `dvec3` or `dvec4`) before writing its first member, and
+/// again after writing its last member.
+///
+/// # Example
+///
+/// Given the following GLSL declaration of an uniform block:
+///
+/// ```glsl
+/// layout(std140) uniform Uniforms {
+///     vec3 position;
+///     float intensity;
+///     float weights[2];
+///     mat4 transform;
+/// }
+/// ```
+///
+/// The following will write a compatible buffer:
+///
+/// ```
+/// use std140::Std140Writer;
+///
+/// let mut buffer = [0u8; 128];
+/// let mut writer = Std140Writer::new(&mut buffer);
+///
+/// writer.write_vec3([1.0, 0.0, 0.0]);
+/// writer.write_float(0.5);
+/// writer.write_array(&[std140::float(0.25), std140::float(0.75)]);
+/// writer.write_mat4([
+///     [1.0, 0.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [0.0, 0.0, 1.0, 0.0],
+///     [0.0, 0.0, 0.0, 1.0],
+/// ]);
+///
+/// assert_eq!(writer.finish(), 112);
+/// assert_eq!(&buffer[12..16], &0.5f32.to_ne_bytes());
+/// assert_eq!(&buffer[16..20], &0.25f32.to_ne_bytes());
+/// assert_eq!(&buffer[32..36], &0.75f32.to_ne_bytes());
+/// ```
+pub struct Std140Writer<'a> {
+    buffer: &'a mut [u8],
+    offset: usize,
+}
+
+impl<'a> Std140Writer<'a> {
+    /// Creates a new writer that starts writing at the start of the `buffer`.
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Std140Writer { buffer, offset: 0 }
+    }
+
+    /// Returns the offset at which the writer will consider placing the next value.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Advances the offset to the next multiple of `alignment`, zeroing the skipped bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is zero or if the buffer is too small.
+    pub fn align_to(&mut self, alignment: usize) {
+        let offset = self.offset.next_multiple_of(alignment);
+
+        self.fill_zeros(offset);
+    }
+
+    /// Writes the `value` at the next offset that is a multiple of its std140 base alignment and
+    /// returns that offset.
+    ///
+    /// Only [STD140_SIZE][ReprStd140::STD140_SIZE] bytes are written, so that a scalar may be
+    /// placed in the 4 bytes after a `vec3`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is too small.
+    pub fn write<T>(&mut self, value: &T) -> usize
+    where
+        T: ReprStd140,
+    {
+        self.align_to(T::STD140_ALIGNMENT);
+
+        let offset = self.offset;
+
+        self.copy_value(value);
+
+        offset
+    }
+
+    /// Writes the `elements` as an std140 array and returns the offset of the first element.
+    ///
+    /// The array is aligned to at least 16 bytes and each element is padded to a multiple of 16
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is too small.
+    pub fn write_array<T>(&mut self, elements: &[T]) -> usize
+    where
+        T: Std140ArrayElement,
+    {
+        let stride = element_stride::<T>();
+
+        self.align_to(mem::align_of::<ArrayElementWrapper<T>>());
+
+        let offset = self.offset;
+
+        for element in elements {
+            let end = self.offset + stride;
+
+            self.copy_value(element);
+            self.fill_zeros(end);
+        }
+
+        offset
+    }
+
+    /// Writes an `f32` as a GLSL `float` and returns its offset.
+    pub fn write_float(&mut self, value: f32) -> usize {
+        self.write(&float(value))
+    }
+
+    /// Writes an `i32` as a GLSL `int` and returns its offset.
+    pub fn write_int(&mut self, value: i32) -> usize {
+        self.write(&int(value))
+    }
+
+    /// Writes an `u32` as a GLSL `uint` and returns its offset.
+    pub fn write_uint(&mut self, value: u32) -> usize {
+        self.write(&uint(value))
+    }
+
+    /// Writes a `bool` as a GLSL `bool` and returns its offset.
+    pub fn write_bool(&mut self, value: bool) -> usize {
+        self.write(&boolean::from(value))
+    }
+
+    /// Writes an `f64` as a GLSL `double` and returns its offset.
+    pub fn write_double(&mut self, value: f64) -> usize {
+        self.write(&double(value))
+    }
+
+    /// Writes the components of a GLSL `vec2` and returns its offset.
+    pub fn write_vec2(&mut self, value: [f32; 2]) -> usize {
+        self.write(&vec2(value[0], value[1]))
+    }
+
+    /// Writes the components of a GLSL `vec3` and returns its offset.
+    pub fn write_vec3(&mut self, value: [f32; 3]) -> usize {
+        self.write(&vec3(value[0], value[1], value[2]))
+    }
+
+    /// Writes the components of a GLSL `vec4` and returns its offset.
+    pub fn write_vec4(&mut self, value: [f32; 4]) -> usize {
+        self.write(&vec4(value[0], value[1], value[2], value[3]))
+    }
+
+    /// Writes the columns of a GLSL `mat2` and returns its offset.
+    pub fn write_mat2(&mut self, columns: [[f32; 2]; 2]) -> usize {
+        self.write(&mat2x2::from(columns))
+    }
+
+    /// Writes the columns of a GLSL `mat3` and returns its offset.
+    pub fn write_mat3(&mut self, columns: [[f32; 3]; 3]) -> usize {
+        self.write(&mat3x3::from(columns))
+    }
+
+    /// Writes the columns of a GLSL `mat4` and returns its offset.
+    pub fn write_mat4(&mut self, columns: [[f32; 4]; 4]) -> usize {
+        self.write(&mat4x4::from(columns))
+    }
+
+    /// Rounds the offset up to a multiple of 16 (zeroing the skipped bytes) and returns the
+    /// resulting size of the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is too small.
+    pub fn finish(mut self) -> usize {
+        self.align_to(16);
+
+        self.offset
+    }
+
+    fn fill_zeros(&mut self, end: usize) {
+        self.buffer_range(end).fill(0);
+        self.offset = end;
+    }
+
+    /// Copies the `STD140_SIZE` bytes of `value` to the current offset, with zeros in place of any
+    /// padding.
+    fn copy_value<T>(&mut self, value: &T)
+    where
+        T: ReprStd140,
+    {
+        let end = self.offset + T::STD140_SIZE;
+
+        value.copy_std140_bytes(self.buffer_range(end));
+        self.offset = end;
+    }
+
+    fn buffer_range(&mut self, end: usize) -> &mut [u8] {
+        let len = self.buffer.len();
+
+        assert!(
+            end <= len,
+            "std140 writer buffer too small: needs at least {} bytes, but has {}",
+            end,
+            len
+        );
+
+        &mut self.buffer[self.offset..end]
+    }
+}
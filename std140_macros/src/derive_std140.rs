@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Index, Member, Meta, NestedMeta};
+
+use crate::repr_std140::{cfg_attrs, parse_crate_path};
+
+pub fn expand_derive_std140(
+    args: &[NestedMeta],
+    input: &DeriveInput,
+) -> Result<TokenStream, String> {
+    let data = if let Data::Struct(data) = &input.data {
+        data
+    } else {
+        return Err("#[derive_std140] can only be applied to a struct.".to_string());
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err("#[derive_std140] does not support generic structs.".to_string());
+    }
+
+    let mut crate_arg = None;
+
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("crate") => {
+                crate_arg = Some((parse_crate_path(name_value)?, name_value));
+            }
+            _ => return Err("Unknown argument for #[derive_std140]".to_string()),
+        }
+    }
+
+    let mod_path = match &crate_arg {
+        Some((path, _)) => path.clone(),
+        None => quote!(std140),
+    };
+    let repr_args = match &crate_arg {
+        Some((_, name_value)) => quote!((#name_value)),
+        None => quote!(),
+    };
+    let vis = &input.vis;
+    let struct_name = &input.ident;
+    let std140_name = format_ident!("Std140{}", struct_name);
+
+    let members: Vec<Member> = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect();
+
+    let as_std140_fields = data.fields.iter().zip(&members).map(|(field, member)| {
+        let cfgs = cfg_attrs(field);
+
+        quote_spanned! {field.ty.span()=>
+            #(#cfgs)* #member: #mod_path::AsStd140::as_std140(&self.#member)
+        }
+    });
+
+    let from_std140_fields = data.fields.iter().zip(&members).map(|(field, member)| {
+        let cfgs = cfg_attrs(field);
+
+        quote_spanned! {field.ty.span()=>
+            #(#cfgs)* #member: #mod_path::FromStd140::from_std140(value.#member)
+        }
+    });
+
+    let std140_fields = data.fields.iter().map(|field| {
+        let vis = &field.vis;
+        let ty = &field.ty;
+        let std140_ty = quote_spanned!(ty.span()=> <#ty as #mod_path::AsStd140>::Output);
+        let cfgs = cfg_attrs(field);
+
+        match &field.ident {
+            Some(ident) => quote!(#(#cfgs)* #vis #ident: #std140_ty),
+            None => quote!(#(#cfgs)* #vis #std140_ty),
+        }
+    });
+
+    let std140_struct = match &data.fields {
+        Fields::Named(_) => quote! {
+            #vis struct #std140_name {
+                #(#std140_fields,)*
+            }
+        },
+        Fields::Unnamed(_) => quote! {
+            #vis struct #std140_name(#(#std140_fields,)*);
+        },
+        Fields::Unit => {
+            return Err("#[derive_std140] cannot be applied to a unit struct.".to_string());
+        }
+    };
+
+    let doc = format!(
+        "The std140 counterpart of [{}], generated by `#[derive_std140]`.",
+        struct_name
+    );
+
+    Ok(quote! {
+        #input
+
+        #[doc = #doc]
+        #[#mod_path::repr_std140 #repr_args]
+        #[derive(Clone, Copy)]
+        #std140_struct
+
+        #[automatically_derived]
+        impl #mod_path::AsStd140 for #struct_name {
+            type Output = #std140_name;
+
+            fn as_std140(&self) -> Self::Output {
+                #std140_name {
+                    #(#as_std140_fields,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #mod_path::FromStd140 for #struct_name {
+            fn from_std140(value: Self::Output) -> Self {
+                #struct_name {
+                    #(#from_std140_fields,)*
+                }
+            }
+        }
+    })
+}
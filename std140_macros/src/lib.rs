@@ -1,19 +1,49 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use proc_macro2;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, AttributeArgs, DeriveInput};
 
+mod derive_std140;
 mod repr_std140;
+mod repr_std430;
+mod std140_enum;
 
 #[proc_macro_attribute]
 pub fn repr_std140(args: TokenStream, input: TokenStream) -> TokenStream {
-    assert!(args.is_empty(), "#[repr_std140] does not take arguments.");
+    let args = parse_macro_input!(args as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+
+    repr_std140::expand_repr_std140(&args, &input)
+        .unwrap_or_else(compile_error)
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn derive_std140(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_std140::expand_derive_std140(&args, &input)
+        .unwrap_or_else(compile_error)
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn repr_std430(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+
+    repr_std430::expand_repr_std430(&args, &input)
+        .unwrap_or_else(compile_error)
+        .into()
+}
 
+#[proc_macro_derive(Std140Enum, attributes(std140))]
+pub fn derive_std140_enum(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    repr_std140::expand_repr_std140(&input)
+    std140_enum::expand_std140_enum(&input)
         .unwrap_or_else(compile_error)
         .into()
 }
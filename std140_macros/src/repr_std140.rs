@@ -1,60 +1,450 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Ident};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Ident, Index, Lit, Member, Meta, MetaNameValue,
+    NestedMeta,
+};
 
-pub fn expand_repr_std140(input: &DeriveInput) -> Result<TokenStream, String> {
+use crate::repr_std430::is_runtime_array;
+
+#[derive(Default)]
+struct ReprStd140Args {
+    assert_layout: bool,
+    warn_padding: bool,
+    warn_cfg_fields: bool,
+    crate_path: Option<TokenStream>,
+    set: Option<u32>,
+    binding: Option<u32>,
+}
+
+impl ReprStd140Args {
+    fn parse(args: &[NestedMeta]) -> Result<Self, String> {
+        let mut parsed = ReprStd140Args::default();
+
+        for arg in args {
+            match arg {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("assert_layout") => {
+                    parsed.assert_layout = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("warn_padding") => {
+                    parsed.warn_padding = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("warn_cfg_fields") => {
+                    parsed.warn_cfg_fields = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("crate") =>
+                {
+                    parsed.crate_path = Some(parse_crate_path(name_value)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("set") =>
+                {
+                    parsed.set = Some(parse_index(name_value, "set")?);
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("binding") =>
+                {
+                    parsed.binding = Some(parse_index(name_value, "binding")?);
+                }
+                _ => return Err("Unknown argument for #[repr_std140]".to_string()),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+pub fn expand_repr_std140(args: &[NestedMeta], input: &DeriveInput) -> Result<TokenStream, String> {
     if let Data::Struct(data) = &input.data {
-        if has_other_repr(input) {
+        check_repr(input, "repr_std140", Some(16))?;
+
+        if data.fields.is_empty() {
             return Err(
-                "Cannot parse another #[repr] attribute on a struct marked with #[repr_std140]"
+                "Cannot represent a struct without fields as std140; GLSL does not allow empty structs or blocks"
                     .to_string(),
             );
         }
 
-        let mod_path = quote!(std140);
+        if data.fields.iter().any(|field| is_runtime_array(&field.ty)) {
+            return Err(
+                "A `runtime_array` cannot be used in a std140 block; runtime-sized arrays are only supported by #[repr_std430]"
+                    .to_string(),
+            );
+        }
+
+        let args = ReprStd140Args::parse(args)?;
+
+        let mod_path = args.crate_path.unwrap_or_else(|| quote!(std140));
         let struct_name = &input.ident;
         let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-        let asserts = data.fields.iter().map(|field| {
+        let asserts = data.fields.iter().enumerate().map(|(i, field)| {
             let ty = &field.ty;
             let span = field.span();
+            let (_, name) = member_and_name(field, i);
+            let assert_trait = Ident::new(&format!("AssertReprStd140Field{}", i), span);
+            let message = format!(
+                "field `{}` of `{}` has type `{{Self}}`, which is not a std140-compatible type",
+                name, struct_name
+            );
+            let cfgs = cfg_attrs(field);
 
-            quote_spanned!(span=> assert_repr_std140::<#ty> { marker: std::marker::PhantomData };)
-        });
+            quote_spanned! {span=>
+                #(#cfgs)*
+                {
+                    #[diagnostic::on_unimplemented(
+                        message = #message,
+                        label = "not a std140-compatible type",
+                        note = "a field type must be a std140 scalar, vector or matrix, a `std140::array`, or another struct marked with `#[repr_std140]`"
+                    )]
+                    trait #assert_trait {}
 
-        let suffix = struct_name.to_string().trim_start_matches("r#").to_owned();
-        let dummy_const = Ident::new(
-            &format!("_IMPL_REPR_STD140_FOR_{}", suffix),
-            Span::call_site(),
-        );
+                    impl<T> #assert_trait for T where T: #mod_path::ReprStd140 {}
 
-        let asserts = quote! {
-            struct assert_repr_std140<T> where T: #mod_path::ReprStd140 {
-                marker: std::marker::PhantomData<T>
+                    fn assert_repr_std140<T: #assert_trait>() {}
+
+                    assert_repr_std140::<#ty>();
+                }
             }
+        });
 
-            #(#asserts)*
+        let asserts = quote! {
+            #[allow(dead_code)]
+            fn assert_fields #impl_generics () #where_clause {
+                #(#asserts)*
+            }
         };
 
+        let collect_field_paths = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (member, name) = member_and_name(field, i);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                <#ty as #mod_path::ReprStd140>::collect_field_paths(
+                    &#mod_path::join_field_path(prefix, #name),
+                    offset + core::mem::offset_of!(Self, #member),
+                    paths,
+                );
+            }
+        });
+
+        let field_layouts = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (member, name) = member_and_name(field, i);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                #mod_path::FieldLayout {
+                    name: #name,
+                    offset: core::mem::offset_of!(Self, #member),
+                    size: <#ty as #mod_path::ReprStd140>::STD140_SIZE,
+                    align: <#ty as #mod_path::ReprStd140>::STD140_ALIGNMENT,
+                }
+            }
+        });
+
+        let field_names = data.fields.iter().enumerate().map(|(i, field)| {
+            let (_, name) = member_and_name(field, i);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                #name
+            }
+        });
+
+        let mut description_capacity = 0;
+        let describe_fields = data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let ty = &field.ty;
+                let (member, name) = member_and_name(field, i);
+                let ty_name = type_name(ty);
+                let cfgs = cfg_attrs(field);
+
+                // The name, the type and 2 numbers of at most 20 digits, plus the punctuation.
+                description_capacity += name.len() + ty_name.len() + 2 * 20 + 32;
+
+                quote! {
+                    #(#cfgs)*
+                    let description = description.push_field(
+                        #name,
+                        #ty_name,
+                        core::mem::offset_of!(Self, #member),
+                        core::mem::size_of::<#ty>(),
+                    );
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let copy_fields = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (member, _) = member_and_name(field, i);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                <#ty as #mod_path::ReprStd140>::copy_std140_bytes(
+                    &self.#member,
+                    &mut dst[core::mem::offset_of!(Self, #member)..],
+                );
+            }
+        });
+
+        let glsl_members = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (_, name) = member_and_name(field, i);
+            // GLSL identifiers cannot start with a digit, so tuple struct fields are prefixed.
+            let glsl_name = match &field.ident {
+                Some(_) => name,
+                None => format!("_{}", name),
+            };
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                members.push(<#ty as #mod_path::ReprStd140>::glsl_field_declaration(#glsl_name, structs));
+            }
+        });
+
+        let glsl_struct_name = struct_name.to_string().trim_start_matches("r#").to_owned();
+
+        let check_bit_patterns = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (member, _) = member_and_name(field, i);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                if !<#ty as #mod_path::ReprStd140>::is_valid_bit_pattern(
+                    &bytes[core::mem::offset_of!(Self, #member)..]
+                ) {
+                    return false;
+                }
+            }
+        });
+
         let impl_std140_struct = quote! {
             #[automatically_derived]
-            unsafe impl #impl_generics #mod_path::Std140Struct for #struct_name #ty_generics #where_clause {}
+            unsafe impl #impl_generics #mod_path::Std140Struct for #struct_name #ty_generics #where_clause {
+                const FIELD_LAYOUTS: &'static [#mod_path::FieldLayout] = &[#(#field_layouts),*];
+
+                const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+
+                const LAYOUT_DESCRIPTION_BYTES: &'static [u8] = &{
+                    let description = #mod_path::__private::LayoutDescription::<#description_capacity>::new();
+
+                    #(#describe_fields)*
+
+                    description.into_bytes()
+                };
+
+                #mod_path::__if_std! {
+                    fn collect_struct_field_paths(
+                        prefix: &str,
+                        offset: usize,
+                        paths: &mut #mod_path::__private::Vec<(#mod_path::__private::String, usize, usize)>,
+                    ) {
+                        #(#collect_field_paths)*
+                    }
+                }
+
+                fn is_valid_struct_bit_pattern(bytes: &[u8]) -> bool {
+                    #(#check_bit_patterns)*
+
+                    true
+                }
+
+                fn copy_struct_bytes(&self, dst: &mut [u8]) {
+                    let dst = &mut dst[..core::mem::size_of::<Self>()];
+
+                    // Zero the padding; the fields overwrite their own bytes below.
+                    dst.fill(0);
+
+                    #(#copy_fields)*
+                }
+
+                const GLSL_STRUCT_NAME: &'static str = #glsl_struct_name;
+
+                #mod_path::__if_std! {
+                    fn glsl_struct_members(
+                        structs: &mut #mod_path::__private::Vec<#mod_path::__private::String>,
+                    ) -> #mod_path::__private::Vec<#mod_path::__private::String> {
+                        let mut members = #mod_path::__private::Vec::new();
+
+                        #(#glsl_members)*
+
+                        members
+                    }
+                }
+            }
+        };
+
+        let impl_bytemuck = impl_bytemuck(
+            &mod_path,
+            &quote!(#mod_path::ReprStd140),
+            input,
+            &data.fields,
+        );
+
+        let layout_asserts = if args.assert_layout {
+            if !input.generics.params.is_empty() {
+                return Err(
+                    "#[repr_std140(assert_layout)] is not supported on generic structs".to_string(),
+                );
+            }
+
+            let field_asserts = data.fields.iter().enumerate().map(|(i, field)| {
+                let ty = &field.ty;
+                let span = field.span();
+                let (member, name) = member_and_name(field, i);
+                let message = format!(
+                    "field `{}` of `{}` does not start at the offset required by std140",
+                    name, struct_name
+                );
+                let cfgs = cfg_attrs(field);
+
+                quote_spanned! {span=>
+                    #(#cfgs)*
+                    {
+                        offset = offset.next_multiple_of(<#ty as #mod_path::ReprStd140>::STD140_ALIGNMENT);
+
+                        assert!(offset == core::mem::offset_of!(#struct_name, #member), #message);
+
+                        offset += <#ty as #mod_path::ReprStd140>::STD140_SIZE;
+                    }
+                }
+            });
+
+            let size_message = format!(
+                "the size of `{}` does not match the size required by std140",
+                struct_name
+            );
+
+            quote! {
+                const _: () = {
+                    let mut offset = 0usize;
+
+                    #(#field_asserts)*
+
+                    let size = offset.next_multiple_of(core::mem::align_of::<#struct_name>());
+
+                    assert!(core::mem::size_of::<#struct_name>() == size, #size_message);
+                };
+            }
+        } else {
+            quote!()
+        };
+
+        let padding_check = if args.warn_padding {
+            if !input.generics.params.is_empty() {
+                return Err(
+                    "#[repr_std140(warn_padding)] is not supported on generic structs".to_string(),
+                );
+            }
+
+            let field_count = data.fields.len();
+            let push_fields = data.fields.iter().map(|field| {
+                let ty = &field.ty;
+                let cfgs = cfg_attrs(field);
+
+                quote! {
+                    #(#cfgs)*
+                    let sizes = sizes.push(
+                        core::mem::align_of::<#ty>(),
+                        core::mem::size_of::<#ty>(),
+                    );
+                }
+            });
+
+            quote_spanned! {struct_name.span()=>
+                const _: () = #mod_path::__private::PaddingCheck::<{
+                    let sizes = #mod_path::__private::FieldSizes::<#field_count>::new();
+
+                    #(#push_fields)*
+
+                    sizes.has_excess_padding()
+                }>::check();
+            }
+        } else {
+            quote!()
+        };
+
+        let cfg_field_warnings = if args.warn_cfg_fields {
+            let warnings = data
+                .fields
+                .iter()
+                .filter(|field| !cfg_attrs(field).is_empty())
+                .map(|field| {
+                    quote_spanned! {field.span()=>
+                        const _: () = #mod_path::__private::CfgFieldWarning::warn();
+                    }
+                });
+
+            quote!(#(#warnings)*)
+        } else {
+            quote!()
         };
 
+        let set = args.set.map(|set| {
+            quote! {
+                /// The descriptor set index of the uniform block, as specified with
+                /// `#[repr_std140(set = ...)]`.
+                pub const SET: u32 = #set;
+            }
+        });
+        let binding = args.binding.map(|binding| {
+            quote! {
+                /// The binding index of the uniform block, as specified with
+                /// `#[repr_std140(binding = ...)]`.
+                pub const BINDING: u32 = #binding;
+            }
+        });
+
+        let binding_consts = if set.is_some() || binding.is_some() {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #struct_name #ty_generics #where_clause {
+                    #set
+                    #binding
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let stripped_input = without_repr(input);
+
         let generated = quote! {
             #[repr(C, align(16))]
-            #input
+            #stripped_input
 
-            #[allow(non_upper_case_globals, unused_attributes, unused_qualifications)]
-            const #dummy_const: () = {
+            #[allow(non_upper_case_globals, unused_attributes, unused_qualifications, clippy::no_effect, clippy::multiple_bound_locations)]
+            const _: () = {
                 #[allow(unknown_lints)]
-                #[cfg_attr(feature = "cargo-clippy", allow(useless_attribute))]
+                #[allow(clippy::useless_attribute)]
                 #[allow(rust_2018_idioms)]
 
                 #asserts
 
                 #impl_std140_struct
+
+                #impl_bytemuck
+
+                #layout_asserts
+
+                #padding_check
+
+                #cfg_field_warnings
+
+                #binding_consts
             };
         };
 
@@ -64,9 +454,252 @@ pub fn expand_repr_std140(input: &DeriveInput) -> Result<TokenStream, String> {
     }
 }
 
-fn has_other_repr(input: &DeriveInput) -> bool {
+/// Checks that the `#[repr]` attributes on a struct marked with `macro_name` only contain hints that
+/// are compatible with the representation the macro applies itself: `C`, and `align(N)` where `N`
+/// does not exceed `max_align` (alignment hints are rejected entirely if `max_align` is `None`).
+pub(crate) fn check_repr(
+    input: &DeriveInput,
+    macro_name: &str,
+    max_align: Option<u64>,
+) -> Result<(), String> {
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let hints = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested,
+            _ => {
+                return Err(format!(
+                    "Cannot parse the #[repr] attribute on a struct marked with #[{}]",
+                    macro_name
+                ))
+            }
+        };
+
+        for hint in hints {
+            match hint {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C") => (),
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("align") => {
+                    let max_align = max_align.ok_or_else(|| {
+                        format!(
+                            "#[repr(align)] cannot be used on a struct marked with #[{}]; the alignment of the struct is determined by its fields",
+                            macro_name
+                        )
+                    })?;
+
+                    let align = match list.nested.iter().next() {
+                        Some(NestedMeta::Lit(Lit::Int(lit))) if list.nested.len() == 1 => {
+                            lit.base10_parse::<u64>().ok()
+                        }
+                        _ => None,
+                    };
+
+                    match align {
+                        Some(align) if align.is_power_of_two() && align <= max_align => (),
+                        Some(align) if align.is_power_of_two() => {
+                            return Err(format!(
+                                "#[repr(align({}))] cannot be used on a struct marked with #[{}]; the alignment of the struct must be {}",
+                                align, macro_name, max_align
+                            ))
+                        }
+                        _ => {
+                            return Err(
+                                "#[repr(align(N))] requires `N` to be a power of two".to_string()
+                            )
+                        }
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("packed") => {
+                    return Err(format!(
+                        "#[repr(packed)] cannot be used on a struct marked with #[{}]; packing would break the alignment of its fields",
+                        macro_name
+                    ))
+                }
+                NestedMeta::Meta(meta) => {
+                    let hint = meta.path().get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+
+                    return Err(format!(
+                        "#[repr({})] cannot be used on a struct marked with #[{}]; only #[repr(C)] and #[repr(align(N))] are compatible",
+                        hint, macro_name
+                    ));
+                }
+                NestedMeta::Lit(_) => {
+                    return Err(format!("Cannot parse the #[repr] attribute on a struct marked with #[{}]", macro_name))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a copy of `input` without its `#[repr]` attributes, so that only the representation
+/// applied by the macro remains.
+pub(crate) fn without_repr(input: &DeriveInput) -> DeriveInput {
+    let mut input = input.clone();
+
+    input.attrs.retain(|attr| !attr.path.is_ident("repr"));
+
     input
+}
+
+/// Returns the `#[cfg]` attributes of a field, so that the code generated for the field can be
+/// conditionally compiled along with it.
+pub(crate) fn cfg_attrs(field: &syn::Field) -> Vec<&Attribute> {
+    field
         .attrs
         .iter()
-        .any(|attr| attr.path.is_ident(&Ident::new("repr", Span::call_site())))
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Returns the source representation of a type, without the whitespace `quote` inserts between
+/// tokens (e.g. `std140::array<std140::vec4, 2>`).
+fn type_name(ty: &syn::Type) -> String {
+    let mut name = quote!(#ty).to_string();
+
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+    ] {
+        name = name.replace(from, to);
+    }
+
+    name
+}
+
+fn member_and_name(field: &syn::Field, index: usize) -> (Member, String) {
+    match &field.ident {
+        Some(ident) => (
+            Member::Named(ident.clone()),
+            ident.to_string().trim_start_matches("r#").to_owned(),
+        ),
+        None => (Member::Unnamed(Index::from(index)), index.to_string()),
+    }
+}
+
+/// Generates `bytemuck::Zeroable` and `bytemuck::Pod` implementations for the struct if the
+/// `bytemuck` feature of the `std140` crate is enabled.
+///
+/// The `Zeroable` implementation requires every field type to implement `Zeroable`. Only the type
+/// parameters of the struct are bounded in the where clause; the field types are checked in a
+/// function body instead, and only if they implement `field_trait` (`ReprStd140` or `ReprStd430`).
+/// A field type that does not implement `field_trait` is already reported by the field assertions,
+/// so this avoids a second, less helpful error about `Zeroable` for that field.
+///
+/// The `Pod` implementation is only generated for structs without type parameters or
+/// conditionally compiled fields. It is bounded on every field type implementing `Pod` (which
+/// excludes `boolean`s and types that contain padding) and on the field sizes adding up to the size
+/// of the struct (which excludes padding between and after the fields). The bounds are
+/// higher-ranked, so that they are not checked eagerly: a struct for which they don't hold simply
+/// does not implement `Pod`.
+pub(crate) fn impl_bytemuck(
+    crate_path: &TokenStream,
+    field_trait: &TokenStream,
+    input: &DeriveInput,
+    fields: &Fields,
+) -> TokenStream {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let predicates = input
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|w| w.predicates.iter());
+    let type_params = input.generics.type_params().map(|param| &param.ident);
+
+    let where_clause = quote! {
+        where
+            #(#predicates,)*
+            #(#type_params: #crate_path::__private::bytemuck::Zeroable,)*
+    };
+
+    let asserts = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let span = field.span();
+        let cfgs = cfg_attrs(field);
+
+        quote_spanned! {span=>
+            #(#cfgs)*
+            ZeroableField::<#ty>(core::marker::PhantomData).assert_zeroable();
+        }
+    });
+
+    let impl_pod = if input.generics.params.is_empty()
+        && fields.iter().all(|field| cfg_attrs(field).is_empty())
+    {
+        let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+        quote! {
+            #[automatically_derived]
+            unsafe impl #crate_path::__private::bytemuck::Pod for #struct_name
+            where
+                #(for<'__pod> #field_types: #crate_path::__private::bytemuck::Pod,)*
+                for<'__pod> #crate_path::__private::PodCheck<{
+                    0 #(+ core::mem::size_of::<#field_types>())* == core::mem::size_of::<#struct_name>()
+                }>: #crate_path::__private::NoPadding,
+                for<'__pod> #struct_name: Copy,
+            {
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #crate_path::__if_bytemuck! {
+            #[allow(dead_code)]
+            fn assert_fields_zeroable #impl_generics () #where_clause {
+                struct ZeroableField<T>(core::marker::PhantomData<T>);
+
+                impl<T> ZeroableField<T> where T: #field_trait {
+                    fn assert_zeroable(&self) where T: #crate_path::__private::bytemuck::Zeroable {}
+                }
+
+                // Selected only if the inherent method above is not applicable, i.e. for a field
+                // type that does not implement the field trait.
+                trait InvalidField {
+                    fn assert_zeroable(&self) {}
+                }
+
+                impl<T> InvalidField for ZeroableField<T> {}
+
+                #(#asserts)*
+            }
+
+            #[automatically_derived]
+            unsafe impl #impl_generics #crate_path::__private::bytemuck::Zeroable for #struct_name #ty_generics #where_clause {}
+
+            #impl_pod
+        }
+    }
+}
+
+/// Parses the value of a `set = N` or `binding = N` argument.
+fn parse_index(name_value: &MetaNameValue, name: &str) -> Result<u32, String> {
+    if let Lit::Int(lit) = &name_value.lit {
+        lit.base10_parse::<u32>()
+            .map_err(|_| format!("The `{}` argument must fit in a `u32`", name))
+    } else {
+        Err(format!(
+            "The `{}` argument expects an integer literal, e.g. `{} = 0`",
+            name, name
+        ))
+    }
+}
+
+/// Parses the value of a `crate = "path"` argument, which specifies the path to the `std140` crate
+/// for when it is not available as `std140` (e.g. because the dependency was renamed).
+pub(crate) fn parse_crate_path(name_value: &MetaNameValue) -> Result<TokenStream, String> {
+    if let Lit::Str(lit) = &name_value.lit {
+        lit.parse::<syn::Path>()
+            .map(|path| quote!(#path))
+            .map_err(|_| format!("`{}` is not a valid path to the std140 crate", lit.value()))
+    } else {
+        Err(
+            "The `crate` argument expects a string literal, e.g. `crate = \"my_std140\"`"
+                .to_string(),
+        )
+    }
 }
@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Index, Member, Meta, NestedMeta, Type};
+
+use crate::repr_std140::{check_repr, impl_bytemuck, parse_crate_path, without_repr};
+
+pub fn expand_repr_std430(args: &[NestedMeta], input: &DeriveInput) -> Result<TokenStream, String> {
+    if let Data::Struct(data) = &input.data {
+        check_repr(input, "repr_std430", None)?;
+
+        if data.fields.is_empty() {
+            return Err(
+                "Cannot represent a struct without fields as std430; GLSL does not allow empty structs or blocks"
+                    .to_string(),
+            );
+        }
+
+        let runtime_array_index = data
+            .fields
+            .iter()
+            .position(|field| is_runtime_array(&field.ty));
+
+        if let Some(index) = runtime_array_index {
+            if index != data.fields.len() - 1 {
+                return Err(
+                    "A `runtime_array` must be the last field of a struct marked with #[repr_std430]"
+                        .to_string(),
+                );
+            }
+        }
+
+        let crate_path = parse_args(args)?.unwrap_or_else(|| quote!(std140));
+        let mod_path = quote!(#crate_path::std430);
+        let struct_name = &input.ident;
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+        let asserts = data.fields.iter().take(runtime_array_index.unwrap_or(data.fields.len())).map(|field| {
+            let ty = &field.ty;
+            let span = field.span();
+
+            quote_spanned!(span=> assert_repr_std430::<#ty> { marker: core::marker::PhantomData };)
+        });
+
+        let asserts = quote! {
+            struct assert_repr_std430<T> where T: #mod_path::ReprStd430 {
+                marker: core::marker::PhantomData<T>
+            }
+
+            #[allow(dead_code)]
+            fn assert_fields #impl_generics () #where_clause {
+                #(#asserts)*
+            }
+        };
+
+        let impl_struct = if let Some(index) = runtime_array_index {
+            let field = data.fields.iter().last().unwrap();
+            let ty = &field.ty;
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+
+            quote! {
+                #[automatically_derived]
+                unsafe impl #impl_generics #mod_path::Std430RuntimeSizedStruct for #struct_name #ty_generics #where_clause {
+                    const RUNTIME_ARRAY_OFFSET: usize = core::mem::offset_of!(Self, #member);
+
+                    const RUNTIME_ARRAY_STRIDE: usize = <#ty>::STRIDE;
+                }
+            }
+        } else {
+            quote! {
+                #[automatically_derived]
+                unsafe impl #impl_generics #mod_path::Std430Struct for #struct_name #ty_generics #where_clause {}
+            }
+        };
+
+        let impl_bytemuck = impl_bytemuck(
+            &crate_path,
+            &quote!(#mod_path::ReprStd430),
+            input,
+            &data.fields,
+        );
+
+        let stripped_input = without_repr(input);
+
+        let generated = quote! {
+            #[repr(C)]
+            #stripped_input
+
+            #[allow(non_upper_case_globals, unused_attributes, unused_qualifications, clippy::no_effect, clippy::multiple_bound_locations)]
+            const _: () = {
+                #[allow(unknown_lints)]
+                #[allow(clippy::useless_attribute)]
+                #[allow(rust_2018_idioms)]
+
+                #asserts
+
+                #impl_struct
+
+                #impl_bytemuck
+            };
+        };
+
+        Ok(generated)
+    } else {
+        Err("Cannot represent an enum or union as std430, only a struct.".to_string())
+    }
+}
+
+/// Parses the arguments of `#[repr_std430]`, which only accepts an optional `crate = "path"`.
+fn parse_args(args: &[NestedMeta]) -> Result<Option<TokenStream>, String> {
+    let mut crate_path = None;
+
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("crate") => {
+                crate_path = Some(parse_crate_path(name_value)?);
+            }
+            _ => return Err("Unknown argument for #[repr_std430]".to_string()),
+        }
+    }
+
+    Ok(crate_path)
+}
+
+/// Returns `true` if `ty` names the std430 `runtime_array` type.
+pub(crate) fn is_runtime_array(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "runtime_array")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
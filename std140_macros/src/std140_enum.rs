@@ -0,0 +1,94 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, NestedMeta};
+
+use crate::repr_std140::parse_crate_path;
+
+pub fn expand_std140_enum(input: &DeriveInput) -> Result<TokenStream, String> {
+    let data = if let Data::Enum(data) = &input.data {
+        data
+    } else {
+        return Err("#[derive(Std140Enum)] can only be applied to an enum.".to_string());
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err("#[derive(Std140Enum)] does not support generic enums.".to_string());
+    }
+
+    if !has_repr_u32(input) {
+        return Err(
+            "#[derive(Std140Enum)] requires the enum to be marked #[repr(u32)].".to_string(),
+        );
+    }
+
+    let mut mod_path = quote!(std140);
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("std140"))
+    {
+        let nested = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested,
+            _ => return Err("Expected `#[std140(crate = \"path\")]`".to_string()),
+        };
+
+        for meta in nested {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("crate") =>
+                {
+                    mod_path = parse_crate_path(&name_value)?;
+                }
+                _ => return Err("Unknown argument for #[std140(...)]".to_string()),
+            }
+        }
+    }
+
+    let mut variants = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(format!(
+                "#[derive(Std140Enum)] only supports variants without fields, but `{}` has fields.",
+                variant.ident
+            ));
+        }
+
+        variants.push(&variant.ident);
+    }
+
+    let enum_name = &input.ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #mod_path::Std140Enum for #enum_name {
+            fn to_std140(self) -> #mod_path::uint {
+                #mod_path::uint(self as u32)
+            }
+
+            fn from_std140(value: #mod_path::uint) -> Option<Self> {
+                #(
+                    if value.0 == #enum_name::#variants as u32 {
+                        return Some(#enum_name::#variants);
+                    }
+                )*
+
+                None
+            }
+        }
+    })
+}
+
+fn has_repr_u32(input: &DeriveInput) -> bool {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("u32")))
+}
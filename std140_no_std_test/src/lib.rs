@@ -0,0 +1,29 @@
+//! Verifies that the `std140` crate and the code generated by its macros build in a `no_std` crate.
+
+#![no_std]
+
+use std140::AsBytes;
+
+#[std140::repr_std140(assert_layout)]
+pub struct PointLight {
+    pub intensity: std140::float,
+    pub position: std140::vec3,
+}
+
+#[std140::repr_std140]
+pub struct Uniforms {
+    pub transform: std140::mat4x4,
+    pub lights: std140::array<PointLight, 2>,
+    pub enabled: std140::boolean,
+}
+
+#[std140::std430::repr_std430]
+pub struct Particles {
+    pub weights: std140::std430::array<std140::float, 4>,
+}
+
+const _: () = assert!(std140::std140_offset_of!(Uniforms, lights[1].position) == 112);
+
+pub fn write_uniforms(uniforms: &Uniforms, buffer: &mut [u8]) -> usize {
+    uniforms.write_bytes(buffer)
+}
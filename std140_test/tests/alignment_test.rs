@@ -0,0 +1,209 @@
+use std140::{repr_std140, ReprStd140};
+
+#[repr_std140]
+struct PointLight {
+    position: std140::vec4,
+    intensity: std140::float,
+}
+
+#[test]
+fn scalar_alignment_test() {
+    assert_eq!(std140::float::STD140_ALIGNMENT, 4);
+    assert_eq!(std140::int::STD140_ALIGNMENT, 4);
+    assert_eq!(std140::uint::STD140_ALIGNMENT, 4);
+    assert_eq!(std140::boolean::STD140_ALIGNMENT, 4);
+    assert_eq!(std140::double::STD140_ALIGNMENT, 8);
+}
+
+#[test]
+fn vector_alignment_test() {
+    assert_eq!(std140::vec2::STD140_ALIGNMENT, 8);
+    assert_eq!(std140::vec3::STD140_ALIGNMENT, 16);
+    assert_eq!(std140::vec4::STD140_ALIGNMENT, 16);
+    assert_eq!(std140::bvec2::STD140_ALIGNMENT, 8);
+    assert_eq!(std140::dvec2::STD140_ALIGNMENT, 16);
+    assert_eq!(std140::dvec3::STD140_ALIGNMENT, 32);
+    assert_eq!(std140::dvec4::STD140_ALIGNMENT, 32);
+}
+
+#[test]
+fn vector_size_test() {
+    assert_eq!(std140::vec2::STD140_SIZE, 8);
+    assert_eq!(std140::vec3::STD140_SIZE, 12);
+    assert_eq!(std140::vec4::STD140_SIZE, 16);
+    assert_eq!(std140::dvec3::STD140_SIZE, 24);
+}
+
+#[test]
+fn matrix_alignment_test() {
+    assert_eq!(std140::mat2x2::STD140_ALIGNMENT, 16);
+    assert_eq!(std140::mat2x2::STD140_SIZE, 32);
+    assert_eq!(std140::mat4x4::STD140_ALIGNMENT, 16);
+    assert_eq!(std140::mat4x4::STD140_SIZE, 64);
+    assert_eq!(std140::dmat3x3::STD140_ALIGNMENT, 32);
+    assert_eq!(std140::dmat3x3::STD140_SIZE, 96);
+}
+
+#[test]
+fn array_alignment_test() {
+    assert_eq!(<std140::array<std140::float, 3>>::STD140_ALIGNMENT, 16);
+    assert_eq!(<std140::array<std140::float, 3>>::STD140_SIZE, 48);
+    assert_eq!(<std140::array<std140::dvec4, 2>>::STD140_ALIGNMENT, 32);
+    assert_eq!(<std140::array<std140::dvec4, 2>>::STD140_SIZE, 64);
+}
+
+#[test]
+fn struct_alignment_test() {
+    assert_eq!(PointLight::STD140_ALIGNMENT, 16);
+    assert_eq!(PointLight::STD140_SIZE, 32);
+}
+
+fn next_offset<T: ReprStd140>(offset: usize) -> usize {
+    offset.next_multiple_of(T::STD140_ALIGNMENT) + T::STD140_SIZE
+}
+
+#[test]
+fn generic_offset_calculation_test() {
+    // vec3 followed by a float packs the float into the vec3's trailing 4 bytes.
+    let offset = next_offset::<std140::vec3>(0);
+    let offset = next_offset::<std140::float>(offset);
+
+    assert_eq!(offset, 16);
+
+    let offset = next_offset::<std140::vec2>(offset);
+    let offset = next_offset::<std140::array<std140::float, 2>>(offset);
+
+    assert_eq!(offset, 64);
+}
+
+#[repr_std140]
+struct Uniforms {
+    transform: std140::mat4x4,
+    light: PointLight,
+    exposure: std140::float,
+}
+
+#[repr_std140]
+struct Scalar {
+    value: std140::float,
+}
+
+#[repr_std140]
+struct Doubles {
+    position: std140::dvec3,
+    scale: std140::double,
+}
+
+#[test]
+fn struct_size_is_multiple_of_16_test() {
+    assert_eq!(Uniforms::STD140_SIZE, 112);
+    assert_eq!(Scalar::STD140_SIZE, 16);
+    assert_eq!(Doubles::STD140_SIZE, 64);
+    assert_eq!(Doubles::STD140_ALIGNMENT, 32);
+
+    for size in [
+        Uniforms::STD140_SIZE,
+        Scalar::STD140_SIZE,
+        Doubles::STD140_SIZE,
+        PointLight::STD140_SIZE,
+    ] {
+        assert_eq!(size % 16, 0);
+    }
+}
+
+#[repr_std140]
+struct SingleInt(std140::int);
+
+#[repr_std140]
+struct SingleBoolean {
+    value: std140::boolean,
+}
+
+#[repr_std140]
+struct SingleVec2 {
+    value: std140::vec2,
+}
+
+#[repr_std140]
+struct SingleDouble {
+    value: std140::double,
+}
+
+#[repr_std140]
+struct SingleDvec3 {
+    value: std140::dvec3,
+}
+
+#[test]
+fn single_field_struct_size_test() {
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Scalar>(), 16);
+    assert_eq!(align_of::<Scalar>(), 16);
+    assert_eq!(size_of::<SingleInt>(), 16);
+    assert_eq!(size_of::<SingleBoolean>(), 16);
+    assert_eq!(size_of::<SingleVec2>(), 16);
+    assert_eq!(size_of::<SingleDouble>(), 16);
+    assert_eq!(size_of::<SingleDvec3>(), 32);
+    assert_eq!(size_of::<std140::array<Scalar, 3>>(), 48);
+}
+
+#[test]
+fn struct_buffer_size_test() {
+    const BUFFER_SIZE: usize = Uniforms::STD140_SIZE * 4;
+
+    assert_eq!(BUFFER_SIZE, 448);
+}
+
+#[repr_std140]
+struct Transform {
+    model: std140::mat4x4,
+    tint: std140::vec4,
+}
+
+#[test]
+fn dynamic_offset_stride_test() {
+    assert_eq!(std::mem::size_of::<Transform>(), 80);
+    assert_eq!(std140::dynamic_offset_stride::<Transform>(256), 256);
+    assert_eq!(std140::dynamic_offset_stride::<Transform>(64), 128);
+    assert_eq!(std140::dynamic_offset_stride::<Transform>(16), 80);
+    assert_eq!(std140::dynamic_offset_stride::<Uniforms>(256), 256);
+}
+
+fn assert_unpadded<T: std140::Std140UnpaddedArrayElement>() {
+    assert_eq!(
+        std::mem::size_of::<std140::array<T, 3>>(),
+        3 * std::mem::size_of::<T>()
+    );
+}
+
+#[test]
+fn unpadded_array_element_test() {
+    assert_unpadded::<std140::vec3>();
+    assert_unpadded::<std140::vec4>();
+    assert_unpadded::<std140::ivec3>();
+    assert_unpadded::<std140::uvec4>();
+    assert_unpadded::<std140::bvec3>();
+    assert_unpadded::<std140::dvec2>();
+    assert_unpadded::<std140::dvec3>();
+    assert_unpadded::<std140::dvec4>();
+    assert_unpadded::<std140::mat2x2>();
+    assert_unpadded::<std140::dmat3x3>();
+    assert_unpadded::<std140::RowMajor<std140::mat2x3>>();
+    assert_unpadded::<std140::array<std140::float, 2>>();
+    assert_unpadded::<PointLight>();
+}
+
+#[test]
+fn element_stride_test() {
+    assert_eq!(std140::element_stride::<std140::float>(), 16);
+    assert_eq!(std140::element_stride::<std140::dvec2>(), 16);
+    assert_eq!(std140::element_stride::<std140::vec3>(), 16);
+    assert_eq!(std140::element_stride::<std140::dvec3>(), 32);
+    assert_eq!(std140::element_stride::<std140::mat3x3>(), 48);
+    assert_eq!(std140::element_stride::<PointLight>(), 32);
+    assert_eq!(
+        std::mem::size_of::<std140::array<std140::vec2, 5>>(),
+        5 * std140::element_stride::<std140::vec2>()
+    );
+}
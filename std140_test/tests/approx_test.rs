@@ -0,0 +1,82 @@
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+#[test]
+fn float_relative_eq_test() {
+    assert_relative_eq!(std140::float(0.1 + 0.2), std140::float(0.3));
+    assert_relative_ne!(std140::float(0.1), std140::float(0.2));
+}
+
+#[test]
+fn double_ulps_eq_test() {
+    assert_ulps_eq!(std140::double(0.1 + 0.2), std140::double(0.3));
+}
+
+#[test]
+fn vec3_relative_eq_test() {
+    let a = std140::vec3(0.1, 0.2, 0.3) * 3.0;
+    let b = std140::vec3(0.3, 0.6, 0.9);
+
+    assert_relative_eq!(a, b);
+    assert_relative_ne!(a, std140::vec3(0.3, 0.6, 1.0));
+}
+
+#[test]
+fn vec4_abs_diff_eq_epsilon_test() {
+    let a = std140::vec4(1.0, 2.0, 3.0, 4.0);
+    let b = std140::vec4(1.0, 2.0, 3.0, 4.05);
+
+    assert_abs_diff_eq!(a, b, epsilon = 0.1);
+    assert!(!approx::abs_diff_eq!(a, b, epsilon = 0.01));
+}
+
+#[test]
+fn mat2x2_relative_eq_test() {
+    let rotation = std140::mat2x2(std140::vec2(0.0, 1.0), std140::vec2(-1.0, 0.0));
+    let angle = core::f32::consts::FRAC_PI_2;
+    let computed = std140::mat2x2(
+        std140::vec2(angle.cos(), angle.sin()),
+        std140::vec2(-angle.sin(), angle.cos()),
+    );
+
+    assert_ne!(computed, rotation);
+    assert_relative_eq!(computed, rotation, epsilon = 1e-6);
+}
+
+#[test]
+fn dmat3x3_ulps_eq_test() {
+    let a = std140::dmat3x3(
+        std140::dvec3(0.1 + 0.2, 0.0, 0.0),
+        std140::dvec3(0.0, 1.0, 0.0),
+        std140::dvec3(0.0, 0.0, 1.0),
+    );
+    let b = std140::dmat3x3(
+        std140::dvec3(0.3, 0.0, 0.0),
+        std140::dvec3(0.0, 1.0, 0.0),
+        std140::dvec3(0.0, 0.0, 1.0),
+    );
+
+    assert_ulps_eq!(a, b);
+}
+
+#[test]
+fn array_relative_eq_test() {
+    let a = std140::array![std140::float(0.1 + 0.2), std140::float(1.0)];
+    let b = std140::array![std140::float(0.3), std140::float(1.0)];
+
+    assert_relative_eq!(a, b);
+    assert_relative_ne!(a, std140::array![std140::float(0.3), std140::float(2.0)]);
+}
+
+#[test]
+fn array_of_matrices_relative_eq_test() {
+    let a = std140::array![std140::mat2x2(
+        std140::vec2(0.1 + 0.2, 0.0),
+        std140::vec2(0.0, 1.0),
+    )];
+    let b = std140::array![std140::mat2x2(
+        std140::vec2(0.3, 0.0),
+        std140::vec2(0.0, 1.0),
+    )];
+
+    assert_relative_eq!(a, b);
+}
@@ -0,0 +1,463 @@
+use std::iter::FromIterator;
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PointLight {
+    position: std140::vec3,
+    intensity: std140::float,
+}
+
+fn light(i: usize) -> PointLight {
+    PointLight {
+        position: std140::vec3(i as f32, 0.0, 0.0),
+        intensity: std140::float(1.0),
+    }
+}
+
+#[test]
+fn array_from_fn_test() {
+    let value = std140::array::<PointLight, 2>::from_fn(light);
+
+    assert_eq!(value, std140::array![light(0), light(1)]);
+}
+
+#[test]
+fn sized_array_test() {
+    let value = std140::sized_array![3; light(0), light(1), light(2),];
+
+    assert_eq!(value, std140::array![light(0), light(1), light(2)]);
+}
+
+#[test]
+fn array_repeat_test() {
+    const LEN: usize = 2;
+    const ZEROS: std140::array<std140::vec2, 3> = std140::array![std140::vec2(0.0, 0.0); 3];
+
+    let value = std140::array![light(1); LEN + 1];
+
+    assert_eq!(value, std140::array![light(1), light(1), light(1)]);
+    assert_eq!(ZEROS.get(2), Some(&std140::vec2(0.0, 0.0)));
+}
+
+#[test]
+fn array_non_copy_elements_test() {
+    #[std140::repr_std140]
+    #[derive(PartialEq, Debug)]
+    struct Material {
+        color: std140::vec4,
+    }
+
+    // Listing the elements individually does not require `Copy`.
+    let value = std140::array![
+        Material {
+            color: std140::vec4(1.0, 0.0, 0.0, 1.0)
+        },
+        Material {
+            color: std140::vec4(0.0, 1.0, 0.0, 1.0)
+        },
+    ];
+
+    assert_eq!(
+        value.get(1).unwrap().color,
+        std140::vec4(0.0, 1.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn array_from_slice_test() {
+    let lights: Vec<PointLight> = (0..3).map(light).collect();
+
+    assert_eq!(
+        std140::array::<PointLight, 3>::from_slice(&lights),
+        Some(std140::array![light(0), light(1), light(2)])
+    );
+}
+
+#[test]
+fn array_from_slice_length_mismatch_test() {
+    let lights: Vec<PointLight> = (0..3).map(light).collect();
+
+    assert_eq!(std140::array::<PointLight, 2>::from_slice(&lights), None);
+    assert_eq!(std140::array::<PointLight, 4>::from_slice(&lights), None);
+}
+
+#[test]
+fn nested_array_layout_test() {
+    // `float x[3][4]` in GLSL: every `float` occupies 16 bytes, every inner array 64 bytes.
+    assert_eq!(
+        std::mem::size_of::<std140::array<std140::array<std140::float, 4>, 3>>(),
+        192
+    );
+    // `vec3 x[2][2]`
+    assert_eq!(
+        std::mem::size_of::<std140::array<std140::array<std140::vec3, 2>, 2>>(),
+        64
+    );
+}
+
+#[test]
+fn nested_array_offsets_test() {
+    #[std140::repr_std140]
+    struct Grid {
+        scale: std140::float,
+        cells: std140::array<std140::array<std140::float, 4>, 3>,
+    }
+
+    assert_eq!(std140::std140_offset_of!(Grid, cells), 16);
+    assert_eq!(std140::std140_offset_of!(Grid, cells[1]), 80);
+    assert_eq!(std140::std140_offset_of!(Grid, cells[2][3]), 192);
+    assert_eq!(std::mem::size_of::<Grid>(), 208);
+}
+
+#[test]
+fn nested_vec4_array_layout_test() {
+    use std140::ReprStd140;
+
+    #[std140::repr_std140]
+    struct Grid {
+        origin: std140::vec2,
+        grid: std140::array<std140::array<std140::vec4, 4>, 4>,
+    }
+
+    // `vec4 grid[4][4]` in GLSL: a `vec4` needs no padding, so every inner array occupies 64 bytes.
+    assert_eq!(
+        std::mem::size_of::<std140::array<std140::array<std140::vec4, 4>, 4>>(),
+        256
+    );
+    assert_eq!(
+        <std140::array<std140::array<std140::vec4, 4>, 4>>::STD140_ALIGNMENT,
+        16
+    );
+    assert_eq!(std140::std140_offset_of!(Grid, grid), 16);
+    assert_eq!(std140::std140_offset_of!(Grid, grid[1]), 80);
+    assert_eq!(std140::std140_offset_of!(Grid, grid[1][2]), 112);
+    assert_eq!(std140::std140_offset_of!(Grid, grid[3][3]), 256);
+    assert_eq!(std::mem::size_of::<Grid>(), 272);
+
+    let mut grid = std140::array![std140::array![std140::vec4(0.0, 0.0, 0.0, 0.0); 4]; 4];
+
+    *grid.get_mut(2).unwrap().get_mut(1).unwrap() = std140::vec4(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(
+        std140::AsBytes::to_bytes(&grid)[2 * 64 + 16..2 * 64 + 32],
+        *std140::AsBytes::as_bytes(&std140::vec4(1.0, 2.0, 3.0, 4.0))
+    );
+}
+
+#[test]
+fn array_eq_primitive_array_test() {
+    let value = std140::array![std140::float(0.0), std140::float(0.5), std140::float(1.0)];
+    let primitive = [std140::float(0.0), std140::float(0.5), std140::float(1.0)];
+
+    assert_eq!(value, primitive);
+    assert_eq!(primitive, value);
+    assert_ne!(value, [std140::float(0.0); 3]);
+    assert_ne!([std140::float(0.0); 3], value);
+}
+
+#[test]
+fn array_from_iter_test() {
+    let value = std140::array::<PointLight, 2>::from_iter((0..2).map(light));
+
+    assert_eq!(value, std140::array![light(0), light(1)]);
+}
+
+#[test]
+#[should_panic(expected = "expected 3 elements for a std140 array, but the iterator yielded 2")]
+fn array_from_iter_too_few_test() {
+    let _: std140::array<PointLight, 3> = (0..2).map(light).collect();
+}
+
+#[test]
+#[should_panic(expected = "expected 2 elements for a std140 array, but the iterator yielded 5")]
+fn array_from_iter_too_many_test() {
+    let _: std140::array<PointLight, 2> = (0..5).map(light).collect();
+}
+
+#[test]
+fn array_get_test() {
+    let mut value = std140::array![light(0), light(1)];
+
+    assert_eq!(value.get(1), Some(&light(1)));
+    assert_eq!(value.get(2), None);
+
+    *value.get_mut(0).unwrap() = light(5);
+
+    assert_eq!(value.get(0), Some(&light(5)));
+    assert!(value.get_mut(2).is_none());
+}
+
+#[test]
+fn array_map_test() {
+    let value = std140::array![std140::float(1.0), std140::float(2.0), std140::float(3.0)];
+
+    assert_eq!(
+        value.map(|x| std140::float(x.0 * x.0)),
+        [std140::float(1.0), std140::float(4.0), std140::float(9.0)]
+    );
+}
+
+#[test]
+fn array_to_vec_test() {
+    let value = std140::array![std140::float(1.0), std140::float(2.0), std140::float(3.0)];
+
+    assert_eq!(
+        value.to_vec(),
+        vec![std140::float(1.0), std140::float(2.0), std140::float(3.0)]
+    );
+}
+
+#[test]
+fn array_copy_to_slice_test() {
+    let value = std140::array![light(0), light(1)];
+    let mut lights = [light(9); 2];
+
+    value.copy_to_slice(&mut lights);
+
+    assert_eq!(lights, [light(0), light(1)]);
+}
+
+#[test]
+#[should_panic(expected = "the length of the slice does not match the length of the std140 array")]
+fn array_copy_to_slice_length_mismatch_test() {
+    let value = std140::array![light(0), light(1)];
+    let mut lights = [light(9); 3];
+
+    value.copy_to_slice(&mut lights);
+}
+
+#[test]
+fn array_as_ref_test() {
+    let value = std140::array![light(0), light(1)];
+
+    assert_eq!(value.as_ref(), &[light(0), light(1)]);
+}
+
+#[test]
+fn array_as_mut_test() {
+    let mut value = std140::array![std140::vec3(1.0, 0.0, 0.0), std140::vec3(0.0, 1.0, 0.0)];
+
+    value.as_mut()[1] = std140::vec3(0.0, 0.0, 1.0);
+
+    assert_eq!(
+        value,
+        [std140::vec3(1.0, 0.0, 0.0), std140::vec3(0.0, 0.0, 1.0)]
+    );
+}
+
+#[test]
+fn array_concat_test() {
+    let value: std140::array<PointLight, 3> =
+        std140::array![light(0)].concat(std140::array![light(1), light(2)]);
+
+    assert_eq!(value, std140::array![light(0), light(1), light(2)]);
+}
+
+#[test]
+fn array_split_at_test() {
+    let (left, right) = std140::array![light(0), light(1), light(2)].split_at::<2, 1>();
+
+    assert_eq!(left, std140::array![light(0), light(1)]);
+    assert_eq!(right, std140::array![light(2)]);
+}
+
+#[test]
+fn array_fold_test() {
+    let value = std140::array![
+        std140::float(1.0),
+        std140::float(2.0),
+        std140::float(3.0),
+        std140::float(4.0),
+    ];
+
+    assert_eq!(value.fold(0.0, |sum, element| sum + element.0), 10.0);
+    assert_eq!(
+        value.fold(Vec::new(), |mut elements, element| {
+            elements.push(element.0);
+            elements
+        }),
+        vec![1.0, 2.0, 3.0, 4.0]
+    );
+}
+
+#[test]
+fn array_try_from_slice_test() {
+    use std::convert::TryFrom;
+
+    let lights: Vec<PointLight> = (0..2).map(light).collect();
+
+    assert_eq!(
+        std140::array::<PointLight, 2>::try_from(&lights[..]),
+        Ok(std140::array![light(0), light(1)])
+    );
+}
+
+#[test]
+fn array_try_from_slice_length_mismatch_test() {
+    use std::convert::TryFrom;
+    use std::convert::TryInto;
+
+    fn convert(weights: &[std140::float]) -> Result<std140::array<std140::float, 4>, String> {
+        let value = weights
+            .try_into()
+            .map_err(|e: std140::SliceLengthError| e.to_string())?;
+
+        Ok(value)
+    }
+
+    let weights = [std140::float(1.0); 3];
+    let error = std140::array::<std140::float, 4>::try_from(&weights[..]).unwrap_err();
+
+    assert_eq!(error.expected(), 4);
+    assert_eq!(error.actual(), 3);
+    assert_eq!(
+        convert(&weights),
+        Err("expected a slice of 4 elements for a std140 array, but the slice has 3".to_string())
+    );
+}
+
+#[test]
+fn array_into_iter_test() {
+    let value: std140::array<std140::vec2, 3> = std140::array![
+        std140::vec2(1.0, 2.0),
+        std140::vec2(3.0, 4.0),
+        std140::vec2(5.0, 6.0),
+    ];
+
+    let mut iter = value.into_iter();
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(std140::vec2(1.0, 2.0)));
+    assert_eq!(iter.next_back(), Some(std140::vec2(5.0, 6.0)));
+    assert_eq!(iter.next(), Some(std140::vec2(3.0, 4.0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn array_for_loop_test() {
+    let value = std140::array![std140::uint(1), std140::uint(2), std140::uint(3)];
+
+    let mut elements = Vec::new();
+
+    for element in value {
+        elements.push(element);
+    }
+
+    assert_eq!(
+        elements,
+        vec![std140::uint(1), std140::uint(2), std140::uint(3)]
+    );
+}
+
+#[test]
+fn array_get_const_test() {
+    const WEIGHTS: std140::array<std140::float, 3> =
+        std140::array![std140::float(0.25), std140::float(0.5), std140::float(0.25),];
+    const CENTER: std140::float = WEIGHTS.get_const(1);
+
+    assert_eq!(CENTER, std140::float(0.5));
+}
+
+#[test]
+#[should_panic]
+fn array_get_const_out_of_bounds_test() {
+    let value = std140::array![std140::float(0.0), std140::float(1.0)];
+
+    let _ = value.get_const(std::hint::black_box(2));
+}
+
+#[test]
+fn array_range_index_test() {
+    let mut value = std140::array![light(0), light(1), light(2), light(3)];
+
+    assert_eq!(&value[1..3], &[light(1), light(2)]);
+    assert_eq!(&value[2..], &[light(2), light(3)]);
+    assert_eq!(&value[..1], &[light(0)]);
+    assert_eq!(&value[..=1], &[light(0), light(1)]);
+    assert_eq!(&value[1..=1], &[light(1)]);
+    assert_eq!(value[..].len(), 4);
+
+    for light in &mut value[2..] {
+        light.intensity = std140::float(0.5);
+    }
+
+    assert_eq!(value.get(1).unwrap().intensity, std140::float(1.0));
+    assert_eq!(value.get(3).unwrap().intensity, std140::float(0.5));
+}
+
+#[test]
+#[should_panic]
+fn array_range_index_out_of_bounds_test() {
+    let value = std140::array![std140::vec4(0.0, 0.0, 0.0, 0.0); 2];
+
+    let _ = &value[1..std::hint::black_box(3)];
+}
+
+#[test]
+fn array_from_primitive_array_test() {
+    let plain = [
+        std140::vec4(1.0, 0.0, 0.0, 1.0),
+        std140::vec4(0.0, 1.0, 0.0, 1.0),
+        std140::vec4(0.0, 0.0, 1.0, 1.0),
+        std140::vec4(1.0, 1.0, 1.0, 1.0),
+    ];
+
+    let value: std140::array<std140::vec4, 4> = plain.into();
+
+    assert_eq!(value, plain);
+    assert_eq!(<[std140::vec4; 4]>::from(value), plain);
+}
+
+#[test]
+fn array_primitive_array_round_trip_test() {
+    let floats = [std140::float(0.5), std140::float(1.5)];
+    let round_trip: [std140::float; 2] = std140::array::from(floats).into();
+
+    assert_eq!(round_trip, floats);
+
+    let lights = [light(0), light(1), light(2)];
+    let value = std140::array::from(lights);
+
+    assert_eq!(value, std140::array![light(0), light(1), light(2)]);
+
+    let round_trip: [PointLight; 3] = value.into();
+
+    assert_eq!(round_trip, lights);
+}
+
+#[test]
+fn nested_array_from_primitive_array_test() {
+    let rows = [
+        std140::array![std140::float(0.0), std140::float(1.0)],
+        std140::array![std140::float(2.0), std140::float(3.0)],
+    ];
+
+    let value: std140::array<std140::array<std140::float, 2>, 2> = rows.into();
+    let round_trip: [std140::array<std140::float, 2>; 2] = value.into();
+
+    assert_eq!(round_trip, rows);
+}
+
+fn array_len<T, const LEN: usize>(_: &std140::array<T, LEN>) -> usize
+where
+    T: std140::Std140ArrayElement,
+{
+    LEN
+}
+
+#[test]
+fn array_macro_infers_length_test() {
+    let v0 = std140::vec2(1.0, 0.0);
+    let v1 = std140::vec2(0.0, 1.0);
+    let v2 = std140::vec2(1.0, 1.0);
+
+    let value = std140::array![v0, v1, v2];
+    let repeated = std140::array![v0; 4];
+
+    assert_eq!(array_len(&value), 3);
+    assert_eq!(array_len(&repeated), 4);
+
+    let annotated: std140::array<std140::vec2, 3> = value;
+
+    assert_eq!(annotated.get(2), Some(&v2));
+}
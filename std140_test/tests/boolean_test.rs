@@ -0,0 +1,180 @@
+use std140::boolean::{False, True};
+
+#[test]
+fn boolean_into_bool_test() {
+    assert!(bool::from(True));
+    assert!(!bool::from(False));
+    assert!(True.as_bool());
+}
+
+#[test]
+fn boolean_not_test() {
+    assert_eq!(!True, False);
+    assert_eq!(!False, True);
+}
+
+#[test]
+fn boolean_bit_ops_test() {
+    assert_eq!(True & False, False);
+    assert_eq!(True & True, True);
+    assert_eq!(True | False, True);
+    assert_eq!(False | False, False);
+    assert_eq!(True ^ True, False);
+    assert_eq!(True ^ False, True);
+}
+
+#[test]
+fn boolean_from_raw_test() {
+    assert_eq!(std140::boolean::from_raw(0), False);
+    assert_eq!(std140::boolean::from_raw(1), True);
+    assert_eq!(std140::boolean::from_raw(2), True);
+    assert_eq!(std140::boolean::from_raw(u32::MAX), True);
+}
+
+#[test]
+fn boolean_try_from_raw_test() {
+    assert_eq!(std140::boolean::try_from_raw(0), Ok(False));
+    assert_eq!(std140::boolean::try_from_raw(1), Ok(True));
+
+    let error = std140::boolean::try_from_raw(2).unwrap_err();
+
+    assert_eq!(error.value(), 2);
+    assert_eq!(
+        error.to_string(),
+        "`2` is not a valid std140 boolean (expected `0` or `1`)"
+    );
+}
+
+#[test]
+fn boolean_uint_value_test() {
+    assert_eq!(True.uint_value(), 1);
+    assert_eq!(False.uint_value(), 0);
+    assert_eq!(std140::boolean::from_uint(True.uint_value()), True);
+    assert_eq!(std140::boolean::from_uint(False.uint_value()), False);
+    assert_eq!(std140::boolean::from_uint(u32::MAX), True);
+}
+
+#[test]
+fn boolean_representation_test() {
+    use std140::AsBytes;
+
+    assert_eq!(True.as_bytes(), &1u32.to_ne_bytes());
+    assert_eq!(False.as_bytes(), &0u32.to_ne_bytes());
+    assert_eq!(
+        std140::bvec2(True, False).as_bytes(),
+        std140::uvec2(1, 0).as_bytes()
+    );
+}
+
+#[test]
+fn bvec_layout_test() {
+    use std::mem::{align_of, size_of};
+    use std140::ReprStd140;
+
+    assert_eq!(size_of::<std140::boolean>(), 4);
+    assert_eq!(align_of::<std140::boolean>(), 4);
+
+    // GLSL: bvec2 has a size and base alignment of 8 bytes.
+    assert_eq!(std140::bvec2::STD140_SIZE, 8);
+    assert_eq!(std140::bvec2::STD140_ALIGNMENT, 8);
+    assert_eq!(size_of::<std140::bvec2>(), size_of::<std140::uvec2>());
+
+    // GLSL: bvec3 has a size of 12 bytes and a base alignment of 16 bytes.
+    assert_eq!(std140::bvec3::STD140_SIZE, 12);
+    assert_eq!(std140::bvec3::STD140_ALIGNMENT, 16);
+    assert_eq!(size_of::<std140::bvec3>(), size_of::<std140::uvec3>());
+
+    // GLSL: bvec4 has a size and base alignment of 16 bytes.
+    assert_eq!(std140::bvec4::STD140_SIZE, 16);
+    assert_eq!(std140::bvec4::STD140_ALIGNMENT, 16);
+    assert_eq!(size_of::<std140::bvec4>(), size_of::<std140::uvec4>());
+
+    // Array elements are padded to 16 bytes.
+    assert_eq!(std140::element_stride::<std140::boolean>(), 16);
+    assert_eq!(std140::element_stride::<std140::bvec2>(), 16);
+}
+
+#[std140::repr_std140]
+struct BooleanArrayBlock {
+    flags: std140::array<std140::boolean, 3>,
+    after: std140::float,
+}
+
+#[test]
+fn boolean_array_layout_test() {
+    use std::mem::size_of;
+    use std140::{AsBytes, ReprStd140};
+
+    // GLSL: each element of `bool flags[3]` is padded to 16 bytes.
+    assert_eq!(size_of::<std140::array<std140::boolean, 3>>(), 48);
+    assert_eq!(<std140::array<std140::boolean, 3>>::STD140_ALIGNMENT, 16);
+
+    let flags = std140::array![True, False, True];
+    let bytes = flags.to_bytes();
+
+    assert_eq!(&bytes[0..4], &1u32.to_ne_bytes());
+    assert_eq!(&bytes[16..20], &0u32.to_ne_bytes());
+    assert_eq!(&bytes[32..36], &1u32.to_ne_bytes());
+
+    assert_eq!(std140::std140_offset_of!(BooleanArrayBlock, flags[1]), 16);
+    assert_eq!(std140::std140_offset_of!(BooleanArrayBlock, flags[2]), 32);
+    assert_eq!(std140::std140_offset_of!(BooleanArrayBlock, after), 48);
+}
+
+#[test]
+fn boolean_mask_bits_test() {
+    let mut mask = std140::BooleanMask::new();
+
+    assert_eq!(mask.to_uint(), std140::uint(0));
+
+    mask.set_bit(0, true);
+    mask.set_bit(3, true);
+    mask.set_bit(31, true);
+
+    assert!(mask.get_bit(0));
+    assert!(!mask.get_bit(1));
+    assert!(mask.get_bit(3));
+    assert!(mask.get_bit(31));
+    assert_eq!(mask.to_uint(), std140::uint(0x8000_0009));
+
+    mask.set_bit(3, false);
+
+    assert!(!mask.get_bit(3));
+    assert_eq!(std140::uint::from(mask), std140::uint(0x8000_0001));
+}
+
+#[test]
+fn boolean_mask_iter_bits_test() {
+    let mask = std140::BooleanMask::from_uint(std140::uint(0b1010));
+    let bits: Vec<bool> = mask.iter_bits().collect();
+
+    assert_eq!(bits.len(), 32);
+    assert_eq!(&bits[..4], &[false, true, false, true]);
+    assert!(bits[4..].iter().all(|bit| !bit));
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn boolean_mask_out_of_bounds_test() {
+    std140::BooleanMask::new().set_bit(32, true);
+}
+
+#[test]
+fn boolean_mask_layout_test() {
+    use std::mem::{align_of, size_of};
+    use std140::AsBytes;
+
+    assert_eq!(size_of::<std140::BooleanMask>(), size_of::<std140::uint>());
+    assert_eq!(
+        align_of::<std140::BooleanMask>(),
+        align_of::<std140::uint>()
+    );
+
+    let mask = std140::BooleanMask::from(std140::uint(5));
+
+    assert_eq!(mask.as_bytes(), std140::uint(5).as_bytes());
+    assert_eq!(
+        format!("{:?}", mask),
+        "BooleanMask(0b00000000000000000000000000000101)"
+    );
+}
@@ -0,0 +1,112 @@
+use bytemuck::{Pod, Zeroable};
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Flags {
+    enabled: std140::boolean,
+    mask: std140::bvec3,
+    weight: std140::float,
+}
+
+#[std140::std430::repr_std430]
+#[derive(Clone, Copy)]
+struct Weights {
+    values: std140::std430::array<std140::float, 3>,
+}
+
+#[std140::repr_std140]
+#[derive(Clone, Copy)]
+struct Wrapper<T: std140::Std140ArrayElement> {
+    value: T,
+}
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Transform {
+    origin: std140::vec4,
+    matrix: std140::mat4x4,
+}
+
+#[std140::std430::repr_std430]
+#[derive(Clone, Copy)]
+struct Particle {
+    position: std140::vec2,
+    velocity: std140::vec2,
+}
+
+fn assert_pod<T: Pod>() {}
+
+#[test]
+fn zeroed_boolean_struct_test() {
+    assert_eq!(
+        <Flags as Zeroable>::zeroed(),
+        Flags {
+            enabled: std140::boolean::False,
+            mask: std140::bvec3(
+                std140::boolean::False,
+                std140::boolean::False,
+                std140::boolean::False
+            ),
+            weight: std140::float(0.0),
+        }
+    );
+}
+
+#[test]
+fn zeroed_std430_struct_test() {
+    let value = <Weights as Zeroable>::zeroed();
+
+    assert_eq!(&*value.values, &[std140::float(0.0); 3][..]);
+}
+
+#[test]
+fn zeroed_generic_struct_test() {
+    let value = <Wrapper<std140::vec4> as Zeroable>::zeroed();
+
+    assert_eq!(value.value, std140::vec4::zero());
+}
+
+#[test]
+fn pod_types_test() {
+    assert_pod::<std140::float>();
+    assert_pod::<std140::vec4>();
+    assert_pod::<std140::ivec2>();
+    assert_pod::<std140::dvec4>();
+    assert_pod::<std140::f16vec4>();
+    assert_pod::<std140::mat4x4>();
+    assert_pod::<std140::RowMajor<std140::mat3x4>>();
+    assert_pod::<std140::std430::array<std140::vec2, 4>>();
+    assert_pod::<std140::std430::mat4x2>();
+}
+
+#[test]
+fn cast_slice_test() {
+    let values = [
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+    ];
+
+    let floats: &[f32] = bytemuck::cast_slice(&values);
+
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+}
+
+#[test]
+fn pod_struct_test() {
+    assert_pod::<Transform>();
+    assert_pod::<Particle>();
+
+    let transform = Transform {
+        origin: std140::vec4(1.0, 2.0, 3.0, 1.0),
+        matrix: std140::mat4x4::identity(),
+    };
+
+    let floats: &[f32] = bytemuck::cast_slice(std::slice::from_ref(&transform));
+
+    assert_eq!(floats.len(), 20);
+    assert_eq!(&floats[..4], &[1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(
+        bytemuck::pod_read_unaligned::<Transform>(bytemuck::bytes_of(&transform)),
+        transform
+    );
+}
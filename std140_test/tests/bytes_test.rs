@@ -0,0 +1,303 @@
+use std140::{AsBytes, FromBytesError, Std140Struct, WriteError};
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Material {
+    color: std140::vec3,
+    visible: std140::boolean,
+    flags: std140::array<std140::bvec2, 2>,
+}
+
+fn material() -> Material {
+    Material {
+        color: std140::vec3(1.0, 0.5, 0.25),
+        visible: std140::boolean::True,
+        flags: std140::array![
+            std140::bvec2(std140::boolean::True, std140::boolean::False),
+            std140::bvec2(std140::boolean::False, std140::boolean::True),
+        ],
+    }
+}
+
+#[repr(C, align(16))]
+struct Aligned([u8; 128]);
+
+/// Copies `bytes` into a buffer with 16 byte alignment.
+fn aligned(bytes: &[u8]) -> Aligned {
+    let mut buffer = Aligned([0; 128]);
+
+    buffer.0[..bytes.len()].copy_from_slice(bytes);
+
+    buffer
+}
+
+#[test]
+fn as_bytes_len_test() {
+    assert_eq!(std140::vec4(0.0, 0.0, 0.0, 0.0).as_bytes().len(), 16);
+    assert_eq!(std140::vec3(0.0, 0.0, 0.0).to_bytes().len(), 16);
+    assert_eq!(material().to_bytes().len(), std::mem::size_of::<Material>());
+}
+
+#[test]
+fn write_bytes_zeroes_padding_test() {
+    let mut buffer = [0xffu8; 64];
+
+    let written = material().write_bytes(&mut buffer);
+
+    assert_eq!(written, std::mem::size_of::<Material>());
+
+    // The float after `color`, the bytes between `visible` and `flags`, and the tail of each
+    // `bvec2` element in `flags` are padding.
+    assert_eq!(buffer[12..16], [0; 4]);
+    assert_eq!(buffer[20..32], [0; 12]);
+    assert_eq!(buffer[40..48], [0; 8]);
+    assert_eq!(buffer[56..64], [0; 8]);
+
+    let mut buffer = [0xffu8; 16];
+
+    std140::vec3(1.0, 2.0, 3.0).write_bytes(&mut buffer);
+
+    assert_eq!(buffer[12..16], [0; 4]);
+}
+
+#[test]
+fn from_bytes_round_trip_test() {
+    let value = material();
+    let buffer = aligned(&value.to_bytes());
+    let bytes = &buffer.0[..std::mem::size_of::<Material>()];
+
+    assert_eq!(Material::from_bytes(bytes), Some(&value));
+}
+
+#[test]
+fn from_bytes_wrong_length_test() {
+    let buffer = aligned(&material().to_bytes());
+    let len = std::mem::size_of::<Material>();
+
+    assert_eq!(Material::from_bytes(&buffer.0[..len - 4]), None);
+    assert_eq!(Material::from_bytes(&buffer.0[..len + 4]), None);
+}
+
+#[test]
+fn from_bytes_misaligned_test() {
+    let buffer = aligned(&[0; 20]);
+    let bytes = &buffer.0[4..20];
+
+    assert_eq!(std140::vec4::from_bytes(bytes), None);
+}
+
+#[test]
+fn from_bytes_invalid_boolean_test() {
+    let mut buffer = aligned(&material().to_bytes());
+    let offset = std::mem::offset_of!(Material, visible);
+
+    buffer.0[offset..offset + 4].copy_from_slice(&2u32.to_ne_bytes());
+
+    let bytes = &buffer.0[..std::mem::size_of::<Material>()];
+
+    assert_eq!(Material::from_bytes(bytes), None);
+}
+
+#[test]
+fn read_from_bytes_misaligned_test() {
+    let value = material();
+    let len = std::mem::size_of::<Material>();
+    let mut buffer = [0u8; 128];
+
+    buffer[4..4 + len].copy_from_slice(&value.to_bytes());
+
+    assert_eq!(Material::read_from_bytes(&buffer[4..4 + len]), Ok(value));
+}
+
+#[test]
+fn read_from_bytes_wrong_length_test() {
+    let buffer = aligned(&material().to_bytes());
+    let len = std::mem::size_of::<Material>();
+
+    assert_eq!(
+        Material::read_from_bytes(&buffer.0[..len - 4]),
+        Err(FromBytesError::LengthMismatch {
+            expected: len,
+            actual: len - 4
+        })
+    );
+}
+
+#[test]
+fn read_from_bytes_invalid_boolean_test() {
+    let mut buffer = aligned(&material().to_bytes());
+    let offset = std::mem::offset_of!(Material, visible);
+
+    buffer.0[offset..offset + 4].copy_from_slice(&2u32.to_ne_bytes());
+
+    assert_eq!(
+        Material::read_from_bytes(&buffer.0[..std::mem::size_of::<Material>()]),
+        Err(FromBytesError::InvalidBitPattern)
+    );
+}
+
+#[test]
+fn read_std140_misaligned_offset_test() {
+    let value = material();
+    let len = std::mem::size_of::<Material>();
+    let mut buffer = [0u8; 128];
+
+    buffer[5..5 + len].copy_from_slice(&value.to_bytes());
+
+    assert_eq!(std140::read_std140::<Material>(&buffer, 5), Some(value));
+}
+
+#[test]
+fn read_std140_out_of_bounds_test() {
+    let buffer = aligned(&material().to_bytes());
+    let len = std::mem::size_of::<Material>();
+
+    assert_eq!(
+        std140::read_std140::<Material>(&buffer.0[..len], 0),
+        Some(material())
+    );
+    assert_eq!(std140::read_std140::<Material>(&buffer.0[..len], 1), None);
+    assert_eq!(
+        std140::read_std140::<Material>(&buffer.0, 128 - len + 1),
+        None
+    );
+    assert_eq!(std140::read_std140::<Material>(&buffer.0, usize::MAX), None);
+}
+
+#[test]
+fn read_std140_invalid_boolean_test() {
+    let mut buffer = aligned(&material().to_bytes());
+    let offset = std::mem::offset_of!(Material, visible);
+
+    buffer.0[offset..offset + 4].copy_from_slice(&2u32.to_ne_bytes());
+
+    assert_eq!(std140::read_std140::<Material>(&buffer.0, 0), None);
+}
+
+#[test]
+fn write_to_packs_structs_test() {
+    let mut other = material();
+
+    other.visible = std140::boolean::False;
+
+    let mut buffer = vec![0u8; 128];
+
+    let cursor = material().write_to(&mut buffer, 0).unwrap();
+    let cursor = other.write_to(&mut buffer, cursor).unwrap();
+
+    assert_eq!(cursor, 128);
+    assert_eq!(Material::read_from_bytes(&buffer[..64]), Ok(material()));
+    assert_eq!(Material::read_from_bytes(&buffer[64..]), Ok(other));
+}
+
+#[test]
+fn write_to_misaligned_test() {
+    let mut buffer = vec![0u8; 128];
+
+    assert_eq!(
+        material().write_to(&mut buffer, 20),
+        Err(WriteError::Misaligned {
+            offset: 20,
+            alignment: 16
+        })
+    );
+}
+
+#[test]
+fn write_to_buffer_too_small_test() {
+    let mut buffer = vec![0u8; 100];
+
+    assert_eq!(
+        material().write_to(&mut buffer, 48),
+        Err(WriteError::BufferTooSmall {
+            required: 112,
+            actual: 100
+        })
+    );
+    assert_eq!(buffer, vec![0u8; 100]);
+}
+
+#[test]
+fn write_to_offset_overflow_test() {
+    let mut buffer = vec![0u8; 100];
+
+    assert_eq!(
+        material().write_to(&mut buffer, usize::MAX - 15),
+        Err(WriteError::BufferTooSmall {
+            required: usize::MAX,
+            actual: 100
+        })
+    );
+}
+
+#[test]
+fn scalar_to_le_bytes_test() {
+    assert_eq!(std140::float(1.0).to_le_bytes(), [0x00, 0x00, 0x80, 0x3f]);
+    assert_eq!(
+        std140::double(1.0).to_le_bytes(),
+        [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f]
+    );
+    assert_eq!(std140::int(-2).to_le_bytes(), [0xfe, 0xff, 0xff, 0xff]);
+    assert_eq!(
+        std140::uint(0x0102_0304).to_le_bytes(),
+        [0x04, 0x03, 0x02, 0x01]
+    );
+    assert_eq!(
+        std140::boolean::True.to_le_bytes(),
+        [0x01, 0x00, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn scalar_from_le_bytes_test() {
+    assert_eq!(
+        std140::float::from_le_bytes([0x00, 0x00, 0x80, 0x3f]),
+        std140::float(1.0)
+    );
+    assert_eq!(
+        std140::uint::from_le_bytes([0x04, 0x03, 0x02, 0x01]),
+        std140::uint(0x0102_0304)
+    );
+    assert_eq!(
+        std140::boolean::from_le_bytes([0x00, 0x01, 0x00, 0x00]),
+        std140::boolean::True
+    );
+}
+
+#[test]
+fn vector_to_le_bytes_test() {
+    assert_eq!(
+        std140::vec3(1.0, -2.0, 0.0).to_le_bytes(),
+        [0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x00]
+    );
+    assert_eq!(
+        std140::uvec2(1, 0x0100).to_le_bytes(),
+        [0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00]
+    );
+    assert_eq!(
+        std140::bvec2(std140::boolean::False, std140::boolean::True).to_le_bytes(),
+        [0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn vector_le_bytes_round_trip_test() {
+    const BYTES: [u8; 32] = std140::dvec4(1.0, 2.0, 3.0, 4.0).to_le_bytes();
+
+    assert_eq!(
+        std140::dvec4::from_le_bytes(BYTES),
+        std140::dvec4(1.0, 2.0, 3.0, 4.0)
+    );
+
+    let value = std140::ivec4(-1, 0, 1, i32::MAX);
+
+    assert_eq!(std140::ivec4::from_le_bytes(value.to_le_bytes()), value);
+}
+
+#[cfg(target_endian = "little")]
+#[test]
+fn le_bytes_match_as_bytes_on_little_endian_test() {
+    let value = std140::vec4(0.5, 1.5, 2.5, 3.5);
+
+    assert_eq!(&value.to_le_bytes()[..], value.as_bytes());
+}
@@ -0,0 +1,6 @@
+extern crate std140;
+
+fn main() {
+    let value = std140::array![std140::float(1.0), std140::float(2.0)];
+    let _: &[std140::float] = value.as_ref(); //~ ERROR: the method `as_ref` exists for struct `std140::array<float, 2>`, but its trait bounds were not satisfied
+}
@@ -0,0 +1,6 @@
+extern crate std140;
+
+fn main() {
+    let value = std140::array![std140::float(1.0), std140::float(2.0), std140::float(3.0)];
+    let _ = &value[1..]; //~ ERROR: cannot index into a value of type `std140::array<float, 3>`
+}
@@ -0,0 +1,10 @@
+extern crate std140;
+
+#[std140::repr_std140]
+struct Material {
+    color: std140::vec4,
+}
+
+fn main() {
+    let materials = std140::array![Material { color: std140::vec4(1.0, 0.0, 0.0, 1.0) }; 2]; //~ ERROR: the element of `array![element; N]` must implement `Copy`
+}
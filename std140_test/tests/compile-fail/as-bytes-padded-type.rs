@@ -0,0 +1,9 @@
+extern crate std140;
+
+use std140::AsBytes;
+
+fn main() {
+    let value = std140::vec3(0.0, 1.0, 2.0);
+
+    let _ = value.as_bytes(); //~ ERROR: `vec3` contains padding bytes, which cannot be viewed as `&[u8]`
+}
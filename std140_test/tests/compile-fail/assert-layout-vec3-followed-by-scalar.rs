@@ -0,0 +1,11 @@
+extern crate std140;
+
+#[std140::repr_std140(assert_layout)]
+struct PointLight {
+    position: std140::vec3,
+    intensity: std140::float, //~ ERROR: field `intensity` of `PointLight` does not start at the offset required by std140
+}
+
+fn main() {
+
+}
@@ -0,0 +1,10 @@
+extern crate std140;
+
+#[std140::repr_std140(binding = "2")] //~ ERROR: The `binding` argument expects an integer literal
+struct Camera {
+    view: std140::mat4x4,
+}
+
+fn main() {
+
+}
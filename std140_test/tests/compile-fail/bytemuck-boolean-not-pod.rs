@@ -0,0 +1,19 @@
+extern crate bytemuck;
+extern crate std140;
+
+// Contains no padding, so only the `bvec4` field prevents `Flags` from implementing `Pod`.
+#[std140::repr_std140]
+#[derive(Clone, Copy)]
+struct Flags {
+    enabled: std140::bvec4,
+    weights: std140::vec4,
+}
+
+fn assert_zeroable<T: bytemuck::Zeroable>() {}
+
+fn assert_pod<T: bytemuck::Pod>() {}
+
+fn main() {
+    assert_zeroable::<Flags>();
+    assert_pod::<Flags>(); //~ ERROR: the trait bound `bvec4: Pod` is not satisfied
+}
@@ -0,0 +1,16 @@
+extern crate bytemuck;
+extern crate std140;
+
+// Contains no booleans, but the `float` field is followed by 12 bytes of padding.
+#[std140::repr_std140]
+#[derive(Clone, Copy)]
+struct Light {
+    intensity: std140::float,
+    color: std140::vec4,
+}
+
+fn assert_pod<T: bytemuck::Pod>() {}
+
+fn main() {
+    assert_pod::<Light>(); //~ ERROR: the struct contains padding bytes, so it does not implement `Pod`
+}
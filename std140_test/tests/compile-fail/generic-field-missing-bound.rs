@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[std140::repr_std140]
+struct Material<T> {
+    tint: std140::vec4,
+    extra: T, //~ ERROR: field `extra` of `Material` has type `T`, which is not a std140-compatible type
+    //~^ ERROR: `T` is not a std140-compatible type
+}
+
+fn main() {
+
+}
@@ -0,0 +1,10 @@
+extern crate std140;
+
+#[std140::repr_std140(crate = "not a path")] //~ ERROR: `not a path` is not a valid path to the std140 crate
+struct Uniforms {
+    value: std140::float,
+}
+
+fn main() {
+
+}
@@ -0,0 +1,11 @@
+extern crate std140;
+
+#[std140::repr_std140]
+struct Transform {
+    matrix: std140::Matrix<std140::vec4, 5>, //~ ERROR: field `matrix` of `Transform` has type `Matrix<vec4, 5>`, which is not a std140-compatible type
+    //~^ ERROR: `Matrix<vec4, 5>` is not a std140-compatible type
+}
+
+fn main() {
+
+}
@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[std140::repr_std140] //~ ERROR: #[repr(align(32))] cannot be used on a struct marked with #[repr_std140]
+#[repr(C, align(32))]
+struct PointLight {
+    position: std140::vec3,
+    intensity: std140::float,
+}
+
+fn main() {
+
+}
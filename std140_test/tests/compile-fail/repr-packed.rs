@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[std140::repr_std140] //~ ERROR: #[repr(packed)] cannot be used on a struct marked with #[repr_std140]
+#[repr(packed)]
+struct PointLight {
+    position: std140::vec3,
+    intensity: std140::float,
+}
+
+fn main() {
+
+}
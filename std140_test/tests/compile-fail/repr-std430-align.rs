@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[std140::std430::repr_std430] //~ ERROR: #[repr(align)] cannot be used on a struct marked with #[repr_std430]
+#[repr(align(16))]
+struct Particle {
+    position: std140::vec2,
+    velocity: std140::vec2,
+}
+
+fn main() {
+
+}
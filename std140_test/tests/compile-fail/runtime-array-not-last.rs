@@ -0,0 +1,11 @@
+extern crate std140;
+
+#[std140::std430::repr_std430] //~ ERROR: A `runtime_array` must be the last field of a struct marked with #[repr_std430]
+struct Particles {
+    weights: std140::std430::runtime_array<std140::float>,
+    count: std140::uint,
+}
+
+fn main() {
+
+}
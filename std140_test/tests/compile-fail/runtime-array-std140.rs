@@ -0,0 +1,11 @@
+extern crate std140;
+
+#[std140::repr_std140] //~ ERROR: A `runtime_array` cannot be used in a std140 block
+struct Particles {
+    count: std140::uint,
+    weights: std140::std430::runtime_array<std140::float>,
+}
+
+fn main() {
+
+}
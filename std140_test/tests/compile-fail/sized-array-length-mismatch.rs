@@ -0,0 +1,5 @@
+extern crate std140;
+
+fn main() {
+    let _ = std140::sized_array![3; std140::vec2(1.0, 0.0), std140::vec2(0.0, 1.0)]; //~ ERROR: sized_array!: expected 3 elements, found 2
+}
@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[derive(std140::Std140Enum)] //~ ERROR: #[derive(Std140Enum)] only supports variants without fields, but `Tinted` has fields.
+#[repr(u32)]
+enum MaterialKind {
+    Opaque,
+    Tinted(u32),
+}
+
+fn main() {
+
+}
@@ -0,0 +1,12 @@
+extern crate std140;
+
+#[derive(std140::Std140Enum)] //~ ERROR: #[derive(Std140Enum)] requires the enum to be marked #[repr(u32)].
+#[repr(u8)]
+enum MaterialKind {
+    Opaque,
+    Transparent,
+}
+
+fn main() {
+
+}
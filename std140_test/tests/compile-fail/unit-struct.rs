@@ -0,0 +1,8 @@
+extern crate std140;
+
+#[std140::repr_std140] //~ ERROR: Cannot represent a struct without fields as std140
+struct Empty;
+
+fn main() {
+
+}
@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+extern crate std140;
+
+#[std140::repr_std140(warn_cfg_fields)]
+struct Uniforms {
+    transform: std140::mat4x4,
+    #[cfg(any())] //~ ERROR: this field of a #[repr_std140(warn_cfg_fields)] struct is conditionally compiled
+    debug_color: std140::vec4,
+    intensity: std140::float,
+}
+
+fn main() {
+
+}
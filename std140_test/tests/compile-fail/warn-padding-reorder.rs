@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+extern crate std140;
+
+#[std140::repr_std140(warn_padding)]
+struct Sprite { //~ ERROR: the fields of this #[repr_std140(warn_padding)] struct can be reordered
+    offset: std140::vec2,
+    color: std140::vec4,
+    scale: std140::vec2,
+}
+
+fn main() {
+
+}
@@ -0,0 +1,49 @@
+// The local `std140` module shadows the `std140` crate, so that the generated code only compiles
+// if it uses the path given by the `crate` argument.
+#[allow(dead_code)]
+mod std140 {}
+
+use ::std140 as gl;
+use gl::{std430, AsStd140, Std140Struct};
+
+#[gl::repr_std140(crate = "gl", assert_layout)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PointLight {
+    intensity: gl::float,
+    position: gl::vec3,
+}
+
+#[std430::repr_std430(crate = "gl")]
+#[derive(Clone, Copy)]
+struct Particle {
+    position: gl::vec2,
+    velocity: gl::vec2,
+}
+
+#[gl::derive_std140(crate = "gl")]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Material {
+    color: [f32; 4],
+    roughness: f32,
+}
+
+#[test]
+fn repr_std140_crate_path_test() {
+    assert_eq!(std::mem::size_of::<PointLight>(), 32);
+    assert_eq!(PointLight::std140_layout()[1].offset, 16);
+}
+
+#[test]
+fn repr_std430_crate_path_test() {
+    assert_eq!(std::mem::size_of::<Particle>(), 16);
+}
+
+#[test]
+fn derive_std140_crate_path_test() {
+    let material = Material {
+        color: [1.0, 0.0, 0.0, 1.0],
+        roughness: 0.5,
+    };
+
+    assert_eq!(material.as_std140().roughness, gl::float(0.5));
+}
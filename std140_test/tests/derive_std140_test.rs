@@ -0,0 +1,106 @@
+use std140::{AsStd140, FromStd140, Std140Struct};
+
+#[std140::derive_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PointLight {
+    position: [f32; 3],
+    intensity: f32,
+}
+
+#[std140::derive_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Uniforms {
+    transform: [[f32; 4]; 4],
+    normal_transform: [[f32; 3]; 3],
+    light: PointLight,
+    light_count: u32,
+    shadows: bool,
+    tile: [i32; 2],
+}
+
+#[std140::derive_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct TupleUniforms(f64, [bool; 3]);
+
+fn uniforms() -> Uniforms {
+    Uniforms {
+        transform: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ],
+        normal_transform: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        light: PointLight {
+            position: [10.0, 0.0, 10.0],
+            intensity: 0.5,
+        },
+        light_count: 1,
+        shadows: true,
+        tile: [-1, 2],
+    }
+}
+
+#[test]
+fn derive_std140_as_std140_test() {
+    let std140_uniforms: Std140Uniforms = uniforms().as_std140();
+
+    assert_eq!(
+        std140_uniforms.transform,
+        std140::mat4x4(
+            std140::vec4(1.0, 0.0, 0.0, 0.0),
+            std140::vec4(0.0, 1.0, 0.0, 0.0),
+            std140::vec4(0.0, 0.0, 1.0, 0.0),
+            std140::vec4(1.0, 2.0, 3.0, 1.0),
+        )
+    );
+    assert_eq!(
+        std140_uniforms.light.position,
+        std140::vec3(10.0, 0.0, 10.0)
+    );
+    assert_eq!(std140_uniforms.light.intensity, std140::float(0.5));
+    assert_eq!(std140_uniforms.light_count, std140::uint(1));
+    assert_eq!(std140_uniforms.shadows, std140::boolean::True);
+    assert_eq!(std140_uniforms.tile, std140::ivec2(-1, 2));
+}
+
+#[test]
+fn derive_std140_round_trip_test() {
+    let value = uniforms();
+
+    assert_eq!(Uniforms::from_std140(value.as_std140()), value);
+}
+
+#[test]
+fn derive_std140_tuple_struct_round_trip_test() {
+    let value = TupleUniforms(0.5, [true, false, true]);
+
+    assert_eq!(TupleUniforms::from_std140(value.as_std140()), value);
+}
+
+#[test]
+fn derive_std140_layout_test() {
+    let layout = Std140Uniforms::std140_layout();
+
+    assert_eq!(layout[0].offset, 0);
+    assert_eq!(layout[1].offset, 64);
+    assert_eq!(layout[2].offset, 112);
+    assert_eq!(layout[3].offset, 144);
+}
+
+#[std140::derive_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct WithCfgField {
+    color: [f32; 4],
+    #[cfg(any())]
+    disabled: String,
+}
+
+#[test]
+fn derive_std140_cfg_field_test() {
+    let value = WithCfgField {
+        color: [1.0, 0.0, 0.0, 1.0],
+    };
+
+    assert_eq!(WithCfgField::from_std140(value.as_std140()), value);
+}
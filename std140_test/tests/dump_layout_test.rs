@@ -0,0 +1,62 @@
+#[std140::repr_std140]
+struct PointLight {
+    position: std140::vec3,
+    intensity: std140::float,
+}
+
+#[test]
+fn dump_layout_vec3_test() {
+    let layout = std140::dump_layout::<std140::vec3>();
+
+    println!("vec3: {}", layout);
+
+    assert_eq!(
+        layout,
+        std140::LayoutInfo {
+            size: 12,
+            align: 16,
+            fields: &[],
+        }
+    );
+    assert_eq!(layout.to_string(), "size 12, align 16");
+}
+
+#[test]
+fn dump_layout_mat3x3_test() {
+    let layout = std140::dump_layout::<std140::mat3x3>();
+
+    println!("mat3x3: {}", layout);
+
+    assert_eq!(layout.size, 48);
+    assert_eq!(layout.align, 16);
+    assert!(layout.fields.is_empty());
+    assert_eq!(layout.to_string(), "size 48, align 16");
+}
+
+#[test]
+fn dump_layout_struct_test() {
+    let layout = std140::dump_layout::<PointLight>();
+
+    println!("PointLight: {}", layout);
+
+    assert_eq!(layout.size, 32);
+    assert_eq!(layout.align, 16);
+    assert_eq!(layout.fields.len(), 2);
+    assert_eq!(layout.fields[1].name, "intensity");
+    assert_eq!(layout.fields[1].offset, 16);
+    assert_eq!(
+        layout.to_string(),
+        "size 32, align 16\n\
+         position: offset 0, size 12, align 16\n\
+         intensity: offset 16, size 4, align 4"
+    );
+}
+
+#[test]
+fn dump_layout_array_of_structs_test() {
+    let layout = std140::dump_layout::<std140::array<PointLight, 2>>();
+
+    assert_eq!(layout.size, 64);
+    assert_eq!(layout.align, 16);
+    assert!(layout.fields.is_empty());
+}
@@ -0,0 +1,77 @@
+use std140::{float16, ReprStd140};
+
+#[std140::repr_std140]
+struct HalfData {
+    scale: std140::float16,
+    offset: std140::f16vec2,
+    color: std140::f16vec4,
+    weights: std140::array<std140::float16, 2>,
+}
+
+#[test]
+fn float16_layout_test() {
+    assert_eq!(std::mem::size_of::<std140::float16>(), 2);
+    assert_eq!(std140::float16::STD140_ALIGNMENT, 2);
+    assert_eq!(std140::f16vec2::STD140_ALIGNMENT, 4);
+    assert_eq!(std140::f16vec2::STD140_SIZE, 4);
+    assert_eq!(std140::f16vec3::STD140_ALIGNMENT, 8);
+    assert_eq!(std140::f16vec3::STD140_SIZE, 6);
+    assert_eq!(std140::f16vec4::STD140_ALIGNMENT, 8);
+    assert_eq!(std140::f16vec4::STD140_SIZE, 8);
+}
+
+#[test]
+fn float16_struct_layout_test() {
+    assert_eq!(std::mem::offset_of!(HalfData, scale), 0);
+    assert_eq!(std::mem::offset_of!(HalfData, offset), 4);
+    assert_eq!(std::mem::offset_of!(HalfData, color), 8);
+    assert_eq!(std::mem::offset_of!(HalfData, weights), 16);
+    assert_eq!(std::mem::size_of::<HalfData>(), 48);
+}
+
+#[test]
+fn float16_from_f32_test() {
+    assert_eq!(float16::from_f32(0.0).to_bits(), 0x0000);
+    assert_eq!(float16::from_f32(-0.0).to_bits(), 0x8000);
+    assert_eq!(float16::from_f32(1.0).to_bits(), 0x3c00);
+    assert_eq!(float16::from_f32(-2.0).to_bits(), 0xc000);
+    assert_eq!(float16::from_f32(65504.0).to_bits(), 0x7bff);
+    assert_eq!(float16::from_f32(65536.0).to_bits(), 0x7c00);
+    assert_eq!(float16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+    assert_eq!(float16::from_f32(2.0f32.powi(-24)).to_bits(), 0x0001);
+    assert_eq!(float16::from_f32(2.0f32.powi(-26)).to_bits(), 0x0000);
+}
+
+#[test]
+fn float16_rounding_test() {
+    // Ties round to even.
+    assert_eq!(float16::from_f32(1.0 + 2.0f32.powi(-11)).to_bits(), 0x3c00);
+    assert_eq!(
+        float16::from_f32(1.0 + 3.0 * 2.0f32.powi(-11)).to_bits(),
+        0x3c02
+    );
+    // Anything above the tie rounds up.
+    assert_eq!(
+        float16::from_f32(1.0 + 2.0f32.powi(-11) + 2.0f32.powi(-20)).to_bits(),
+        0x3c01
+    );
+}
+
+#[test]
+fn float16_to_f32_test() {
+    assert_eq!(float16::from_bits(0x3800).to_f32(), 0.5);
+    assert_eq!(float16::from_bits(0x7bff).to_f32(), 65504.0);
+    assert_eq!(float16::from_bits(0x0001).to_f32(), 2.0f32.powi(-24));
+    assert_eq!(float16::from_bits(0x0200).to_f32(), 2.0f32.powi(-15));
+    assert_eq!(float16::from_bits(0x7c00).to_f32(), f32::INFINITY);
+    assert!(float16::from_bits(0x7e00).to_f32().is_nan());
+    assert!(float16::from_f32(f32::NAN).to_f32().is_nan());
+}
+
+#[test]
+fn f16vec_conversion_test() {
+    let value = std140::f16vec3::from(std140::vec3(0.5, -1.0, 2.0));
+
+    assert_eq!(value.1, float16::from_f32(-1.0));
+    assert_eq!(std140::vec3::from(value), std140::vec3(0.5, -1.0, 2.0));
+}
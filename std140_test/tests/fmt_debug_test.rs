@@ -9,6 +9,24 @@ fn fmt_array_test() {
     );
 }
 
+#[test]
+fn fmt_array_alternate_test() {
+    assert_eq!(
+        format!(
+            "{:#?}",
+            std140::array![std140::vec2(1.0, 0.0), std140::vec2(0.0, 1.0),]
+        ),
+        "[\n    [0] => vec2(\n        1.0,\n        0.0,\n    ),\n    [1] => vec2(\n        0.0,\n        1.0,\n    ),\n]"
+    );
+    assert_eq!(
+        format!(
+            "{:#?}",
+            std140::array![std140::float(0.0), std140::float(1.0), std140::float(2.0),]
+        ),
+        "[\n    [0] => float(\n        0.0,\n    ),\n    [1] => float(\n        1.0,\n    ),\n    [2] => float(\n        2.0,\n    ),\n]"
+    );
+}
+
 #[test]
 fn fmt_mat2x2_test() {
     assert_eq!(
@@ -125,3 +143,48 @@ fn fmt_mat4x4_test() {
         std140::vec4(0.0, 0.0, 0.0, 1.0),
     )), "mat4x4[vec4(1.0, 0.0, 0.0, 0.0), vec4(0.0, 1.0, 0.0, 0.0), vec4(0.0, 0.0, 1.0, 0.0), vec4(0.0, 0.0, 0.0, 1.0)]");
 }
+
+#[test]
+fn fmt_ivec_test() {
+    assert_eq!(format!("{:?}", std140::ivec2(1, -2)), "ivec2(1, -2)");
+    assert_eq!(format!("{:?}", std140::ivec3(1, 0, 0)), "ivec3(1, 0, 0)");
+    assert_eq!(
+        format!("{:?}", std140::ivec4(1, 2, 3, -4)),
+        "ivec4(1, 2, 3, -4)"
+    );
+}
+
+#[test]
+fn fmt_uvec_test() {
+    assert_eq!(format!("{:?}", std140::uvec2(1, 2)), "uvec2(1, 2)");
+    assert_eq!(format!("{:?}", std140::uvec3(1, 2, 3)), "uvec3(1, 2, 3)");
+    assert_eq!(
+        format!("{:?}", std140::uvec4(1, 2, 3, u32::MAX)),
+        "uvec4(1, 2, 3, 4294967295)"
+    );
+}
+
+#[test]
+fn fmt_bvec_test() {
+    use std140::boolean::{False, True};
+
+    assert_eq!(
+        format!("{:?}", std140::bvec2(True, False)),
+        "bvec2(True, False)"
+    );
+    assert_eq!(
+        format!("{:?}", std140::bvec3(True, False, True)),
+        "bvec3(True, False, True)"
+    );
+    assert_eq!(
+        format!("{:?}", std140::bvec4(False, False, True, True)),
+        "bvec4(False, False, True, True)"
+    );
+}
+
+#[test]
+fn fmt_integer_and_boolean_scalar_test() {
+    assert_eq!(format!("{:?}", std140::int(-1)), "int(-1)");
+    assert_eq!(format!("{:?}", std140::uint(1)), "uint(1)");
+    assert_eq!(format!("{:?}", std140::boolean::True), "True");
+}
@@ -0,0 +1,54 @@
+#[test]
+fn display_vec3_test() {
+    assert_eq!(format!("{}", std140::vec3(1.0, 0.5, -2.0)), "(1, 0.5, -2)");
+}
+
+#[test]
+fn display_vector_precision_test() {
+    assert_eq!(
+        format!("{:.2}", std140::dvec2(1.0, 1.0 / 3.0)),
+        "(1.00, 0.33)"
+    );
+}
+
+#[test]
+fn display_integer_vector_test() {
+    assert_eq!(format!("{}", std140::ivec2(-1, 20)), "(-1, 20)");
+    assert_eq!(format!("{}", std140::uvec4(1, 2, 3, 4)), "(1, 2, 3, 4)");
+}
+
+#[test]
+fn display_bvec2_test() {
+    assert_eq!(
+        format!(
+            "{}",
+            std140::bvec2(std140::boolean::True, std140::boolean::False)
+        ),
+        "(true, false)"
+    );
+}
+
+#[test]
+fn display_mat2x3_test() {
+    let matrix = std140::mat2x3(std140::vec3(1.0, 20.0, -3.0), std140::vec3(4.5, 5.0, 6.0));
+
+    assert_eq!(format!("{}", matrix), "[ 1, 4.5]\n[20,   5]\n[-3,   6]");
+}
+
+#[test]
+fn display_mat4x4_precision_test() {
+    let matrix = std140::mat4x4(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(10.0, -2.5, 0.0, 1.0),
+    );
+
+    assert_eq!(
+        format!("{:.1}", matrix),
+        "[1.0, 0.0, 0.0, 10.0]\n\
+         [0.0, 1.0, 0.0, -2.5]\n\
+         [0.0, 0.0, 1.0,  0.0]\n\
+         [0.0, 0.0, 0.0,  1.0]"
+    );
+}
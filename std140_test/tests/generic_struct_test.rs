@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use std::mem;
+
+use std140::Std140Struct;
+
+#[std140::repr_std140]
+struct Material<T: std140::Std140ArrayElement> {
+    tint: std140::vec4,
+    extra: T,
+}
+
+#[std140::repr_std140]
+struct Lights<T, const N: usize>
+where
+    T: std140::Std140ArrayElement,
+{
+    count: std140::uint,
+    lights: std140::array<T, N>,
+}
+
+#[test]
+fn generic_struct_layout_test() {
+    assert_eq!(mem::size_of::<Material<std140::float>>(), 32);
+    assert_eq!(mem::size_of::<Material<std140::mat2x2>>(), 48);
+    assert_eq!(
+        Material::<std140::float>::flat_field_paths(),
+        vec![("tint".to_string(), 0, 16), ("extra".to_string(), 16, 4)]
+    );
+}
+
+#[test]
+fn const_generic_struct_layout_test() {
+    assert_eq!(mem::size_of::<Lights<std140::vec2, 3>>(), 64);
+    assert_eq!(mem::offset_of!(Lights<std140::vec2, 3>, lights), 16);
+    assert_eq!(Lights::<std140::vec2, 3>::std140_layout()[1].size, 48);
+    assert_eq!(
+        std140::std140_offset_of!(Lights<Material<std140::float>, 2>, lights[1].extra),
+        64
+    );
+}
+
+#[test]
+fn generic_layout_description_test() {
+    assert_eq!(
+        Material::<std140::mat2x2>::LAYOUT_DESCRIPTION,
+        "tint: std140::vec4 (offset 0, size 16)\nextra: T (offset 16, size 32)"
+    );
+}
@@ -0,0 +1,33 @@
+#[test]
+fn mat4x3_to_mat4_padding_test() {
+    let matrix = std140::mat4x3(
+        std140::vec3(1.0, 2.0, 3.0),
+        std140::vec3(4.0, 5.0, 6.0),
+        std140::vec3(7.0, 8.0, 9.0),
+        std140::vec3(10.0, 11.0, 12.0),
+    );
+
+    let mat4 = matrix.to_mat4();
+
+    assert_eq!(mat4.x_axis, glam::Vec4::new(1.0, 2.0, 3.0, 0.0));
+    assert_eq!(mat4.y_axis, glam::Vec4::new(4.0, 5.0, 6.0, 0.0));
+    assert_eq!(mat4.z_axis, glam::Vec4::new(7.0, 8.0, 9.0, 0.0));
+    assert_eq!(mat4.w_axis, glam::Vec4::new(10.0, 11.0, 12.0, 1.0));
+    assert_eq!(mat4.row(3), glam::Vec4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn mat3x4_to_mat4_padding_test() {
+    let matrix = std140::mat3x4(
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+        std140::vec4(9.0, 10.0, 11.0, 12.0),
+    );
+
+    let mat4 = matrix.to_mat4();
+
+    assert_eq!(mat4.x_axis, glam::Vec4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(mat4.y_axis, glam::Vec4::new(5.0, 6.0, 7.0, 8.0));
+    assert_eq!(mat4.z_axis, glam::Vec4::new(9.0, 10.0, 11.0, 12.0));
+    assert_eq!(mat4.w_axis, glam::Vec4::new(0.0, 0.0, 0.0, 1.0));
+}
@@ -0,0 +1,167 @@
+use std140::{RowMajor, Std140Struct};
+
+#[std140::repr_std140]
+struct Scalars {
+    a: std140::float,
+    b: std140::int,
+    c: std140::uint,
+    d: std140::boolean,
+    e: std140::double,
+}
+
+#[std140::repr_std140]
+struct Vectors {
+    a: std140::vec2,
+    b: std140::ivec3,
+    c: std140::uvec4,
+    d: std140::bvec2,
+    e: std140::dvec3,
+}
+
+#[std140::repr_std140]
+struct Matrices {
+    a: std140::mat4x4,
+    b: std140::mat2x3,
+    c: std140::dmat3x3,
+    d: std140::dmat4x2,
+    e: RowMajor<std140::mat3x4>,
+}
+
+#[std140::repr_std140]
+struct Material {
+    color: std140::vec4,
+    roughness: std140::float,
+}
+
+#[std140::repr_std140]
+struct PointLight {
+    position: std140::vec3,
+    material: Material,
+}
+
+#[std140::repr_std140]
+struct Scene {
+    lights: std140::array<PointLight, 4>,
+    materials: std140::array<Material, 2>,
+    weights: std140::array<std140::array<std140::float, 3>, 2>,
+    #[cfg(any())]
+    disabled: std140::float,
+}
+
+#[std140::repr_std140]
+struct Range(std140::float, std140::float);
+
+#[test]
+fn glsl_scalar_names_test() {
+    assert_eq!(
+        Scalars::glsl_declaration(),
+        "layout(std140) uniform Scalars {
+    float a;
+    int b;
+    uint c;
+    bool d;
+    double e;
+};"
+    );
+}
+
+#[test]
+fn glsl_vector_names_test() {
+    assert_eq!(
+        Vectors::glsl_declaration(),
+        "layout(std140) uniform Vectors {
+    vec2 a;
+    ivec3 b;
+    uvec4 c;
+    bvec2 d;
+    dvec3 e;
+};"
+    );
+}
+
+#[test]
+fn glsl_matrix_names_test() {
+    assert_eq!(
+        Matrices::glsl_declaration(),
+        "layout(std140) uniform Matrices {
+    mat4 a;
+    mat2x3 b;
+    dmat3 c;
+    dmat4x2 d;
+    mat3x4 e;
+};"
+    );
+}
+
+#[test]
+fn glsl_nested_structs_test() {
+    assert_eq!(
+        Scene::glsl_declaration(),
+        "struct Material {
+    vec4 color;
+    float roughness;
+};
+
+struct PointLight {
+    vec3 position;
+    Material material;
+};
+
+layout(std140) uniform Scene {
+    PointLight lights[4];
+    Material materials[2];
+    float weights[2][3];
+};"
+    );
+}
+
+#[test]
+fn glsl_tuple_struct_test() {
+    assert_eq!(
+        Range::glsl_declaration(),
+        "layout(std140) uniform Range {
+    float _0;
+    float _1;
+};"
+    );
+}
+
+#[std140::repr_std140]
+struct Projection {
+    matrix: RowMajor<std140::mat2x3>,
+}
+
+#[test]
+fn glsl_row_major_matches_bytes_test() {
+    use std140::AsBytes;
+
+    assert_eq!(
+        Projection::glsl_declaration(),
+        "layout(std140) uniform Projection {
+    mat2x3 matrix;
+};"
+    );
+
+    let projection = Projection {
+        matrix: RowMajor::<std140::mat2x3>::new(
+            std140::vec2(1.0, 2.0),
+            std140::vec2(3.0, 4.0),
+            std140::vec2(5.0, 6.0),
+        ),
+    };
+
+    let bytes = projection.to_bytes();
+
+    // A (column-major) `mat2x3` is 2 `vec3` columns at a stride of 16 bytes.
+    assert_eq!(bytes.len(), 32);
+
+    let column = |index: usize| -> Vec<f32> {
+        bytes[index * 16..index * 16 + 12]
+            .chunks(4)
+            .map(|chunk| f32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    };
+
+    assert_eq!(column(0), [1.0, 3.0, 5.0]);
+    assert_eq!(column(1), [2.0, 4.0, 6.0]);
+}
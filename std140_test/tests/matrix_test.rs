@@ -0,0 +1,494 @@
+#[test]
+fn matrix_from_columns_array_test() {
+    let columns = [
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(0.0, 0.0, 0.0, 1.0),
+    ];
+
+    let matrix = std140::mat4x4::from(columns);
+
+    assert_eq!(
+        matrix,
+        std140::mat4x4(columns[0], columns[1], columns[2], columns[3])
+    );
+    assert_eq!(<[std140::vec4; 4]>::from(matrix), columns);
+}
+
+#[test]
+fn matrix_columns_test() {
+    let matrix = std140::dmat3x2(
+        std140::dvec2(1.0, 2.0),
+        std140::dvec2(3.0, 4.0),
+        std140::dvec2(5.0, 6.0),
+    );
+
+    assert_eq!(
+        matrix.columns(),
+        &std140::array![
+            std140::dvec2(1.0, 2.0),
+            std140::dvec2(3.0, 4.0),
+            std140::dvec2(5.0, 6.0),
+        ]
+    );
+}
+
+#[test]
+fn mat3x3_from_nested_array_test() {
+    let matrix = std140::mat3x3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    assert_eq!(
+        matrix,
+        std140::mat3x3(
+            std140::vec3(1.0, 2.0, 3.0),
+            std140::vec3(4.0, 5.0, 6.0),
+            std140::vec3(7.0, 8.0, 9.0),
+        )
+    );
+}
+
+#[test]
+fn mat3x3_into_nested_array_excludes_padding_test() {
+    use std140::AsBytes;
+
+    let mut bytes = std140::mat3x3(
+        std140::vec3(1.0, 2.0, 3.0),
+        std140::vec3(4.0, 5.0, 6.0),
+        std140::vec3(7.0, 8.0, 9.0),
+    )
+    .to_bytes();
+
+    // Fill the padding float that follows each column with a sentinel value.
+    for column in 0..3 {
+        let offset = column * 16 + 12;
+
+        bytes[offset..offset + 4].copy_from_slice(&(-1.0f32).to_ne_bytes());
+    }
+
+    let matrix = std140::mat3x3::read_from_bytes(&bytes).unwrap();
+    let value: [[f32; 3]; 3] = matrix.into();
+
+    assert_eq!(value, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+}
+
+#[test]
+fn mat4x2_nested_array_round_trip_test() {
+    let value = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]];
+    let matrix: std140::mat4x2 = value.into();
+
+    assert_eq!(<[[f32; 2]; 4]>::from(matrix), value);
+}
+
+fn mat2x3() -> std140::mat2x3 {
+    std140::mat2x3(std140::vec3(1.0, 2.0, 3.0), std140::vec3(4.0, 5.0, 6.0))
+}
+
+#[test]
+fn matrix_col_test() {
+    let mut matrix = mat2x3();
+
+    assert_eq!(matrix.col(1), std140::vec3(4.0, 5.0, 6.0));
+
+    *matrix.column_mut(0) = std140::vec3(7.0, 8.0, 9.0);
+
+    assert_eq!(matrix.col(0), std140::vec3(7.0, 8.0, 9.0));
+}
+
+#[test]
+fn matrix_column_test() {
+    let mut matrix = mat2x3();
+
+    assert_eq!(matrix.column(0), &std140::vec3(1.0, 2.0, 3.0));
+    assert_eq!(matrix.column(1), &matrix.col(1));
+
+    matrix.column_mut(1).2 = 10.0;
+
+    assert_eq!(matrix.column(1), &std140::vec3(4.0, 5.0, 10.0));
+
+    let mut matrix = std140::dmat4x4::zero();
+
+    *matrix.column_mut(3) = std140::dvec4(1.0, 2.0, 3.0, 1.0);
+
+    assert_eq!(matrix.column(3), &std140::dvec4(1.0, 2.0, 3.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn matrix_column_out_of_bounds_test() {
+    let _ = mat2x3().column(std::hint::black_box(2));
+}
+
+#[test]
+fn matrix_row_test() {
+    let matrix = mat2x3();
+
+    assert_eq!(matrix.row(0), std140::vec2(1.0, 4.0));
+    assert_eq!(matrix.row(2), std140::vec2(3.0, 6.0));
+}
+
+#[test]
+#[should_panic]
+fn matrix_row_out_of_bounds_test() {
+    let _ = mat2x3().row(3);
+}
+
+#[test]
+fn matrix_transpose_test() {
+    assert_eq!(
+        mat2x3().transpose(),
+        std140::mat3x2(
+            std140::vec2(1.0, 4.0),
+            std140::vec2(2.0, 5.0),
+            std140::vec2(3.0, 6.0),
+        )
+    );
+}
+
+#[test]
+fn matrix_transpose_twice_test() {
+    let mat4x4 = std140::mat4x4::from([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    let dmat3x4 = std140::dmat3x4::from([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+    ]);
+
+    assert_eq!(mat4x4.transpose().transpose(), mat4x4);
+    assert_eq!(mat2x3().transpose().transpose(), mat2x3());
+    assert_eq!(dmat3x4.transpose().transpose(), dmat3x4);
+    assert_eq!(dmat3x4.transpose().col(3), std140::dvec3(4.0, 8.0, 12.0));
+}
+
+const IDENTITY: std140::mat4x4 = std140::mat4x4(
+    std140::vec4(1.0, 0.0, 0.0, 0.0),
+    std140::vec4(0.0, 1.0, 0.0, 0.0),
+    std140::vec4(0.0, 0.0, 1.0, 0.0),
+    std140::vec4(0.0, 0.0, 0.0, 1.0),
+);
+
+const UP: std140::vec3 = std140::vec3(0.0, 1.0, 0.0);
+
+const ZERO: std140::dmat2x3 = std140::dmat2x3::zero();
+
+#[test]
+fn const_constructors_test() {
+    assert_eq!(IDENTITY.col(2), std140::vec4(0.0, 0.0, 1.0, 0.0));
+    assert_eq!(IDENTITY.row(3), std140::vec4(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(UP, std140::vec3(0.0, 1.0, 0.0));
+    assert_eq!(
+        ZERO,
+        std140::dmat2x3(std140::dvec3::zero(), std140::dvec3::zero())
+    );
+}
+
+#[test]
+fn mat2x3_from_row_major_test() {
+    // 3 rows, 2 columns.
+    let matrix = std140::mat2x3::from_row_major([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+    assert_eq!(
+        matrix,
+        std140::mat2x3(std140::vec3(1.0, 3.0, 5.0), std140::vec3(2.0, 4.0, 6.0))
+    );
+    assert_eq!(matrix.row(1), std140::vec2(3.0, 4.0));
+}
+
+#[test]
+fn mat4x4_row_major_round_trip_test() {
+    let rows = [
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ];
+
+    let matrix = std140::mat4x4::from_row_major(rows);
+
+    assert_eq!(matrix.col(3), std140::vec4(4.0, 8.0, 12.0, 16.0));
+    assert_eq!(matrix.to_row_major(), rows);
+    assert_ne!(<[[f32; 4]; 4]>::from(matrix), rows);
+}
+
+#[test]
+fn dmat3x2_to_row_major_test() {
+    let matrix = std140::dmat3x2(
+        std140::dvec2(1.0, 2.0),
+        std140::dvec2(3.0, 4.0),
+        std140::dvec2(5.0, 6.0),
+    );
+
+    assert_eq!(matrix.to_row_major(), [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+}
+
+#[test]
+fn matrix_alias_test() {
+    let matrix: std140::Matrix<std140::dvec3, 4> = std140::dmat4x3::zero();
+
+    assert_eq!(matrix, std140::dmat4x3::default());
+    assert_eq!(matrix.col(3), std140::dvec3::zero());
+    assert_eq!(
+        format!("{:?}", std140::Matrix::<std140::vec2, 3>::zero()),
+        "mat3x2[vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0)]"
+    );
+}
+
+#[test]
+fn mat4x4_identity_test() {
+    assert_eq!(std140::mat4x4::identity(), IDENTITY);
+}
+
+#[test]
+fn mat4x4_translation_test() {
+    let transform = std140::mat4x4::translation(std140::vec3(1.0, 2.0, 3.0));
+
+    assert_eq!(transform.col(0), std140::vec4(1.0, 0.0, 0.0, 0.0));
+    assert_eq!(transform.col(3), std140::vec4(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(transform.row(0), std140::vec4(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(transform.to_row_major()[2], [0.0, 0.0, 1.0, 3.0]);
+}
+
+#[test]
+fn mat4x4_scale_test() {
+    assert_eq!(
+        std140::mat4x4::scale(std140::vec3(2.0, 3.0, 4.0)),
+        std140::mat4x4::from_diagonal(std140::vec4(2.0, 3.0, 4.0, 1.0))
+    );
+    assert_eq!(
+        std140::mat4x4::scale(std140::vec3(2.0, 3.0, 4.0)).col(1),
+        std140::vec4(0.0, 3.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn mat4x4_from_columns_test() {
+    let columns = [
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+        std140::vec4(9.0, 10.0, 11.0, 12.0),
+        std140::vec4(13.0, 14.0, 15.0, 16.0),
+    ];
+
+    let value = std140::mat4x4::from_columns(columns);
+
+    assert_eq!(
+        value,
+        std140::mat4x4(columns[0], columns[1], columns[2], columns[3])
+    );
+    assert_eq!(value.into_columns(), columns);
+}
+
+#[test]
+fn dmat2x3_into_columns_test() {
+    let value = std140::dmat2x3(std140::dvec3(1.0, 2.0, 3.0), std140::dvec3(4.0, 5.0, 6.0));
+
+    let columns: [std140::dvec3; 2] = value.into_columns();
+
+    assert_eq!(
+        columns,
+        [std140::dvec3(1.0, 2.0, 3.0), std140::dvec3(4.0, 5.0, 6.0)]
+    );
+    assert_eq!(std140::dmat2x3::from_columns(columns), value);
+}
+
+#[std140::repr_std140(assert_layout)]
+struct Skin {
+    bone_count: std140::uint,
+    bones: std140::array<std140::mat4x4, 8>,
+    normals: std140::array<std140::mat3x3, 4>,
+    uv_transforms: std140::array<std140::mat2x2, 3>,
+    weight: std140::float,
+}
+
+#[test]
+fn matrix_array_size_test() {
+    use std::mem::size_of;
+
+    // Each column of a matrix in an array occupies 16 bytes.
+    assert_eq!(size_of::<std140::array<std140::mat4x4, 8>>(), 4 * 16 * 8);
+    assert_eq!(size_of::<std140::array<std140::mat3x3, 4>>(), 3 * 16 * 4);
+    assert_eq!(size_of::<std140::array<std140::mat2x2, 3>>(), 2 * 16 * 3);
+    assert_eq!(size_of::<std140::array<std140::mat2x4, 2>>(), 2 * 16 * 2);
+    assert_eq!(size_of::<std140::array<std140::mat4x2, 2>>(), 4 * 16 * 2);
+    assert_eq!(size_of::<std140::array<std140::dmat3x3, 2>>(), 3 * 32 * 2);
+}
+
+#[test]
+fn matrix_array_offsets_test() {
+    assert_eq!(std140::std140_offset_of!(Skin, bones), 16);
+    assert_eq!(std140::std140_offset_of!(Skin, bones[7]), 16 + 7 * 64);
+    assert_eq!(std140::std140_offset_of!(Skin, normals), 528);
+    assert_eq!(std140::std140_offset_of!(Skin, normals[3]), 528 + 3 * 48);
+    assert_eq!(std140::std140_offset_of!(Skin, uv_transforms), 720);
+    assert_eq!(
+        std140::std140_offset_of!(Skin, uv_transforms[2]),
+        720 + 2 * 32
+    );
+    assert_eq!(std140::std140_offset_of!(Skin, weight), 816);
+    assert_eq!(std::mem::size_of::<Skin>(), 832);
+}
+
+#[test]
+fn matrix_array_element_bytes_test() {
+    use std140::AsBytes;
+
+    let mut normals = std140::array![std140::mat3x3::zero(); 2];
+
+    *normals.get_mut(1).unwrap() = std140::mat3x3(
+        std140::vec3(1.0, 2.0, 3.0),
+        std140::vec3(4.0, 5.0, 6.0),
+        std140::vec3(7.0, 8.0, 9.0),
+    );
+
+    let bytes = normals.to_bytes();
+
+    // The 2nd matrix starts at byte 48; its 2nd column starts 16 bytes later.
+    assert_eq!(&bytes[48..52], &1.0f32.to_ne_bytes());
+    assert_eq!(&bytes[64..68], &4.0f32.to_ne_bytes());
+    assert_eq!(&bytes[88..92], &9.0f32.to_ne_bytes());
+}
+
+#[test]
+fn mat4x4_mul_vec4_test() {
+    let translation = std140::mat4x4(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(1.0, 2.0, 3.0, 1.0),
+    );
+
+    assert_eq!(
+        translation * std140::vec4(1.0, 1.0, 1.0, 1.0),
+        std140::vec4(2.0, 3.0, 4.0, 1.0)
+    );
+}
+
+#[test]
+fn mat2x2_mul_mat2x2_test() {
+    // Column-major: the first matrix is [[1, 3], [2, 4]] in row notation.
+    let a = std140::mat2x2(std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0));
+    let b = std140::mat2x2(std140::vec2(5.0, 6.0), std140::vec2(7.0, 8.0));
+
+    assert_eq!(
+        a * b,
+        std140::mat2x2(std140::vec2(23.0, 34.0), std140::vec2(31.0, 46.0))
+    );
+}
+
+#[test]
+fn mat4x4_mul_identity_test() {
+    let identity = std140::mat4x4(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(0.0, 0.0, 0.0, 1.0),
+    );
+    let value = std140::mat4x4(
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+        std140::vec4(9.0, 10.0, 11.0, 12.0),
+        std140::vec4(13.0, 14.0, 15.0, 16.0),
+    );
+
+    assert_eq!(identity * value, value);
+    assert_eq!(value * identity, value);
+}
+
+#[test]
+fn mat2x3_mul_mat3x2_test() {
+    // 3 rows, 2 columns: [[1, 4], [2, 5], [3, 6]] in row notation.
+    let a = mat2x3();
+    // 2 rows, 3 columns: [[1, 0, 1], [0, 1, 1]] in row notation.
+    let b = std140::mat3x2(
+        std140::vec2(1.0, 0.0),
+        std140::vec2(0.0, 1.0),
+        std140::vec2(1.0, 1.0),
+    );
+
+    let product: std140::mat3x3 = a * b;
+
+    assert_eq!(
+        product,
+        std140::mat3x3(
+            std140::vec3(1.0, 2.0, 3.0),
+            std140::vec3(4.0, 5.0, 6.0),
+            std140::vec3(5.0, 7.0, 9.0),
+        )
+    );
+    assert_eq!(a * std140::vec2(1.0, 1.0), std140::vec3(5.0, 7.0, 9.0));
+}
+
+#[test]
+fn dmat3x3_mul_dvec3_test() {
+    let scale = std140::dmat3x3(
+        std140::dvec3(2.0, 0.0, 0.0),
+        std140::dvec3(0.0, 3.0, 0.0),
+        std140::dvec3(0.0, 0.0, 4.0),
+    );
+
+    assert_eq!(
+        scale * std140::dvec3(1.0, 1.0, 1.0),
+        std140::dvec3(2.0, 3.0, 4.0)
+    );
+}
+
+fn rotation_z() -> std140::mat3x3 {
+    std140::mat3x3(
+        std140::vec3(0.0, 1.0, 0.0),
+        std140::vec3(-1.0, 0.0, 0.0),
+        std140::vec3(0.0, 0.0, 1.0),
+    )
+}
+
+#[test]
+fn mat4x4_from_mat3_test() {
+    assert_eq!(
+        std140::mat4x4::from_mat3(rotation_z()),
+        std140::mat4x4(
+            std140::vec4(0.0, 1.0, 0.0, 0.0),
+            std140::vec4(-1.0, 0.0, 0.0, 0.0),
+            std140::vec4(0.0, 0.0, 1.0, 0.0),
+            std140::vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    );
+}
+
+#[test]
+fn mat4x4_to_mat3_test() {
+    let value = std140::mat4x4(
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+        std140::vec4(9.0, 10.0, 11.0, 12.0),
+        std140::vec4(13.0, 14.0, 15.0, 16.0),
+    );
+
+    assert_eq!(
+        value.to_mat3(),
+        std140::mat3x3(
+            std140::vec3(1.0, 2.0, 3.0),
+            std140::vec3(5.0, 6.0, 7.0),
+            std140::vec3(9.0, 10.0, 11.0),
+        )
+    );
+}
+
+#[test]
+fn mat3_promotion_round_trip_test() {
+    assert_eq!(
+        std140::mat4x4::from_mat3(rotation_z()).to_mat3(),
+        rotation_z()
+    );
+
+    let scale = std140::dmat3x3(
+        std140::dvec3(2.0, 0.0, 0.0),
+        std140::dvec3(0.0, 3.0, 0.0),
+        std140::dvec3(0.0, 0.0, 4.0),
+    );
+
+    assert_eq!(std140::dmat4x4::from_mat3(scale).to_mat3(), scale);
+}
@@ -0,0 +1,68 @@
+#[test]
+fn vec3_mint_round_trip_test() {
+    let value: mint::Vector3<f32> = std140::vec3(1.0, 2.0, 3.0).into();
+
+    assert_eq!(
+        value,
+        mint::Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    assert_eq!(std140::vec3::from(value), std140::vec3(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn uvec2_mint_round_trip_test() {
+    let value: mint::Vector2<u32> = std140::uvec2(1, 2).into();
+
+    assert_eq!(value, mint::Vector2 { x: 1, y: 2 });
+    assert_eq!(std140::uvec2::from(value), std140::uvec2(1, 2));
+}
+
+#[test]
+fn mat4x4_mint_round_trip_test() {
+    let matrix = std140::mat4x4(
+        std140::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec4(5.0, 6.0, 7.0, 8.0),
+        std140::vec4(9.0, 10.0, 11.0, 12.0),
+        std140::vec4(13.0, 14.0, 15.0, 16.0),
+    );
+
+    let value: mint::ColumnMatrix4<f32> = matrix.into();
+
+    assert_eq!(
+        value.x,
+        mint::Vector4 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 4.0
+        }
+    );
+    assert_eq!(
+        value.w,
+        mint::Vector4 {
+            x: 13.0,
+            y: 14.0,
+            z: 15.0,
+            w: 16.0
+        }
+    );
+    assert_eq!(std140::mat4x4::from(value), matrix);
+}
+
+#[test]
+fn mat3x2_mint_round_trip_test() {
+    let matrix = std140::mat3x2(
+        std140::vec2(1.0, 2.0),
+        std140::vec2(3.0, 4.0),
+        std140::vec2(5.0, 6.0),
+    );
+
+    let value: mint::ColumnMatrix2x3<f32> = matrix.into();
+
+    assert_eq!(value.z, mint::Vector2 { x: 5.0, y: 6.0 });
+    assert_eq!(std140::mat3x2::from(value), matrix);
+}
@@ -0,0 +1,37 @@
+use std140::prelude::*;
+
+#[repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Uniforms {
+    transform: mat4x4,
+    tint: vec4,
+    offsets: array<vec2, 2>,
+    count: uint,
+    enabled: boolean,
+}
+
+#[derive_std140]
+struct Material {
+    color: [f32; 3],
+}
+
+#[test]
+fn prelude_test() {
+    let uniforms = Uniforms {
+        transform: mat4x4::identity(),
+        tint: vec4(1.0, 1.0, 1.0, 1.0),
+        offsets: sized_array![2; vec2(0.0, 0.0), vec2(1.0, 1.0)],
+        count: uint(2),
+        enabled: boolean::True,
+    };
+
+    assert_eq!(uniforms.to_bytes().len(), 128);
+    assert_eq!(
+        Material {
+            color: [1.0, 0.0, 0.0]
+        }
+        .as_std140()
+        .color,
+        vec3(1.0, 0.0, 0.0)
+    );
+}
@@ -1,4 +1,9 @@
+#![allow(dead_code)]
+
+use std140::Std140Struct;
+
 #[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
 struct PointLight {
     position: std140::vec3,
     // Test repeating the same type twice
@@ -7,10 +12,295 @@ struct PointLight {
 }
 
 #[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
 struct Uniforms {
     transform: std140::mat4x4,
     ambient_light_color: std140::vec3,
     lights: std140::array<PointLight, 2>,
 }
 
+#[std140::repr_std140(assert_layout)]
+struct AssertedLayout {
+    intensity: std140::float,
+    position: std140::vec3,
+    color: std140::vec4,
+    transform: std140::mat3x3,
+    lights: std140::array<PointLight, 2>,
+    flags: std140::uvec2,
+    range: std140::vec2,
+}
+
 fn main() {}
+
+#[test]
+fn flat_field_paths_test() {
+    let paths = Uniforms::flat_field_paths();
+
+    assert_eq!(paths.len(), 8);
+    assert_eq!(paths[0], ("transform".to_string(), 0, 64));
+    assert_eq!(paths[1], ("ambient_light_color".to_string(), 64, 16));
+    assert_eq!(paths[2], ("lights[0].position".to_string(), 80, 16));
+    assert_eq!(paths[3], ("lights[0].intensity".to_string(), 96, 4));
+    assert_eq!(paths[7], ("lights[1].falloff".to_string(), 132, 4));
+}
+
+#[test]
+fn std140_layout_test() {
+    let layout = Uniforms::std140_layout();
+
+    assert_eq!(layout.len(), 3);
+    assert_eq!(
+        layout[1],
+        std140::FieldLayout {
+            name: "ambient_light_color",
+            offset: 64,
+            size: 12,
+            align: 16,
+        }
+    );
+    assert_eq!(
+        layout[2],
+        std140::FieldLayout {
+            name: "lights",
+            offset: 80,
+            size: 64,
+            align: 16,
+        }
+    );
+}
+
+#[test]
+fn field_names_test() {
+    assert_eq!(
+        Uniforms::FIELD_NAMES,
+        ["transform", "ambient_light_color", "lights"]
+    );
+    assert_eq!(Uniforms::FIELD_COUNT, 3);
+    assert_eq!(TupleUniforms::FIELD_NAMES, ["0", "1", "2"]);
+    assert_eq!(TupleUniforms::FIELD_COUNT, 3);
+}
+
+#[test]
+fn field_range_test() {
+    assert_eq!(Uniforms::field_range("transform"), Some(0..64));
+    assert_eq!(Uniforms::field_range("ambient_light_color"), Some(64..76));
+    assert_eq!(Uniforms::field_range("lights"), Some(80..144));
+    assert_eq!(Uniforms::field_range("missing"), None);
+
+    let uniforms = Uniforms {
+        ambient_light_color: std140::vec3(0.1, 0.2, 0.3),
+        ..Default::default()
+    };
+
+    let range = Uniforms::field_range("ambient_light_color").unwrap();
+
+    assert_eq!(
+        &std140::AsBytes::to_bytes(&uniforms)[range],
+        &std140::AsBytes::to_bytes(&uniforms.ambient_light_color)[..12]
+    );
+}
+
+#[test]
+fn derive_default_test() {
+    let uniforms = Uniforms::default();
+
+    assert_eq!(uniforms.transform, std140::mat4x4::zero());
+    assert_eq!(uniforms.ambient_light_color, std140::vec3::zero());
+    assert_eq!(
+        uniforms.lights,
+        std140::array![
+            PointLight {
+                position: std140::vec3::zero(),
+                intensity: std140::float(0.0),
+                falloff: std140::float(0.0),
+            };
+            2
+        ]
+    );
+}
+
+#[test]
+fn default_test() {
+    assert_eq!(std140::int::default(), std140::int(0));
+    assert_eq!(std140::boolean::default(), std140::boolean::False);
+    assert_eq!(
+        std140::bvec2::default(),
+        std140::bvec2(std140::boolean::False, std140::boolean::False)
+    );
+    assert_eq!(std140::dmat2x3::default(), std140::dmat2x3::zero());
+    assert_eq!(
+        std140::array::<std140::uint, 3>::default(),
+        std140::array![std140::uint(0); 3]
+    );
+}
+
+const LIGHTS_INTENSITY_OFFSET: usize = std140::std140_offset_of!(Uniforms, lights[1].intensity);
+
+const _: () = assert!(LIGHTS_INTENSITY_OFFSET == 128);
+
+#[test]
+fn std140_offset_of_test() {
+    assert_eq!(std140::std140_offset_of!(Uniforms, transform), 0);
+    assert_eq!(std140::std140_offset_of!(Uniforms, ambient_light_color), 64);
+    assert_eq!(std140::std140_offset_of!(Uniforms, lights), 80);
+    assert_eq!(std140::std140_offset_of!(Uniforms, lights[0].position), 80);
+    assert_eq!(std140::std140_offset_of!(Uniforms, lights[1].falloff), 132);
+    assert_eq!(std140::std140_offset_of!(AssertedLayout, position), 16);
+    assert_eq!(std140::std140_offset_of!(AssertedLayout, flags), 160);
+}
+
+#[test]
+fn std140_offset_of_matches_flat_field_paths_test() {
+    let paths = Uniforms::flat_field_paths();
+
+    assert_eq!(paths[6].1, LIGHTS_INTENSITY_OFFSET);
+}
+
+/// A struct that combines `#[repr_std140]` with other attributes.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[std140::repr_std140]
+struct WithAttributes {
+    /// A documented field.
+    color: std140::vec4,
+    #[cfg(any())]
+    disabled: String,
+    #[cfg(not(any()))]
+    enabled: std140::float,
+}
+
+#[test]
+fn preserves_attributes_test() {
+    let value = WithAttributes {
+        color: std140::vec4(1.0, 0.0, 0.0, 1.0),
+        enabled: std140::float(0.5),
+    };
+    let copy = value;
+
+    assert_eq!(copy, value);
+    assert_eq!(
+        format!("{:?}", value),
+        "WithAttributes { color: vec4(1.0, 0.0, 0.0, 1.0), enabled: float(0.5) }"
+    );
+    assert_eq!(WithAttributes::std140_layout().len(), 2);
+    assert_eq!(WithAttributes::std140_layout()[1].name, "enabled");
+    assert_eq!(WithAttributes::FIELD_NAMES, ["color", "enabled"]);
+    // The disabled field does not take up space in the layout.
+    assert_eq!(WithAttributes::field_range("enabled"), Some(16..20));
+}
+
+#[std140::repr_std140(set = 1, binding = 3)]
+struct Camera {
+    view: std140::mat4x4,
+}
+
+#[std140::repr_std140(binding = 0, assert_layout)]
+struct Exposure {
+    value: std140::float,
+}
+
+#[test]
+fn set_and_binding_test() {
+    assert_eq!(Camera::SET, 1);
+    assert_eq!(Camera::BINDING, 3);
+    assert_eq!(Exposure::BINDING, 0);
+    assert_eq!(std::mem::size_of::<Camera>(), 64);
+}
+
+#[repr(C)]
+#[std140::repr_std140]
+struct WithReprC {
+    value: std140::float,
+}
+
+#[std140::repr_std140]
+#[repr(C, align(8))]
+struct WithReprAlign {
+    value: std140::float,
+}
+
+#[test]
+fn redundant_repr_test() {
+    assert_eq!(std::mem::size_of::<WithReprC>(), 16);
+    assert_eq!(std::mem::align_of::<WithReprC>(), 16);
+    assert_eq!(std::mem::size_of::<WithReprAlign>(), 16);
+    assert_eq!(std::mem::align_of::<WithReprAlign>(), 16);
+}
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Flags {
+    enabled: std140::boolean,
+    mask: std140::bvec3,
+    counts: std140::array<std140::ivec2, 2>,
+}
+
+#[test]
+fn zeroed_test() {
+    assert_eq!(Uniforms::zeroed(), Uniforms::default());
+
+    let flags = Flags::zeroed();
+
+    assert_eq!(flags.enabled, std140::boolean::False);
+    assert_eq!(
+        flags.mask,
+        std140::bvec3(
+            std140::boolean::False,
+            std140::boolean::False,
+            std140::boolean::False
+        )
+    );
+    assert_eq!(flags.counts, std140::array![std140::ivec2(0, 0); 2]);
+}
+
+#[std140::repr_std140(assert_layout)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct TupleUniforms(std140::vec4, std140::float, std140::array<std140::vec2, 2>);
+
+#[test]
+fn tuple_struct_test() {
+    let value = TupleUniforms(
+        std140::vec4(1.0, 0.0, 0.0, 1.0),
+        std140::float(0.5),
+        std140::array![std140::vec2(1.0, 2.0), std140::vec2(3.0, 4.0)],
+    );
+
+    assert_eq!(value.1, std140::float(0.5));
+    assert_eq!(std::mem::size_of::<TupleUniforms>(), 64);
+    assert_eq!(std140::std140_offset_of!(TupleUniforms, 2[1]), 48);
+    assert_eq!(TupleUniforms::std140_layout()[1].name, "1");
+    assert_eq!(TupleUniforms::std140_layout()[1].offset, 16);
+    assert_eq!(
+        TupleUniforms::flat_field_paths()[3],
+        ("2[1]".to_string(), 48, 8)
+    );
+}
+
+#[deny(deprecated)]
+mod warn_padding {
+    #[std140::repr_std140(warn_padding, assert_layout)]
+    pub struct Sprite {
+        pub offset: std140::vec2,
+        pub scale: std140::vec2,
+        pub color: std140::vec4,
+        pub transform: std140::mat3x3,
+    }
+}
+
+#[test]
+fn warn_padding_without_excess_padding_test() {
+    assert_eq!(std::mem::size_of::<warn_padding::Sprite>(), 80);
+}
+
+#[test]
+fn layout_description_test() {
+    assert_eq!(
+        Uniforms::LAYOUT_DESCRIPTION,
+        "transform: std140::mat4x4 (offset 0, size 64)\n\
+         ambient_light_color: std140::vec3 (offset 64, size 16)\n\
+         lights: std140::array<PointLight, 2> (offset 80, size 64)"
+    );
+    assert_eq!(
+        TupleUniforms::LAYOUT_DESCRIPTION.lines().nth(1),
+        Some("1: std140::float (offset 16, size 4)")
+    );
+}
@@ -0,0 +1,48 @@
+use std140::RowMajor;
+
+#[test]
+fn row_major_identity_test() {
+    let value = RowMajor::<std140::mat4x4>::new(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(0.0, 0.0, 0.0, 1.0),
+    );
+
+    let identity = std140::mat4x4(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(0.0, 0.0, 0.0, 1.0),
+    );
+
+    assert_eq!(*value, identity);
+}
+
+#[test]
+fn row_major_transposes_rows_test() {
+    let value =
+        RowMajor::<std140::mat3x2>::new(std140::vec3(1.0, 2.0, 3.0), std140::vec3(4.0, 5.0, 6.0));
+
+    let expected = std140::mat3x2(
+        std140::vec2(1.0, 4.0),
+        std140::vec2(2.0, 5.0),
+        std140::vec2(3.0, 6.0),
+    );
+
+    assert_eq!(value.into_inner(), expected);
+}
+
+#[std140::repr_std140]
+struct Transforms {
+    model: RowMajor<std140::mat4x3>,
+    scale: std140::float,
+}
+
+#[test]
+fn row_major_field_layout_test() {
+    assert_eq!(
+        std::mem::size_of::<Transforms>(),
+        std::mem::size_of::<std140::mat4x3>() + 16
+    );
+}
@@ -0,0 +1,97 @@
+#[test]
+fn scalar_from_primitive_test() {
+    let value: std140::float = 0.5.into();
+
+    assert_eq!(value, std140::float(0.5));
+    assert_eq!(std140::int::from(-1), std140::int(-1));
+    assert_eq!(std140::uint::from(1), std140::uint(1));
+    assert_eq!(std140::double::from(0.25), std140::double(0.25));
+}
+
+#[test]
+fn primitive_from_scalar_test() {
+    assert_eq!(f32::from(std140::float(0.5)), 0.5);
+    assert_eq!(i32::from(std140::int(-1)), -1);
+    assert_eq!(u32::from(std140::uint(1)), 1);
+    assert_eq!(f64::from(std140::double(0.25)), 0.25);
+}
+
+#[test]
+fn scalar_deref_test() {
+    let mut value = std140::uint(1);
+
+    *value += 2;
+
+    assert_eq!(*value, 3);
+    assert!(std140::float(-0.5).is_sign_negative());
+}
+
+#[test]
+fn float_arithmetic_test() {
+    let mut value = std140::float(1.0);
+
+    value += std140::float(2.0);
+    value *= std140::float(4.0);
+
+    assert_eq!(value, std140::float(12.0));
+    assert_eq!(value - std140::float(2.0), std140::float(10.0));
+    assert_eq!(value / std140::float(8.0), std140::float(1.5));
+    assert_eq!(
+        std140::double(0.5) * std140::double(3.0),
+        std140::double(1.5)
+    );
+}
+
+#[test]
+fn integer_arithmetic_wraps_test() {
+    let mut value = std140::uint(0);
+
+    value -= std140::uint(1);
+
+    assert_eq!(value, std140::uint(u32::MAX));
+    assert_eq!(value + std140::uint(2), std140::uint(1));
+    assert_eq!(std140::int(i32::MAX) * std140::int(2), std140::int(-2));
+    assert_eq!(
+        std140::int(i32::MIN) / std140::int(-1),
+        std140::int(i32::MIN)
+    );
+    assert_eq!(std140::int(7) / std140::int(2), std140::int(3));
+}
+
+#[test]
+#[should_panic]
+fn integer_division_by_zero_test() {
+    let _ = std140::uint(1) / std140::uint(0);
+}
+
+#[test]
+fn integer_as_raw_test() {
+    let mut value = std140::uint(7);
+
+    assert_eq!(*value.as_raw(), 7);
+
+    *value.as_raw_mut() += 1;
+
+    assert_eq!(value, std140::uint(8));
+
+    let mut value = std140::int(-1);
+
+    *value.as_raw_mut() -= 1;
+
+    assert_eq!(*value.as_raw(), -2);
+}
+
+#[test]
+fn uint_atomic_counter_test() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let mut counters = std140::array![std140::uint(0); 2];
+    let counter = counters.get_mut(1).unwrap();
+
+    // SAFETY: `counter` is only accessed through `atomic` while `atomic` is in use.
+    let atomic = unsafe { AtomicU32::from_ptr(counter.as_raw_mut()) };
+
+    assert_eq!(atomic.fetch_add(5, Ordering::Relaxed), 0);
+    assert_eq!(atomic.fetch_add(1, Ordering::Relaxed), 5);
+    assert_eq!(counters.get(1), Some(&std140::uint(6)));
+}
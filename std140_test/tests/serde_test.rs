@@ -0,0 +1,82 @@
+#[test]
+fn serialize_vec3_test() {
+    assert_eq!(
+        serde_json::to_string(&std140::vec3(1.0, 0.5, 0.0)).unwrap(),
+        "[1.0,0.5,0.0]"
+    );
+}
+
+#[test]
+fn serialize_boolean_test() {
+    assert_eq!(
+        serde_json::to_string(&std140::bvec2(
+            std140::boolean::True,
+            std140::boolean::False
+        ))
+        .unwrap(),
+        "[true,false]"
+    );
+}
+
+#[test]
+fn serialize_mat2x2_test() {
+    assert_eq!(
+        serde_json::to_string(&std140::mat2x2(
+            std140::vec2(1.0, 0.0),
+            std140::vec2(0.0, 1.0),
+        ))
+        .unwrap(),
+        "[[1.0,0.0],[0.0,1.0]]"
+    );
+}
+
+#[test]
+fn array_round_trip_test() {
+    let value: std140::array<std140::uvec2, 3> = std140::array![
+        std140::uvec2(0, 1),
+        std140::uvec2(2, 3),
+        std140::uvec2(4, 5),
+    ];
+
+    let json = serde_json::to_string(&value).unwrap();
+
+    assert_eq!(json, "[[0,1],[2,3],[4,5]]");
+    assert_eq!(
+        serde_json::from_str::<std140::array<std140::uvec2, 3>>(&json).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn array_length_mismatch_test() {
+    assert!(serde_json::from_str::<std140::array<std140::float, 3>>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<std140::array<std140::float, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+}
+
+#[test]
+fn mat4x4_round_trip_test() {
+    let value = std140::mat4x4(
+        std140::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec4(0.0, 0.0, 0.0, 1.0),
+    );
+
+    let json = serde_json::to_string(&value).unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<std140::mat4x4>(&json).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn serialize_f16vec2_test() {
+    let value = std140::f16vec2::from(std140::vec2(0.5, -1.0));
+
+    assert_eq!(serde_json::to_string(&value).unwrap(), "[0.5,-1.0]");
+    assert_eq!(
+        serde_json::from_str::<std140::f16vec2>("[0.5,-1.0]").unwrap(),
+        value
+    );
+}
@@ -0,0 +1,45 @@
+use std140::Std140Bytes;
+
+#[std140::repr_std140]
+struct Camera {
+    view: std140::mat4x4,
+    position: std140::vec3,
+}
+
+#[std140::repr_std140]
+struct Exposure {
+    value: std140::float,
+}
+
+#[test]
+fn std140_bytes_trait_object_test() {
+    let exposure = Exposure {
+        value: std140::float(0.5),
+    };
+    let expected = std140::AsBytes::to_bytes(&exposure);
+
+    let blocks: Vec<Box<dyn Std140Bytes>> = vec![
+        Box::new(Camera {
+            view: std140::mat4x4::identity(),
+            position: std140::vec3(0.0, 1.0, 0.0),
+        }),
+        Box::new(exposure),
+    ];
+
+    assert_eq!(blocks[0].std140_size(), 80);
+    assert_eq!(blocks[1].std140_size(), 16);
+
+    let total: usize = blocks.iter().map(|block| block.std140_size()).sum();
+
+    assert_eq!(total, 96);
+
+    let mut buffer = vec![0xffu8; total];
+    let mut offset = 0;
+
+    for block in &blocks {
+        offset += block.write_bytes(&mut buffer[offset..]);
+    }
+
+    assert_eq!(offset, 96);
+    assert_eq!(&buffer[80..], &expected[..]);
+}
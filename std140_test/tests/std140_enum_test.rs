@@ -0,0 +1,69 @@
+use std140::Std140Enum;
+
+#[derive(Std140Enum, Clone, Copy, PartialEq, Debug)]
+#[repr(u32)]
+enum MaterialKind {
+    Opaque,
+    Transparent,
+    Emissive = 8,
+    Unlit,
+}
+
+#[test]
+fn to_std140_test() {
+    assert_eq!(MaterialKind::Opaque.to_std140(), std140::uint(0));
+    assert_eq!(MaterialKind::Transparent.to_std140(), std140::uint(1));
+    assert_eq!(MaterialKind::Emissive.to_std140(), std140::uint(8));
+    assert_eq!(MaterialKind::Unlit.to_std140(), std140::uint(9));
+}
+
+#[test]
+fn from_std140_test() {
+    assert_eq!(
+        MaterialKind::from_std140(std140::uint(1)),
+        Some(MaterialKind::Transparent)
+    );
+    assert_eq!(
+        MaterialKind::from_std140(std140::uint(9)),
+        Some(MaterialKind::Unlit)
+    );
+}
+
+#[test]
+fn from_std140_invalid_discriminant_test() {
+    assert_eq!(MaterialKind::from_std140(std140::uint(2)), None);
+    assert_eq!(MaterialKind::from_std140(std140::uint(u32::MAX)), None);
+}
+
+#[test]
+fn round_trip_test() {
+    for kind in [
+        MaterialKind::Opaque,
+        MaterialKind::Transparent,
+        MaterialKind::Emissive,
+        MaterialKind::Unlit,
+    ] {
+        assert_eq!(MaterialKind::from_std140(kind.to_std140()), Some(kind));
+    }
+}
+
+mod renamed {
+    pub use std140 as gl;
+}
+
+#[derive(Std140Enum, Clone, Copy, PartialEq, Debug)]
+#[std140(crate = "renamed::gl")]
+#[repr(u32)]
+enum BlendMode {
+    Replace = 1,
+    Add = 2,
+}
+
+#[test]
+fn crate_path_test() {
+    assert_eq!(BlendMode::Add.to_std140(), std140::uint(2));
+    assert_eq!(
+        BlendMode::from_std140(std140::uint(1)),
+        Some(BlendMode::Replace)
+    );
+}
@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+
+use std::mem;
+
+use std140::std430;
+
+#[std430::repr_std430]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Particle {
+    position: std140::vec2,
+    mass: std140::float,
+}
+
+#[std430::repr_std430]
+struct Particles {
+    count: std140::uint,
+    weights: std430::array<std140::float, 3>,
+    offset: std140::vec2,
+    particles: std430::array<Particle, 2>,
+    transform: std430::mat2x2,
+    color: std140::vec4,
+}
+
+#[test]
+fn std430_array_stride_test() {
+    assert_eq!(mem::size_of::<std430::array<std140::float, 4>>(), 16);
+    assert_eq!(mem::size_of::<std430::array<std140::vec2, 3>>(), 24);
+    assert_eq!(mem::size_of::<std430::array<std140::vec3, 2>>(), 32);
+    assert_eq!(mem::size_of::<std430::array<Particle, 3>>(), 48);
+}
+
+#[test]
+fn std430_matrix_size_test() {
+    assert_eq!(mem::size_of::<std430::mat2x2>(), 16);
+    assert_eq!(mem::size_of::<std430::mat3x2>(), 24);
+    assert_eq!(mem::size_of::<std430::mat4x2>(), 32);
+}
+
+#[test]
+fn std430_struct_layout_test() {
+    assert_eq!(mem::size_of::<Particle>(), 16);
+    assert_eq!(mem::align_of::<Particle>(), 8);
+
+    assert_eq!(mem::offset_of!(Particles, count), 0);
+    assert_eq!(mem::offset_of!(Particles, weights), 4);
+    assert_eq!(mem::offset_of!(Particles, offset), 16);
+    assert_eq!(mem::offset_of!(Particles, particles), 24);
+    assert_eq!(mem::offset_of!(Particles, transform), 56);
+    assert_eq!(mem::offset_of!(Particles, color), 80);
+    assert_eq!(mem::size_of::<Particles>(), 96);
+}
+
+#[test]
+fn std430_array_access_test() {
+    let mut weights = std430::array::<std140::float, 3>::from_fn(|i| std140::float(i as f32));
+
+    weights[1] = std140::float(5.0);
+
+    assert_eq!(
+        *weights,
+        [std140::float(0.0), std140::float(5.0), std140::float(2.0)]
+    );
+}
+
+#[std430::repr_std430]
+struct ParticleBuffer {
+    count: std140::uint,
+    gravity: std140::vec3,
+    particles: std430::runtime_array<Particle>,
+}
+
+#[std430::repr_std430]
+struct Weights(std140::uint, std430::runtime_array<std140::float>);
+
+#[test]
+fn std430_runtime_array_test() {
+    use std430::Std430RuntimeSizedStruct;
+
+    assert_eq!(mem::size_of::<std430::runtime_array<Particle>>(), 0);
+    assert_eq!(std430::runtime_array::<Particle>::STRIDE, 16);
+
+    assert_eq!(ParticleBuffer::RUNTIME_ARRAY_OFFSET, 32);
+    assert_eq!(ParticleBuffer::RUNTIME_ARRAY_STRIDE, 16);
+    assert_eq!(ParticleBuffer::buffer_size(4), 96);
+
+    assert_eq!(Weights::RUNTIME_ARRAY_OFFSET, 4);
+    assert_eq!(Weights::RUNTIME_ARRAY_STRIDE, 4);
+    assert_eq!(Weights::buffer_size(3), 16);
+}
+
+#[test]
+fn std430_matrix_columns_test() {
+    let columns = [
+        std140::vec2(1.0, 2.0),
+        std140::vec2(3.0, 4.0),
+        std140::vec2(5.0, 6.0),
+    ];
+
+    let value = std430::mat3x2::from_columns(columns);
+
+    assert_eq!(value, std430::mat3x2(columns[0], columns[1], columns[2]));
+    assert_eq!(value.into_columns(), columns);
+}
@@ -0,0 +1,362 @@
+#[test]
+fn vec3_recip_test() {
+    assert_eq!(
+        std140::vec3(2.0, 0.5, -4.0).recip(),
+        std140::vec3(0.5, 2.0, -0.25)
+    );
+}
+
+#[test]
+fn vec3_recip_zero_test() {
+    let value = std140::vec3(0.0, -0.0, 1.0).recip();
+
+    assert_eq!(value.0, f32::INFINITY);
+    assert_eq!(value.1, f32::NEG_INFINITY);
+}
+
+#[test]
+fn vec4_rsqrt_test() {
+    assert_eq!(
+        std140::vec4(1.0, 4.0, 16.0, 0.25).rsqrt(),
+        std140::vec4(1.0, 0.5, 0.25, 2.0)
+    );
+}
+
+#[test]
+fn vec2_rsqrt_zero_and_negative_test() {
+    let value = std140::vec2(0.0, -1.0).rsqrt();
+
+    assert_eq!(value.0, f32::INFINITY);
+    assert!(value.1.is_nan());
+}
+
+#[test]
+fn dvec2_rsqrt_test() {
+    assert_eq!(std140::dvec2(4.0, 64.0).rsqrt(), std140::dvec2(0.5, 0.125));
+}
+
+#[test]
+fn integer_vector_hash_map_key_test() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+
+    map.insert(std140::ivec3(1, 2, 3), "a");
+    map.insert(std140::ivec3(3, 2, 1), "b");
+
+    assert_eq!(map.get(&std140::ivec3(1, 2, 3)), Some(&"a"));
+    assert_eq!(map.get(&std140::ivec3(1, 2, 4)), None);
+}
+
+#[test]
+fn integer_and_boolean_hash_set_test() {
+    use std::collections::HashSet;
+
+    let uvecs: HashSet<_> = vec![std140::uvec2(1, 2), std140::uvec2(1, 2)]
+        .into_iter()
+        .collect();
+    let bvecs: HashSet<_> = vec![
+        std140::bvec2(std140::boolean::True, std140::boolean::False),
+        std140::bvec2(std140::boolean::False, std140::boolean::True),
+    ]
+    .into_iter()
+    .collect();
+    let arrays: HashSet<_> = vec![
+        std140::array![std140::int(1), std140::int(2)],
+        std140::array![std140::int(1), std140::int(2)],
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(uvecs.len(), 1);
+    assert_eq!(bvecs.len(), 2);
+    assert_eq!(arrays.len(), 1);
+}
+
+#[test]
+fn float_vector_ops_test() {
+    let a = std140::vec3(1.0, 2.0, 3.0);
+    let b = std140::vec3(0.5, 0.5, 0.5);
+
+    assert_eq!(a + b, std140::vec3(1.5, 2.5, 3.5));
+    assert_eq!(a - b, std140::vec3(0.5, 1.5, 2.5));
+    assert_eq!(a * 2.0, std140::vec3(2.0, 4.0, 6.0));
+    assert_eq!(a * std140::float(0.5), std140::vec3(0.5, 1.0, 1.5));
+    assert_eq!(-a, std140::vec3(-1.0, -2.0, -3.0));
+    assert_eq!(
+        std140::dvec2(1.0, 2.0) * std140::double(3.0),
+        std140::dvec2(3.0, 6.0)
+    );
+}
+
+#[test]
+fn integer_vector_ops_test() {
+    let a = std140::ivec2(1, -2);
+
+    assert_eq!(a + std140::ivec2(2, 2), std140::ivec2(3, 0));
+    assert_eq!(a - std140::ivec2(2, 2), std140::ivec2(-1, -4));
+    assert_eq!(a * std140::int(3), std140::ivec2(3, -6));
+    assert_eq!(-a, std140::ivec2(-1, 2));
+    assert_eq!(std140::uvec3(1, 2, 3) * 2, std140::uvec3(2, 4, 6));
+}
+
+#[test]
+fn integer_vector_ops_overflow_test() {
+    assert_eq!(
+        std140::ivec2(i32::MAX, i32::MIN) + std140::ivec2(1, -1),
+        std140::ivec2(i32::MIN, i32::MAX)
+    );
+    assert_eq!(-std140::ivec2(i32::MIN, 0), std140::ivec2(i32::MIN, 0));
+    assert_eq!(
+        std140::uvec2(0, 1) - std140::uvec2(1, 1),
+        std140::uvec2(u32::MAX, 0)
+    );
+    assert_eq!(
+        std140::uvec4(u32::MAX, 2, 0, 1) * 2,
+        std140::uvec4(u32::MAX - 1, 4, 0, 2)
+    );
+}
+
+#[test]
+fn vec3_swizzle_test() {
+    let value = std140::vec3(1.0, 2.0, 3.0);
+
+    assert_eq!(value.xy(), std140::vec2(1.0, 2.0));
+    assert_eq!(value.xz(), std140::vec2(1.0, 3.0));
+    assert_eq!(value.yz(), std140::vec2(2.0, 3.0));
+    assert_eq!(value.xyz(), value);
+}
+
+#[test]
+fn vec4_swizzle_test() {
+    let value = std140::vec4(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(value.xw(), std140::vec2(1.0, 4.0));
+    assert_eq!(value.zw(), std140::vec2(3.0, 4.0));
+    assert_eq!(value.xyz(), std140::vec3(1.0, 2.0, 3.0));
+    assert_eq!(value.yzw(), std140::vec3(2.0, 3.0, 4.0));
+    assert_eq!(value.xzw(), std140::vec3(1.0, 3.0, 4.0));
+    assert_eq!(value.xyzw(), value);
+}
+
+#[test]
+fn dvec4_swizzle_test() {
+    let value = std140::dvec4(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(value.xy(), std140::dvec2(1.0, 2.0));
+    assert_eq!(value.yzw(), std140::dvec3(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn vector_get_test() {
+    let mut value = std140::vec3(1.0, 2.0, 3.0);
+
+    assert_eq!(value.get(2), Some(&3.0));
+    assert_eq!(value.get(3), None);
+
+    *value.get_mut(1).unwrap() = 5.0;
+
+    assert_eq!(value, std140::vec3(1.0, 5.0, 3.0));
+    assert!(value.get_mut(3).is_none());
+}
+
+#[test]
+fn bvec_get_test() {
+    let value = std140::bvec2(std140::boolean::True, std140::boolean::False);
+
+    assert_eq!(value.get(0), Some(&std140::boolean::True));
+    assert_eq!(value.get(2), None);
+}
+
+#[test]
+fn uvec_ivec_conversion_preserves_bits_test() {
+    let value = std140::uvec4(0, 1, u32::MAX, 1 << 31);
+
+    assert_eq!(
+        std140::ivec4::from(value),
+        std140::ivec4(0, 1, -1, i32::MIN)
+    );
+    assert_eq!(std140::uvec4::from(std140::ivec4::from(value)), value);
+    assert_eq!(
+        std140::uvec2::from(std140::ivec2(-1, i32::MAX)),
+        std140::uvec2(u32::MAX, i32::MAX as u32)
+    );
+    assert_eq!(std140::int::from(std140::uint(u32::MAX)), std140::int(-1));
+    assert_eq!(
+        std140::uint::from(std140::int(-2)),
+        std140::uint(u32::MAX - 1)
+    );
+}
+
+#[test]
+fn bvec_to_integer_vector_conversion_test() {
+    let value = std140::bvec3(
+        std140::boolean::True,
+        std140::boolean::False,
+        std140::boolean::True,
+    );
+
+    assert_eq!(std140::uvec3::from(value), std140::uvec3(1, 0, 1));
+    assert_eq!(std140::ivec3::from(value), std140::ivec3(1, 0, 1));
+    assert_eq!(std140::uint::from(std140::boolean::True), std140::uint(1));
+    assert_eq!(std140::int::from(std140::boolean::False), std140::int(0));
+}
+
+#[test]
+fn integer_vector_checked_ops_test() {
+    assert_eq!(
+        std140::ivec3(1, 2, 3).checked_add(std140::ivec3(1, 1, 1)),
+        Some(std140::ivec3(2, 3, 4))
+    );
+    assert_eq!(
+        std140::uvec2(5, 3).checked_sub(std140::uvec2(2, 3)),
+        Some(std140::uvec2(3, 0))
+    );
+    assert_eq!(
+        std140::ivec2(2, -3).checked_mul(2),
+        Some(std140::ivec2(4, -6))
+    );
+}
+
+#[test]
+fn integer_vector_checked_ops_overflow_test() {
+    assert_eq!(
+        std140::ivec4(0, 0, i32::MAX, 0).checked_add(std140::ivec4(1, 1, 1, 1)),
+        None
+    );
+    assert_eq!(
+        std140::uvec3(1, 0, 1).checked_sub(std140::uvec3(0, 1, 0)),
+        None
+    );
+    assert_eq!(std140::uvec4(1, 2, u32::MAX, 4).checked_mul(2), None);
+    assert_eq!(std140::ivec2(i32::MIN, 0).checked_mul(-1), None);
+}
+
+#[test]
+fn vector_components_test() {
+    let value = std140::vec4(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(value.components().sum::<f32>(), 10.0);
+    assert_eq!(
+        std140::uvec3(1, 2, 3).components().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        std140::bvec2(std140::boolean::True, std140::boolean::False)
+            .components()
+            .filter(|c| c.as_bool())
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn vector_components_mut_test() {
+    let mut value = std140::ivec3(1, 2, 3);
+
+    for component in value.components_mut() {
+        *component *= -1;
+    }
+
+    assert_eq!(value, std140::ivec3(-1, -2, -3));
+}
+
+#[test]
+fn vector_splat_test() {
+    const VALUE: std140::vec4 = std140::vec4::splat(2.0);
+
+    assert_eq!(VALUE, std140::vec4(2.0, 2.0, 2.0, 2.0));
+    assert_eq!(std140::vec2::splat(0.5), std140::vec2(0.5, 0.5));
+    assert_eq!(std140::ivec4::splat(-1), std140::ivec4(-1, -1, -1, -1));
+    assert_eq!(std140::uvec3::splat(7), std140::uvec3(7, 7, 7));
+    assert_eq!(std140::dvec3::splat(1.5), std140::dvec3(1.5, 1.5, 1.5));
+    assert_eq!(
+        std140::bvec2::splat(std140::boolean::True),
+        std140::bvec2(std140::boolean::True, std140::boolean::True)
+    );
+}
+
+#[test]
+fn vector_get_const_test() {
+    const POSITION: std140::vec3 = std140::vec3(1.0, 2.0, 3.0);
+    const HEIGHT: std140::vec2 = std140::vec2(POSITION.get_const(1), 0.0);
+
+    assert_eq!(HEIGHT, std140::vec2(2.0, 0.0));
+    assert_eq!(std140::uvec4(1, 2, 3, 4).get_const(3), 4);
+    assert_eq!(
+        std140::bvec2(std140::boolean::False, std140::boolean::True).get_const(1),
+        std140::boolean::True
+    );
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn vector_get_const_out_of_bounds_test() {
+    let _ = std140::vec2(1.0, 2.0).get_const(2);
+}
+
+#[test]
+fn vector_extend_truncate_test() {
+    const POSITION: std140::vec3 = std140::vec3(1.0, 2.0, 3.0);
+    const HOMOGENEOUS: std140::vec4 = std140::vec4::from_vec3(POSITION, 1.0);
+
+    assert_eq!(HOMOGENEOUS, std140::vec4(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(HOMOGENEOUS.truncate(), POSITION);
+    assert_eq!(POSITION.truncate(), std140::vec2(1.0, 2.0));
+    assert_eq!(
+        std140::vec3::from_vec2(std140::vec2(1.0, 2.0), 3.0),
+        POSITION
+    );
+    assert_eq!(
+        std140::ivec4::from_ivec3(std140::ivec3(-1, 2, -3), 4),
+        std140::ivec4(-1, 2, -3, 4)
+    );
+    assert_eq!(std140::ivec3(-1, 2, -3).truncate(), std140::ivec2(-1, 2));
+    assert_eq!(std140::uvec4(1, 2, 3, 4).truncate(), std140::uvec3(1, 2, 3));
+    assert_eq!(
+        std140::uvec3::from_uvec2(std140::uvec2(1, 2), 3),
+        std140::uvec3(1, 2, 3)
+    );
+    assert_eq!(
+        std140::dvec4::from_dvec3(std140::dvec3(1.0, 2.0, 3.0), 0.0).truncate(),
+        std140::dvec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        std140::bvec3(
+            std140::boolean::True,
+            std140::boolean::False,
+            std140::boolean::True
+        )
+        .truncate(),
+        std140::bvec2(std140::boolean::True, std140::boolean::False)
+    );
+}
+
+#[test]
+fn vector_min_max_clamp_test() {
+    const RANGE: std140::ivec3 =
+        std140::ivec3(1, 5, 3).clamp(std140::ivec3::splat(2), std140::ivec3::splat(4));
+
+    assert_eq!(RANGE, std140::ivec3(2, 4, 3));
+    assert_eq!(
+        std140::vec3(1.0, 5.0, 3.0).clamp(std140::vec3::splat(2.0), std140::vec3::splat(4.0)),
+        std140::vec3(2.0, 4.0, 3.0)
+    );
+    assert_eq!(
+        std140::vec2(1.0, 5.0).min(std140::vec2(2.0, 4.0)),
+        std140::vec2(1.0, 4.0)
+    );
+    assert_eq!(
+        std140::vec2(1.0, 5.0).max(std140::vec2(2.0, 4.0)),
+        std140::vec2(2.0, 5.0)
+    );
+    assert_eq!(
+        std140::uvec4(0, 10, 20, 30).min(std140::uvec4::splat(15)),
+        std140::uvec4(0, 10, 15, 15)
+    );
+    assert_eq!(
+        std140::dvec4(-1.0, 0.5, 2.0, 0.0)
+            .clamp(std140::dvec4::splat(0.0), std140::dvec4::splat(1.0)),
+        std140::dvec4(0.0, 0.5, 1.0, 0.0)
+    );
+}
@@ -0,0 +1,109 @@
+use std140::{AsBytes, Std140Writer};
+
+#[std140::repr_std140]
+struct Light {
+    color: std140::vec4,
+    intensity: std140::float,
+    weights: std140::array<std140::float, 2>,
+    transform: std140::mat2x3,
+    enabled: std140::boolean,
+}
+
+#[test]
+fn write_matches_repr_std140_test() {
+    let light = Light {
+        color: std140::vec4(1.0, 0.5, 0.25, 1.0),
+        intensity: std140::float(2.0),
+        weights: std140::array![std140::float(0.25), std140::float(0.75)],
+        transform: std140::mat2x3(std140::vec3(1.0, 2.0, 3.0), std140::vec3(4.0, 5.0, 6.0)),
+        enabled: std140::boolean::True,
+    };
+
+    let mut buffer = [0xffu8; 256];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    writer.write_vec4([1.0, 0.5, 0.25, 1.0]);
+    writer.write_float(2.0);
+    writer.write_array(&[std140::float(0.25), std140::float(0.75)]);
+    writer.write(&light.transform);
+    writer.write_bool(true);
+
+    let size = writer.finish();
+
+    assert_eq!(size, std::mem::size_of::<Light>());
+
+    // Compare the bytes that are not padding.
+    let bytes = light.to_bytes();
+
+    for range in [0..20, 32..36, 48..52, 64..76, 80..92, 96..100] {
+        assert_eq!(buffer[range.clone()], bytes[range]);
+    }
+}
+
+#[test]
+fn write_pads_with_zeros_test() {
+    let mut buffer = [0xffu8; 64];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    writer.write_float(1.0);
+    writer.write_array(&[std140::float(2.0), std140::float(3.0)]);
+
+    assert_eq!(writer.finish(), 48);
+    assert!(buffer[4..16].iter().all(|b| *b == 0));
+    assert!(buffer[20..32].iter().all(|b| *b == 0));
+    assert!(buffer[36..48].iter().all(|b| *b == 0));
+    assert!(buffer[48..].iter().all(|b| *b == 0xff));
+}
+
+#[test]
+fn write_float_after_vec3_test() {
+    let mut buffer = [0u8; 32];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    assert_eq!(writer.write_vec3([1.0, 2.0, 3.0]), 0);
+    assert_eq!(writer.write_float(4.0), 12);
+    assert_eq!(writer.write_vec2([5.0, 6.0]), 16);
+    assert_eq!(writer.finish(), 32);
+}
+
+#[test]
+fn write_offsets_test() {
+    let mut buffer = [0u8; 256];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    assert_eq!(writer.write_uint(1), 0);
+    assert_eq!(writer.write_double(2.0), 8);
+    assert_eq!(writer.write_int(-3), 16);
+    assert_eq!(writer.write_mat3([[1.0; 3]; 3]), 32);
+    assert_eq!(writer.offset(), 80);
+    assert_eq!(writer.write_vec3([1.0; 3]), 80);
+    assert_eq!(writer.write_array(&[std140::vec3(1.0, 2.0, 3.0)]), 96);
+    assert_eq!(writer.write_mat2([[1.0; 2]; 2]), 112);
+    assert_eq!(writer.write_mat4([[1.0; 4]; 4]), 144);
+    assert_eq!(writer.finish(), 208);
+}
+
+#[test]
+fn write_nested_struct_test() {
+    let mut buffer = [0u8; 64];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    writer.write_float(1.0);
+
+    writer.align_to(16);
+    assert_eq!(writer.write_float(2.0), 16);
+    writer.align_to(16);
+
+    assert_eq!(writer.write_float(3.0), 32);
+    assert_eq!(writer.finish(), 48);
+}
+
+#[test]
+#[should_panic(expected = "std140 writer buffer too small")]
+fn write_buffer_too_small_test() {
+    let mut buffer = [0u8; 16];
+    let mut writer = Std140Writer::new(&mut buffer);
+
+    writer.write_float(1.0);
+    writer.write_vec4([1.0; 4]);
+}